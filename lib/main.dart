@@ -42,7 +42,11 @@ class _State extends State<Demo> {
   void initState() {
     // TODO: implement initState
     super.initState();
-    workers = getWorkers();
+    try {
+      workers = getWorkers();
+    } catch (e) {
+      WidgetsBinding.instance.addPostFrameCallback((_) => showError(e));
+    }
     myFocusNode = FocusNode();
   }
 
@@ -54,6 +58,39 @@ class _State extends State<Demo> {
     super.dispose();
   }
 
+  void showError(Object e) {
+    final String message = switch (e) {
+      UpdateError_Conflict() =>
+        'A munkavállalót közben módosították, töltsd be újra.',
+      UpdateError_Failed(:final field0) => field0,
+      _ => e.toString(),
+    };
+    ScaffoldMessenger.of(context).showSnackBar(SnackBar(
+      backgroundColor: Colors.red,
+      content: Text(message),
+    ));
+  }
+
+  void warnDuplicates(Worker worker) {
+    final List<Worker> others = findDuplicateWorkers(worker: worker);
+    if (others.isEmpty) {
+      return;
+    }
+    ScaffoldMessenger.of(context).showSnackBar(SnackBar(
+      backgroundColor: Colors.orange,
+      content: Text('Azonos TAJ vagy adóazonosító: ' +
+          others.map((w) => w.name).join(', ')),
+    ));
+  }
+
+  void reloadWorkers() {
+    try {
+      workers = getWorkers();
+    } catch (e) {
+      showError(e);
+    }
+  }
+
   Future<void> addEmptyWorker() async {
     debugPrint('Adding empty worker');
     if (!await confirm(context,
@@ -64,9 +101,13 @@ class _State extends State<Demo> {
       return;
     }
     Worker worker = getEmptyWorker();
-    setState(() {
+    try {
       addWorker(worker: worker);
-      workers = getWorkers();
+    } catch (e) {
+      showError(e);
+    }
+    setState(() {
+      reloadWorkers();
     });
   }
 
@@ -78,9 +119,14 @@ class _State extends State<Demo> {
         textCancel: Text('Mégsem'))) {
       return;
     }
-    setState(() {
+    try {
       removeWorkerApi(worker: worker);
-      workers = getWorkers();
+    } catch (e) {
+      showError(e);
+      return;
+    }
+    setState(() {
+      reloadWorkers();
       Navigator.of(context).pop();
     });
   }
@@ -91,12 +137,19 @@ class _State extends State<Demo> {
     });
   }
 
-  void localUpdateWorker(Worker worker) {
-    print("Hello");
-    updateWorker(worker: worker);
+  bool localUpdateWorker(Worker worker) {
+    bool saved = true;
+    try {
+      updateWorker(worker: worker);
+      warnDuplicates(worker);
+    } catch (e) {
+      saved = false;
+      showError(e);
+    }
     setState(() {
-      workers = getWorkers();
+      reloadWorkers();
     });
+    return saved;
   }
 
   void incrementCounter() {
@@ -114,7 +167,13 @@ class _State extends State<Demo> {
         content: Text("Sikertelen export."),
       ));
     } else {
-      exportXmlApi(to: directoryPath);
+      try {
+        final String today = DateTime.now().toIso8601String().substring(0, 10);
+        exportXmlApi(to: directoryPath, date: today);
+      } catch (e) {
+        showError(e);
+        return;
+      }
       ScaffoldMessenger.of(context).showSnackBar(const SnackBar(
         backgroundColor: Colors.green,
         content: Text("Sikeres mentés!"),
@@ -171,7 +230,7 @@ class _State extends State<Demo> {
                   query: query,
                   full: true,
                   workers: workers,
-                  on_change: (Worker w) => {localUpdateWorker(w)},
+                  on_change: (Worker w) => localUpdateWorker(w),
                   delete: (Worker w) => {deleteWorker(w)},
                 )),
               ],
@@ -0,0 +1,89 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.22.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:uuid/uuid.dart';
+
+class FieldChange {
+  final String field;
+  final String before;
+  final String after;
+
+  const FieldChange({
+    required this.field,
+    required this.before,
+    required this.after,
+  });
+
+  @override
+  int get hashCode => field.hashCode ^ before.hashCode ^ after.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FieldChange &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          before == other.before &&
+          after == other.after;
+}
+
+class AuditEntry {
+  final UuidValue id;
+  final DateTime timestamp;
+  final String operator;
+  final AuditAction action;
+  final List<UuidValue> workerIds;
+  final List<FieldChange> changes;
+  final String note;
+
+  const AuditEntry({
+    required this.id,
+    required this.timestamp,
+    required this.operator,
+    required this.action,
+    required this.workerIds,
+    required this.changes,
+    required this.note,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      timestamp.hashCode ^
+      operator.hashCode ^
+      action.hashCode ^
+      workerIds.hashCode ^
+      changes.hashCode ^
+      note.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AuditEntry &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          timestamp == other.timestamp &&
+          operator == other.operator &&
+          action == other.action &&
+          workerIds == other.workerIds &&
+          changes == other.changes &&
+          note == other.note;
+}
+
+enum AuditAction {
+  add,
+  update,
+  remove,
+  select,
+  export,
+  restore,
+  import,
+  subjectAccess,
+  anonymize,
+  rosterExport,
+  ;
+}
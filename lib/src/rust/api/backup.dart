@@ -0,0 +1,88 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.22.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:uuid/uuid.dart';
+
+class Snapshot {
+  final String id;
+  final DateTime createdAt;
+  final SnapshotReason reason;
+  final int size;
+
+  const Snapshot({
+    required this.id,
+    required this.createdAt,
+    required this.reason,
+    required this.size,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      createdAt.hashCode ^
+      reason.hashCode ^
+      size.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Snapshot &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          createdAt == other.createdAt &&
+          reason == other.reason &&
+          size == other.size;
+}
+
+class ImportSummary {
+  final List<String> added;
+  final List<String> updated;
+  final List<String> removed;
+  final int unchanged;
+  final bool replacesSettings;
+
+  const ImportSummary({
+    required this.added,
+    required this.updated,
+    required this.removed,
+    required this.unchanged,
+    required this.replacesSettings,
+  });
+
+  @override
+  int get hashCode =>
+      added.hashCode ^
+      updated.hashCode ^
+      removed.hashCode ^
+      unchanged.hashCode ^
+      replacesSettings.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportSummary &&
+          runtimeType == other.runtimeType &&
+          added == other.added &&
+          updated == other.updated &&
+          removed == other.removed &&
+          unchanged == other.unchanged &&
+          replacesSettings == other.replacesSettings;
+}
+
+enum SnapshotReason {
+  daily,
+  export,
+  manual,
+  restore,
+  ;
+}
+
+enum ImportMode {
+  merge,
+  replace,
+  ;
+}
@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.22.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:uuid/uuid.dart';
+
+class Crew {
+  final UuidValue id;
+  final String name;
+  final List<UuidValue> workerIds;
+  final DateTime updatedAt;
+
+  const Crew({
+    required this.id,
+    required this.name,
+    required this.workerIds,
+    required this.updatedAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      workerIds.hashCode ^
+      updatedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Crew &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          workerIds == other.workerIds &&
+          updatedAt == other.updatedAt;
+}
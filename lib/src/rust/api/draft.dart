@@ -0,0 +1,128 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.22.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:uuid/uuid.dart';
+
+class RowOverride {
+  final String? name;
+  final String? taj;
+  final String? taxnumber;
+  final String? category;
+  final int? days;
+
+  const RowOverride({
+    required this.name,
+    required this.taj,
+    required this.taxnumber,
+    required this.category,
+    required this.days,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      taj.hashCode ^
+      taxnumber.hashCode ^
+      category.hashCode ^
+      days.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RowOverride &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          taj == other.taj &&
+          taxnumber == other.taxnumber &&
+          category == other.category &&
+          days == other.days;
+}
+
+class DraftRow {
+  final UuidValue workerId;
+  final RowOverride overrides;
+
+  const DraftRow({
+    required this.workerId,
+    required this.overrides,
+  });
+
+  @override
+  int get hashCode => workerId.hashCode ^ overrides.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DraftRow &&
+          runtimeType == other.runtimeType &&
+          workerId == other.workerId &&
+          overrides == other.overrides;
+}
+
+class ReportDraft {
+  final UuidValue id;
+  final String date;
+  final String employer;
+  final String category;
+  final List<DraftRow> rows;
+  final DateTime updatedAt;
+  final DateTime? exportedAt;
+
+  const ReportDraft({
+    required this.id,
+    required this.date,
+    required this.employer,
+    required this.category,
+    required this.rows,
+    required this.updatedAt,
+    required this.exportedAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      date.hashCode ^
+      employer.hashCode ^
+      category.hashCode ^
+      rows.hashCode ^
+      updatedAt.hashCode ^
+      exportedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReportDraft &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          date == other.date &&
+          employer == other.employer &&
+          category == other.category &&
+          rows == other.rows &&
+          updatedAt == other.updatedAt &&
+          exportedAt == other.exportedAt;
+}
+
+class DraftIssue {
+  final UuidValue? workerId;
+  final String message;
+
+  const DraftIssue({
+    required this.workerId,
+    required this.message,
+  });
+
+  @override
+  int get hashCode => workerId.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DraftIssue &&
+          runtimeType == other.runtimeType &&
+          workerId == other.workerId &&
+          message == other.message;
+}
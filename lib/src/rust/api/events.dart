@@ -0,0 +1,95 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.22.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import, camel_case_types
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:uuid/uuid.dart';
+import 'worker.dart';
+
+sealed class DbEvent {
+  const DbEvent._();
+
+  const factory DbEvent.workerAdded(
+      {required Worker worker}) = DbEvent_WorkerAdded;
+  const factory DbEvent.workerUpdated(
+      {required Worker worker}) = DbEvent_WorkerUpdated;
+  const factory DbEvent.workerRemoved(
+      {required UuidValue id}) = DbEvent_WorkerRemoved;
+  const factory DbEvent.selectionChanged(
+      {required UuidValue id, required bool selected}) =
+      DbEvent_SelectionChanged;
+  const factory DbEvent.selectionsChanged(
+      {required List<UuidValue> selected, required List<UuidValue> unselected}) =
+      DbEvent_SelectionsChanged;
+  const factory DbEvent.exportDone(
+      {required String path, required List<UuidValue> workerIds}) =
+      DbEvent_ExportDone;
+  const factory DbEvent.conflict(
+      {required Worker local, required bool deleted}) = DbEvent_Conflict;
+  const factory DbEvent.reloaded() = DbEvent_Reloaded;
+  const factory DbEvent.changed(
+      {required List<UuidValue> ids}) = DbEvent_Changed;
+}
+
+class DbEvent_WorkerAdded extends DbEvent {
+  final Worker worker;
+
+  const DbEvent_WorkerAdded({required this.worker}) : super._();
+}
+
+class DbEvent_WorkerUpdated extends DbEvent {
+  final Worker worker;
+
+  const DbEvent_WorkerUpdated({required this.worker}) : super._();
+}
+
+class DbEvent_WorkerRemoved extends DbEvent {
+  final UuidValue id;
+
+  const DbEvent_WorkerRemoved({required this.id}) : super._();
+}
+
+class DbEvent_SelectionChanged extends DbEvent {
+  final UuidValue id;
+  final bool selected;
+
+  const DbEvent_SelectionChanged({required this.id, required this.selected})
+      : super._();
+}
+
+class DbEvent_SelectionsChanged extends DbEvent {
+  final List<UuidValue> selected;
+  final List<UuidValue> unselected;
+
+  const DbEvent_SelectionsChanged(
+      {required this.selected, required this.unselected})
+      : super._();
+}
+
+class DbEvent_ExportDone extends DbEvent {
+  final String path;
+  final List<UuidValue> workerIds;
+
+  const DbEvent_ExportDone({required this.path, required this.workerIds})
+      : super._();
+}
+
+class DbEvent_Conflict extends DbEvent {
+  final Worker local;
+  final bool deleted;
+
+  const DbEvent_Conflict({required this.local, required this.deleted})
+      : super._();
+}
+
+class DbEvent_Reloaded extends DbEvent {
+  const DbEvent_Reloaded() : super._();
+}
+
+class DbEvent_Changed extends DbEvent {
+  final List<UuidValue> ids;
+
+  const DbEvent_Changed({required this.ids}) : super._();
+}
@@ -0,0 +1,88 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.22.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:uuid/uuid.dart';
+import 'settings.dart';
+
+class ReportedWorker {
+  final UuidValue workerId;
+  final String category;
+  final int days;
+
+  const ReportedWorker({
+    required this.workerId,
+    required this.category,
+    required this.days,
+  });
+
+  @override
+  int get hashCode => workerId.hashCode ^ category.hashCode ^ days.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReportedWorker &&
+          runtimeType == other.runtimeType &&
+          workerId == other.workerId &&
+          category == other.category &&
+          days == other.days;
+}
+
+class ExportRecord {
+  final UuidValue id;
+  final DateTime timestamp;
+  final String operator;
+  final Employer employer;
+  final String reportDate;
+  final List<UuidValue> workerIds;
+  final List<ReportedWorker> workers;
+  final String fileName;
+  final String path;
+  final String sha256;
+
+  const ExportRecord({
+    required this.id,
+    required this.timestamp,
+    required this.operator,
+    required this.employer,
+    required this.reportDate,
+    required this.workerIds,
+    required this.workers,
+    required this.fileName,
+    required this.path,
+    required this.sha256,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      timestamp.hashCode ^
+      operator.hashCode ^
+      employer.hashCode ^
+      reportDate.hashCode ^
+      workerIds.hashCode ^
+      workers.hashCode ^
+      fileName.hashCode ^
+      path.hashCode ^
+      sha256.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExportRecord &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          timestamp == other.timestamp &&
+          operator == other.operator &&
+          employer == other.employer &&
+          reportDate == other.reportDate &&
+          workerIds == other.workerIds &&
+          workers == other.workers &&
+          fileName == other.fileName &&
+          path == other.path &&
+          sha256 == other.sha256;
+}
@@ -0,0 +1,51 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.22.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:uuid/uuid.dart';
+
+class HistoryItem {
+  final UuidValue id;
+  final HistoryAction action;
+  final String description;
+  final List<UuidValue> workerIds;
+  final DateTime createdAt;
+
+  const HistoryItem({
+    required this.id,
+    required this.action,
+    required this.description,
+    required this.workerIds,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      action.hashCode ^
+      description.hashCode ^
+      workerIds.hashCode ^
+      createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HistoryItem &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          action == other.action &&
+          description == other.description &&
+          workerIds == other.workerIds &&
+          createdAt == other.createdAt;
+}
+
+enum HistoryAction {
+  add,
+  update,
+  remove,
+  select,
+  ;
+}
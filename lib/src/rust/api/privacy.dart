@@ -0,0 +1,115 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.22.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'audit.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:uuid/uuid.dart';
+import 'worker.dart';
+
+class ExportAppearance {
+  final UuidValue? exportId;
+  final DateTime timestamp;
+  final String reportDate;
+  final String operator;
+  final String file;
+
+  const ExportAppearance({
+    required this.exportId,
+    required this.timestamp,
+    required this.reportDate,
+    required this.operator,
+    required this.file,
+  });
+
+  @override
+  int get hashCode =>
+      exportId.hashCode ^
+      timestamp.hashCode ^
+      reportDate.hashCode ^
+      operator.hashCode ^
+      file.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExportAppearance &&
+          runtimeType == other.runtimeType &&
+          exportId == other.exportId &&
+          timestamp == other.timestamp &&
+          reportDate == other.reportDate &&
+          operator == other.operator &&
+          file == other.file;
+}
+
+class SubjectReport {
+  final DateTime generatedAt;
+  final Worker worker;
+  final List<String> reportedDays;
+  final List<ExportAppearance> exports;
+  final List<AuditEntry> auditEntries;
+
+  const SubjectReport({
+    required this.generatedAt,
+    required this.worker,
+    required this.reportedDays,
+    required this.exports,
+    required this.auditEntries,
+  });
+
+  @override
+  int get hashCode =>
+      generatedAt.hashCode ^
+      worker.hashCode ^
+      reportedDays.hashCode ^
+      exports.hashCode ^
+      auditEntries.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SubjectReport &&
+          runtimeType == other.runtimeType &&
+          generatedAt == other.generatedAt &&
+          worker == other.worker &&
+          reportedDays == other.reportedDays &&
+          exports == other.exports &&
+          auditEntries == other.auditEntries;
+}
+
+class RetentionCandidate {
+  final UuidValue id;
+  final String name;
+  final DateTime? lastActivity;
+  final int reportedDays;
+  final int keptExports;
+
+  const RetentionCandidate({
+    required this.id,
+    required this.name,
+    required this.lastActivity,
+    required this.reportedDays,
+    required this.keptExports,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      lastActivity.hashCode ^
+      reportedDays.hashCode ^
+      keptExports.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RetentionCandidate &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          lastActivity == other.lastActivity &&
+          reportedDays == other.reportedDays &&
+          keptExports == other.keptExports;
+}
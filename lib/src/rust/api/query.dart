@@ -0,0 +1,110 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.22.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:uuid/uuid.dart';
+import 'worker.dart';
+
+class WorkerSort {
+  final WorkerSortKey key;
+  final bool descending;
+
+  const WorkerSort({
+    required this.key,
+    required this.descending,
+  });
+
+  @override
+  int get hashCode => key.hashCode ^ descending.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WorkerSort &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          descending == other.descending;
+}
+
+class WorkerQuery {
+  final String? text;
+  final bool? selected;
+  final String? city;
+  final bool? hasValidationErrors;
+  final List<String> tags;
+  final String? lastWorkedFrom;
+  final String? lastWorkedTo;
+  final List<WorkerSort> sort;
+
+  const WorkerQuery({
+    required this.text,
+    required this.selected,
+    required this.city,
+    required this.hasValidationErrors,
+    required this.tags,
+    required this.lastWorkedFrom,
+    required this.lastWorkedTo,
+    required this.sort,
+  });
+
+  @override
+  int get hashCode =>
+      text.hashCode ^
+      selected.hashCode ^
+      city.hashCode ^
+      hasValidationErrors.hashCode ^
+      tags.hashCode ^
+      lastWorkedFrom.hashCode ^
+      lastWorkedTo.hashCode ^
+      sort.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WorkerQuery &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          selected == other.selected &&
+          city == other.city &&
+          hasValidationErrors == other.hasValidationErrors &&
+          tags == other.tags &&
+          lastWorkedFrom == other.lastWorkedFrom &&
+          lastWorkedTo == other.lastWorkedTo &&
+          sort == other.sort;
+}
+
+class WorkerPage {
+  final List<Worker> workers;
+  final int total;
+  final int offset;
+
+  const WorkerPage({
+    required this.workers,
+    required this.total,
+    required this.offset,
+  });
+
+  @override
+  int get hashCode => workers.hashCode ^ total.hashCode ^ offset.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WorkerPage &&
+          runtimeType == other.runtimeType &&
+          workers == other.workers &&
+          total == other.total &&
+          offset == other.offset;
+}
+
+enum WorkerSortKey {
+  name,
+  city,
+  birthDate,
+  selected,
+  lastWorked,
+  ;
+}
@@ -5,6 +5,23 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:uuid/uuid.dart';
 
-String demoGreet({required String name, dynamic hint}) =>
-    RustLib.instance.api.demoGreet(name: name, hint: hint);
+enum RosterColumn {
+  name,
+  address,
+  taj,
+  taxnumber,
+  mothersname,
+  birthdate,
+  birthplace,
+  lastWorked,
+  reportedDays,
+  ;
+}
+
+enum RosterFormat {
+  csv,
+  xlsx,
+  ;
+}
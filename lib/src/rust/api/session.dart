@@ -0,0 +1,32 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.22.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:uuid/uuid.dart';
+
+class SelectionSession {
+  final String employer;
+  final String date;
+  final List<UuidValue> workerIds;
+
+  const SelectionSession({
+    required this.employer,
+    required this.date,
+    required this.workerIds,
+  });
+
+  @override
+  int get hashCode => employer.hashCode ^ date.hashCode ^ workerIds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SelectionSession &&
+          runtimeType == other.runtimeType &&
+          employer == other.employer &&
+          date == other.date &&
+          workerIds == other.workerIds;
+}
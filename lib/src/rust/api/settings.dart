@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.22.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:uuid/uuid.dart';
+
+class Employer {
+  final String taxNumber;
+  final String name;
+  final String phone;
+
+  const Employer({
+    required this.taxNumber,
+    required this.name,
+    required this.phone,
+  });
+
+  @override
+  int get hashCode => taxNumber.hashCode ^ name.hashCode ^ phone.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Employer &&
+          runtimeType == other.runtimeType &&
+          taxNumber == other.taxNumber &&
+          name == other.name &&
+          phone == other.phone;
+}
+
+class Settings {
+  final String operator;
+  final int backupKeep;
+  final int autoLockMinutes;
+  final int retentionYears;
+  final List<Employer> employers;
+
+  const Settings({
+    required this.operator,
+    required this.backupKeep,
+    required this.autoLockMinutes,
+    required this.retentionYears,
+    required this.employers,
+  });
+
+  @override
+  int get hashCode =>
+      operator.hashCode ^
+      backupKeep.hashCode ^
+      autoLockMinutes.hashCode ^
+      retentionYears.hashCode ^
+      employers.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Settings &&
+          runtimeType == other.runtimeType &&
+          operator == other.operator &&
+          backupKeep == other.backupKeep &&
+          autoLockMinutes == other.autoLockMinutes &&
+          retentionYears == other.retentionYears &&
+          employers == other.employers;
+}
@@ -4,19 +4,131 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'audit.dart';
+import 'backup.dart';
+import 'crew.dart';
+import 'draft.dart';
+import 'events.dart';
+import 'export_history.dart';
+import 'history.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:uuid/uuid.dart';
+import 'privacy.dart';
+import 'query.dart';
+import 'roster_export.dart';
+import 'session.dart';
+import 'settings.dart';
+import 'spreadsheet_import.dart';
 import 'worker.dart';
+import 'xml_import.dart';
 
-void exportXmlApi({required String to, dynamic hint}) =>
-    RustLib.instance.api.exportXmlApi(to: to, hint: hint);
+void exportXmlApi({required String to, required String date, dynamic hint}) =>
+    RustLib.instance.api.exportXmlApi(to: to, date: date, hint: hint);
+
+List<ExportRecord> getExportHistory({dynamic hint}) =>
+    RustLib.instance.api.getExportHistory(hint: hint);
+
+String getExportXml({required UuidValue id, dynamic hint}) =>
+    RustLib.instance.api.getExportXml(id: id, hint: hint);
+
+String resaveExport(
+    {required UuidValue id, required String to, dynamic hint}) =>
+    RustLib.instance.api.resaveExport(id: id, to: to, hint: hint);
+
+List<ReportDraft> getDrafts({dynamic hint}) =>
+    RustLib.instance.api.getDrafts(hint: hint);
+
+ReportDraft getDraft({required UuidValue id, dynamic hint}) =>
+    RustLib.instance.api.getDraft(id: id, hint: hint);
+
+ReportDraft createDraft(
+    {required String date, required String employer, dynamic hint}) =>
+    RustLib.instance.api
+        .createDraft(date: date, employer: employer, hint: hint);
+
+ReportDraft saveDraft({required ReportDraft draft, dynamic hint}) =>
+    RustLib.instance.api.saveDraft(draft: draft, hint: hint);
+
+void deleteDraft({required UuidValue id, dynamic hint}) =>
+    RustLib.instance.api.deleteDraft(id: id, hint: hint);
+
+List<DraftIssue> validateDraft({required UuidValue id, dynamic hint}) =>
+    RustLib.instance.api.validateDraft(id: id, hint: hint);
+
+String exportDraftXml(
+    {required UuidValue id, required String to, dynamic hint}) =>
+    RustLib.instance.api.exportDraftXml(id: id, to: to, hint: hint);
+
+void exportCrewXml(
+    {required UuidValue id,
+    required String to,
+    required String date,
+    dynamic hint}) =>
+    RustLib.instance.api.exportCrewXml(id: id, to: to, date: date, hint: hint);
+
+List<Crew> getCrews({dynamic hint}) =>
+    RustLib.instance.api.getCrews(hint: hint);
+
+List<Worker> getCrewWorkers({required UuidValue id, dynamic hint}) =>
+    RustLib.instance.api.getCrewWorkers(id: id, hint: hint);
+
+Crew createCrew(
+    {required String name, required List<UuidValue> workerIds, dynamic hint}) =>
+    RustLib.instance.api
+        .createCrew(name: name, workerIds: workerIds, hint: hint);
+
+Crew createCrewFromSelection({required String name, dynamic hint}) =>
+    RustLib.instance.api.createCrewFromSelection(name: name, hint: hint);
+
+Crew updateCrew({required Crew crew, dynamic hint}) =>
+    RustLib.instance.api.updateCrew(crew: crew, hint: hint);
+
+void deleteCrew({required UuidValue id, dynamic hint}) =>
+    RustLib.instance.api.deleteCrew(id: id, hint: hint);
+
+int applyCrew({required UuidValue id, required bool replace, dynamic hint}) =>
+    RustLib.instance.api.applyCrew(id: id, replace: replace, hint: hint);
 
 List<Worker> getWorkers({dynamic hint}) =>
     RustLib.instance.api.getWorkers(hint: hint);
 
+List<Worker> queryWorkersApi({required WorkerQuery query, dynamic hint}) =>
+    RustLib.instance.api.queryWorkersApi(query: query, hint: hint);
+
+String exportRoster(
+    {required WorkerQuery query,
+    required List<RosterColumn> columns,
+    required RosterFormat format,
+    required String to,
+    dynamic hint}) =>
+    RustLib.instance.api.exportRoster(
+        query: query, columns: columns, format: format, to: to, hint: hint);
+
+List<Worker> findDuplicateWorkers({required Worker worker, dynamic hint}) =>
+    RustLib.instance.api.findDuplicateWorkers(worker: worker, hint: hint);
+
+WorkerPage getWorkersPage(
+    {required WorkerQuery query,
+    required int offset,
+    required int limit,
+    dynamic hint}) =>
+    RustLib.instance.api
+        .getWorkersPage(query: query, offset: offset, limit: limit, hint: hint);
+
+Future<WorkerPage> fetchWorkersPage(
+    {required WorkerQuery query,
+    required int offset,
+    required int limit,
+    dynamic hint}) =>
+    RustLib.instance.api.fetchWorkersPage(
+        query: query, offset: offset, limit: limit, hint: hint);
+
 void addWorker({required Worker worker, dynamic hint}) =>
     RustLib.instance.api.addWorker(worker: worker, hint: hint);
 
+Stream<DbEvent> subscribeEvents({dynamic hint}) =>
+    RustLib.instance.api.subscribeEvents(hint: hint);
+
 Worker getEmptyWorker({dynamic hint}) =>
     RustLib.instance.api.getEmptyWorker(hint: hint);
 
@@ -25,3 +137,177 @@ void updateWorker({required Worker worker, dynamic hint}) =>
 
 void removeWorkerApi({required Worker worker, dynamic hint}) =>
     RustLib.instance.api.removeWorkerApi(worker: worker, hint: hint);
+
+SelectionSession getSelectionSession({dynamic hint}) =>
+    RustLib.instance.api.getSelectionSession(hint: hint);
+
+void openSelectionSession(
+    {required String employer, required String date, dynamic hint}) =>
+    RustLib.instance.api
+        .openSelectionSession(employer: employer, date: date, hint: hint);
+
+List<Worker> getSelectedWorkers({dynamic hint}) =>
+    RustLib.instance.api.getSelectedWorkers(hint: hint);
+
+void setWorkerSelected(
+    {required UuidValue id, required bool selected, dynamic hint}) =>
+    RustLib.instance.api
+        .setWorkerSelected(id: id, selected: selected, hint: hint);
+
+int selectAllWorkers({dynamic hint}) =>
+    RustLib.instance.api.selectAllWorkers(hint: hint);
+
+int clearSelection({dynamic hint}) =>
+    RustLib.instance.api.clearSelection(hint: hint);
+
+int invertSelection({dynamic hint}) =>
+    RustLib.instance.api.invertSelection(hint: hint);
+
+int selectWorkersByIds(
+    {required List<UuidValue> ids, required bool selected, dynamic hint}) =>
+    RustLib.instance.api
+        .selectWorkersByIds(ids: ids, selected: selected, hint: hint);
+
+int selectWorkersByQuery(
+    {required WorkerQuery query, required bool selected, dynamic hint}) =>
+    RustLib.instance.api
+        .selectWorkersByQuery(query: query, selected: selected, hint: hint);
+
+HistoryItem? undo({dynamic hint}) => RustLib.instance.api.undo(hint: hint);
+
+HistoryItem? redo({dynamic hint}) => RustLib.instance.api.redo(hint: hint);
+
+bool canUndo({dynamic hint}) => RustLib.instance.api.canUndo(hint: hint);
+
+bool canRedo({dynamic hint}) => RustLib.instance.api.canRedo(hint: hint);
+
+List<HistoryItem> getHistory({required int limit, dynamic hint}) =>
+    RustLib.instance.api.getHistory(limit: limit, hint: hint);
+
+List<AuditEntry> getAuditLog(
+    {required UuidValue? workerId,
+    required String? from,
+    required String? to,
+    dynamic hint}) =>
+    RustLib.instance.api
+        .getAuditLog(workerId: workerId, from: from, to: to, hint: hint);
+
+SubjectReport getWorkerDataReport({required UuidValue id, dynamic hint}) =>
+    RustLib.instance.api.getWorkerDataReport(id: id, hint: hint);
+
+List<String> exportWorkerData(
+    {required UuidValue id, required String to, dynamic hint}) =>
+    RustLib.instance.api.exportWorkerData(id: id, to: to, hint: hint);
+
+List<RetentionCandidate> previewRetention({dynamic hint}) =>
+    RustLib.instance.api.previewRetention(hint: hint);
+
+int runRetention({dynamic hint}) =>
+    RustLib.instance.api.runRetention(hint: hint);
+
+Settings getSettings({dynamic hint}) =>
+    RustLib.instance.api.getSettings(hint: hint);
+
+void setSettings({required Settings settings, dynamic hint}) =>
+    RustLib.instance.api.setSettings(settings: settings, hint: hint);
+
+List<Snapshot> listSnapshots({dynamic hint}) =>
+    RustLib.instance.api.listSnapshots(hint: hint);
+
+Snapshot createSnapshot({dynamic hint}) =>
+    RustLib.instance.api.createSnapshot(hint: hint);
+
+void restoreSnapshot({required String id, dynamic hint}) =>
+    RustLib.instance.api.restoreSnapshot(id: id, hint: hint);
+
+void exportBackupFile(
+    {required String path, required String password, dynamic hint}) =>
+    RustLib.instance.api
+        .exportBackupFile(path: path, password: password, hint: hint);
+
+ImportSummary previewBackupImport(
+    {required String path,
+    required String password,
+    required ImportMode mode,
+    dynamic hint}) =>
+    RustLib.instance.api.previewBackupImport(
+        path: path, password: password, mode: mode, hint: hint);
+
+ImportSummary importBackupFile(
+    {required String path,
+    required String password,
+    required ImportMode mode,
+    dynamic hint}) =>
+    RustLib.instance.api.importBackupFile(
+        path: path, password: password, mode: mode, hint: hint);
+
+List<ImportProfile> getImportProfiles({dynamic hint}) =>
+    RustLib.instance.api.getImportProfiles(hint: hint);
+
+ImportProfile saveImportProfile(
+    {required ImportProfile profile, dynamic hint}) =>
+    RustLib.instance.api.saveImportProfile(profile: profile, hint: hint);
+
+void deleteImportProfile({required String name, dynamic hint}) =>
+    RustLib.instance.api.deleteImportProfile(name: name, hint: hint);
+
+List<String> getSpreadsheetHeaders({required String path, dynamic hint}) =>
+    RustLib.instance.api.getSpreadsheetHeaders(path: path, hint: hint);
+
+SpreadsheetImport previewSpreadsheetImport(
+    {required String path, required ImportProfile profile, dynamic hint}) =>
+    RustLib.instance.api
+        .previewSpreadsheetImport(path: path, profile: profile, hint: hint);
+
+SpreadsheetImport importSpreadsheet(
+    {required String path, required ImportProfile profile, dynamic hint}) =>
+    RustLib.instance.api
+        .importSpreadsheet(path: path, profile: profile, hint: hint);
+
+XmlImportSummary previewXmlImport(
+    {required List<String> paths, dynamic hint}) =>
+    RustLib.instance.api.previewXmlImport(paths: paths, hint: hint);
+
+XmlImportSummary importXmlFiles({required List<String> paths, dynamic hint}) =>
+    RustLib.instance.api.importXmlFiles(paths: paths, hint: hint);
+
+bool isStorageEncrypted({dynamic hint}) =>
+    RustLib.instance.api.isStorageEncrypted(hint: hint);
+
+bool isStorageUnlocked({dynamic hint}) =>
+    RustLib.instance.api.isStorageUnlocked(hint: hint);
+
+void unlockStorage({required String passphrase, dynamic hint}) =>
+    RustLib.instance.api.unlockStorage(passphrase: passphrase, hint: hint);
+
+void enableStorageEncryption({required String passphrase, dynamic hint}) =>
+    RustLib.instance.api
+        .enableStorageEncryption(passphrase: passphrase, hint: hint);
+
+void changeStoragePassphrase(
+    {required String oldPassphrase,
+    required String newPassphrase,
+    dynamic hint}) =>
+    RustLib.instance.api.changeStoragePassphrase(
+        oldPassphrase: oldPassphrase, newPassphrase: newPassphrase, hint: hint);
+
+void disableStorageEncryption({required String passphrase, dynamic hint}) =>
+    RustLib.instance.api
+        .disableStorageEncryption(passphrase: passphrase, hint: hint);
+
+bool hasPin({dynamic hint}) => RustLib.instance.api.hasPin(hint: hint);
+
+bool isLocked({dynamic hint}) => RustLib.instance.api.isLocked(hint: hint);
+
+void lockApp({dynamic hint}) => RustLib.instance.api.lockApp(hint: hint);
+
+void unlockApp({required String pin, dynamic hint}) =>
+    RustLib.instance.api.unlockApp(pin: pin, hint: hint);
+
+void setPin(
+    {required String? currentPin, required String newPin, dynamic hint}) =>
+    RustLib.instance.api
+        .setPin(currentPin: currentPin, newPin: newPin, hint: hint);
+
+void removePin({required String pin, dynamic hint}) =>
+    RustLib.instance.api.removePin(pin: pin, hint: hint);
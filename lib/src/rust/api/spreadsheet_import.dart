@@ -0,0 +1,110 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.22.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:uuid/uuid.dart';
+import 'worker.dart';
+
+class ColumnMapping {
+  final String header;
+  final WorkerField field;
+
+  const ColumnMapping({
+    required this.header,
+    required this.field,
+  });
+
+  @override
+  int get hashCode => header.hashCode ^ field.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ColumnMapping &&
+          runtimeType == other.runtimeType &&
+          header == other.header &&
+          field == other.field;
+}
+
+class ImportProfile {
+  final String name;
+  final List<ColumnMapping> columns;
+
+  const ImportProfile({
+    required this.name,
+    required this.columns,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ columns.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportProfile &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          columns == other.columns;
+}
+
+class ImportRow {
+  final int row;
+  final Worker worker;
+  final List<String> errors;
+
+  const ImportRow({
+    required this.row,
+    required this.worker,
+    required this.errors,
+  });
+
+  @override
+  int get hashCode => row.hashCode ^ worker.hashCode ^ errors.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportRow &&
+          runtimeType == other.runtimeType &&
+          row == other.row &&
+          worker == other.worker &&
+          errors == other.errors;
+}
+
+class SpreadsheetImport {
+  final List<ImportRow> rows;
+  final int valid;
+
+  const SpreadsheetImport({
+    required this.rows,
+    required this.valid,
+  });
+
+  @override
+  int get hashCode => rows.hashCode ^ valid.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SpreadsheetImport &&
+          runtimeType == other.runtimeType &&
+          rows == other.rows &&
+          valid == other.valid;
+}
+
+enum WorkerField {
+  name,
+  taj,
+  taxnumber,
+  mothersname,
+  birthdate,
+  birthplace,
+  zip,
+  city,
+  street,
+  tags,
+  ;
+}
@@ -1,7 +1,7 @@
 // This file is automatically generated, so please do not edit it.
 // Generated by `flutter_rust_bridge`@ 2.0.0-dev.22.
 
-// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import, camel_case_types
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
@@ -19,6 +19,9 @@ class Worker {
   String city;
   String street;
   bool isSelected;
+  final DateTime? anonymizedAt;
+  final int revision;
+  List<String> tags;
 
   Worker({
     required this.id,
@@ -32,6 +35,9 @@ class Worker {
     required this.city,
     required this.street,
     required this.isSelected,
+    required this.anonymizedAt,
+    required this.revision,
+    required this.tags,
   });
 
   Worker cloned({dynamic hint}) => RustLib.instance.api.workerCloned(
@@ -49,6 +55,11 @@ class Worker {
         to: to,
       );
 
+  List<String> validationErrors({dynamic hint}) =>
+      RustLib.instance.api.workerValidationErrors(
+        that: this,
+      );
+
   @override
   int get hashCode =>
       id.hashCode ^
@@ -61,7 +72,10 @@ class Worker {
       zip.hashCode ^
       city.hashCode ^
       street.hashCode ^
-      isSelected.hashCode;
+      isSelected.hashCode ^
+      anonymizedAt.hashCode ^
+      revision.hashCode ^
+      tags.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -78,5 +92,30 @@ class Worker {
           zip == other.zip &&
           city == other.city &&
           street == other.street &&
-          isSelected == other.isSelected;
+          isSelected == other.isSelected &&
+          anonymizedAt == other.anonymizedAt &&
+          revision == other.revision &&
+          tags == other.tags;
+}
+
+sealed class UpdateError implements FrbException {
+  const UpdateError._();
+
+  const factory UpdateError.conflict(
+      {required Worker local, required Worker remote}) = UpdateError_Conflict;
+  const factory UpdateError.failed(String field0) = UpdateError_Failed;
+}
+
+class UpdateError_Conflict extends UpdateError {
+  final Worker local;
+  final Worker remote;
+
+  const UpdateError_Conflict({required this.local, required this.remote})
+      : super._();
+}
+
+class UpdateError_Failed extends UpdateError {
+  final String field0;
+
+  const UpdateError_Failed(this.field0) : super._();
 }
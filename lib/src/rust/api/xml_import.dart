@@ -0,0 +1,47 @@
+// This file is automatically generated, so please do not edit it.
+// Generated by `flutter_rust_bridge`@ 2.0.0-dev.22.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:uuid/uuid.dart';
+
+class XmlImportSummary {
+  final int files;
+  final List<String> skipped;
+  final List<String> added;
+  final int matched;
+  final int reports;
+  final List<String> errors;
+
+  const XmlImportSummary({
+    required this.files,
+    required this.skipped,
+    required this.added,
+    required this.matched,
+    required this.reports,
+    required this.errors,
+  });
+
+  @override
+  int get hashCode =>
+      files.hashCode ^
+      skipped.hashCode ^
+      added.hashCode ^
+      matched.hashCode ^
+      reports.hashCode ^
+      errors.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is XmlImportSummary &&
+          runtimeType == other.runtimeType &&
+          files == other.files &&
+          skipped == other.skipped &&
+          added == other.added &&
+          matched == other.matched &&
+          reports == other.reports &&
+          errors == other.errors;
+}
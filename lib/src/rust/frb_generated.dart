@@ -3,8 +3,22 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/audit.dart';
+import 'api/backup.dart';
+import 'api/crew.dart';
+import 'api/draft.dart';
+import 'api/events.dart';
+import 'api/export_history.dart';
+import 'api/history.dart';
+import 'api/privacy.dart';
+import 'api/query.dart';
+import 'api/roster_export.dart';
+import 'api/session.dart';
+import 'api/settings.dart';
 import 'api/simple.dart';
+import 'api/spreadsheet_import.dart';
 import 'api/worker.dart';
+import 'api/xml_import.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.io.dart' if (dart.library.html) 'frb_generated.web.dart';
@@ -68,24 +82,215 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 abstract class RustLibApi extends BaseApi {
   void addWorker({required Worker worker, dynamic hint});
 
-  void exportXmlApi({required String to, dynamic hint});
+  int applyCrew({required UuidValue id, required bool replace, dynamic hint});
+
+  bool canRedo({dynamic hint});
+
+  bool canUndo({dynamic hint});
+
+  void changeStoragePassphrase(
+      {required String oldPassphrase,
+      required String newPassphrase,
+      dynamic hint});
+
+  int clearSelection({dynamic hint});
+
+  Crew createCrew(
+      {required String name, required List<UuidValue> workerIds, dynamic hint});
+
+  Crew createCrewFromSelection({required String name, dynamic hint});
+
+  ReportDraft createDraft(
+      {required String date, required String employer, dynamic hint});
+
+  Snapshot createSnapshot({dynamic hint});
+
+  void deleteCrew({required UuidValue id, dynamic hint});
+
+  void deleteDraft({required UuidValue id, dynamic hint});
+
+  void deleteImportProfile({required String name, dynamic hint});
+
+  void disableStorageEncryption({required String passphrase, dynamic hint});
+
+  void enableStorageEncryption({required String passphrase, dynamic hint});
+
+  void exportBackupFile(
+      {required String path, required String password, dynamic hint});
+
+  void exportCrewXml(
+      {required UuidValue id,
+      required String to,
+      required String date,
+      dynamic hint});
+
+  String exportDraftXml(
+      {required UuidValue id, required String to, dynamic hint});
+
+  String exportRoster(
+      {required WorkerQuery query,
+      required List<RosterColumn> columns,
+      required RosterFormat format,
+      required String to,
+      dynamic hint});
+
+  List<String> exportWorkerData(
+      {required UuidValue id, required String to, dynamic hint});
+
+  void exportXmlApi({required String to, required String date, dynamic hint});
+
+  Future<WorkerPage> fetchWorkersPage(
+      {required WorkerQuery query,
+      required int offset,
+      required int limit,
+      dynamic hint});
+
+  List<Worker> findDuplicateWorkers({required Worker worker, dynamic hint});
+
+  List<AuditEntry> getAuditLog(
+      {required UuidValue? workerId,
+      required String? from,
+      required String? to,
+      dynamic hint});
+
+  List<Worker> getCrewWorkers({required UuidValue id, dynamic hint});
+
+  List<Crew> getCrews({dynamic hint});
+
+  ReportDraft getDraft({required UuidValue id, dynamic hint});
+
+  List<ReportDraft> getDrafts({dynamic hint});
 
   Worker getEmptyWorker({dynamic hint});
 
+  List<ExportRecord> getExportHistory({dynamic hint});
+
+  String getExportXml({required UuidValue id, dynamic hint});
+
+  List<HistoryItem> getHistory({required int limit, dynamic hint});
+
+  List<ImportProfile> getImportProfiles({dynamic hint});
+
+  List<Worker> getSelectedWorkers({dynamic hint});
+
+  SelectionSession getSelectionSession({dynamic hint});
+
+  Settings getSettings({dynamic hint});
+
+  List<String> getSpreadsheetHeaders({required String path, dynamic hint});
+
+  SubjectReport getWorkerDataReport({required UuidValue id, dynamic hint});
+
   List<Worker> getWorkers({dynamic hint});
 
+  WorkerPage getWorkersPage(
+      {required WorkerQuery query,
+      required int offset,
+      required int limit,
+      dynamic hint});
+
+  bool hasPin({dynamic hint});
+
+  ImportSummary importBackupFile(
+      {required String path,
+      required String password,
+      required ImportMode mode,
+      dynamic hint});
+
+  SpreadsheetImport importSpreadsheet(
+      {required String path, required ImportProfile profile, dynamic hint});
+
+  XmlImportSummary importXmlFiles({required List<String> paths, dynamic hint});
+
   Future<void> initApp({dynamic hint});
 
+  int invertSelection({dynamic hint});
+
+  bool isLocked({dynamic hint});
+
+  bool isStorageEncrypted({dynamic hint});
+
+  bool isStorageUnlocked({dynamic hint});
+
+  List<Snapshot> listSnapshots({dynamic hint});
+
+  void lockApp({dynamic hint});
+
+  void openSelectionSession(
+      {required String employer, required String date, dynamic hint});
+
+  ImportSummary previewBackupImport(
+      {required String path,
+      required String password,
+      required ImportMode mode,
+      dynamic hint});
+
+  List<RetentionCandidate> previewRetention({dynamic hint});
+
+  SpreadsheetImport previewSpreadsheetImport(
+      {required String path, required ImportProfile profile, dynamic hint});
+
+  XmlImportSummary previewXmlImport(
+      {required List<String> paths, dynamic hint});
+
+  List<Worker> queryWorkersApi({required WorkerQuery query, dynamic hint});
+
+  HistoryItem? redo({dynamic hint});
+
+  void removePin({required String pin, dynamic hint});
+
   void removeWorkerApi({required Worker worker, dynamic hint});
 
+  String resaveExport(
+      {required UuidValue id, required String to, dynamic hint});
+
+  void restoreSnapshot({required String id, dynamic hint});
+
+  int runRetention({dynamic hint});
+
+  ReportDraft saveDraft({required ReportDraft draft, dynamic hint});
+
+  ImportProfile saveImportProfile(
+      {required ImportProfile profile, dynamic hint});
+
+  int selectAllWorkers({dynamic hint});
+
+  int selectWorkersByIds(
+      {required List<UuidValue> ids, required bool selected, dynamic hint});
+
+  int selectWorkersByQuery(
+      {required WorkerQuery query, required bool selected, dynamic hint});
+
+  void setPin(
+      {required String? currentPin, required String newPin, dynamic hint});
+
+  void setSettings({required Settings settings, dynamic hint});
+
+  void setWorkerSelected(
+      {required UuidValue id, required bool selected, dynamic hint});
+
+  Stream<DbEvent> subscribeEvents({dynamic hint});
+
+  HistoryItem? undo({dynamic hint});
+
+  void unlockApp({required String pin, dynamic hint});
+
+  void unlockStorage({required String passphrase, dynamic hint});
+
+  Crew updateCrew({required Crew crew, dynamic hint});
+
   void updateWorker({required Worker worker, dynamic hint});
 
+  List<DraftIssue> validateDraft({required UuidValue id, dynamic hint});
+
   Worker workerCloned({required Worker that, dynamic hint});
 
   bool workerHasValidBirthdate({required Worker that, dynamic hint});
 
   Worker workerSetSelected(
       {required Worker that, required bool to, dynamic hint});
+
+  List<String> workerValidationErrors({required Worker that, dynamic hint});
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -102,11 +307,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_box_autoadd_worker(worker, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
+        decodeErrorData: sse_decode_String,
       ),
       constMeta: kAddWorkerConstMeta,
       argValues: [worker],
@@ -121,410 +326,4255 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  void exportXmlApi({required String to, dynamic hint}) {
+  int applyCrew({required UuidValue id, required bool replace, dynamic hint}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(to, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+        sse_encode_Uuid(id, serializer);
+        sse_encode_bool(replace, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_u_32,
         decodeErrorData: sse_decode_String,
       ),
-      constMeta: kExportXmlApiConstMeta,
-      argValues: [to],
+      constMeta: kApplyCrewConstMeta,
+      argValues: [id, replace],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kExportXmlApiConstMeta => const TaskConstMeta(
-        debugName: "export_xml_api",
-        argNames: ["to"],
+  TaskConstMeta get kApplyCrewConstMeta => const TaskConstMeta(
+        debugName: "apply_crew",
+        argNames: ["id", "replace"],
       );
 
   @override
-  Worker getEmptyWorker({dynamic hint}) {
+  bool canRedo({dynamic hint}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_worker,
+        decodeSuccessData: sse_decode_bool,
         decodeErrorData: null,
       ),
-      constMeta: kGetEmptyWorkerConstMeta,
+      constMeta: kCanRedoConstMeta,
       argValues: [],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kGetEmptyWorkerConstMeta => const TaskConstMeta(
-        debugName: "get_empty_worker",
+  TaskConstMeta get kCanRedoConstMeta => const TaskConstMeta(
+        debugName: "can_redo",
         argNames: [],
       );
 
   @override
-  List<Worker> getWorkers({dynamic hint}) {
+  bool canUndo({dynamic hint}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_worker,
+        decodeSuccessData: sse_decode_bool,
         decodeErrorData: null,
       ),
-      constMeta: kGetWorkersConstMeta,
+      constMeta: kCanUndoConstMeta,
       argValues: [],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kGetWorkersConstMeta => const TaskConstMeta(
-        debugName: "get_workers",
+  TaskConstMeta get kCanUndoConstMeta => const TaskConstMeta(
+        debugName: "can_undo",
         argNames: [],
       );
 
   @override
-  Future<void> initApp({dynamic hint}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void changeStoragePassphrase(
+      {required String oldPassphrase,
+      required String newPassphrase,
+      dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 7, port: port_);
+        sse_encode_String(oldPassphrase, serializer);
+        sse_encode_String(newPassphrase, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kInitAppConstMeta,
-      argValues: [],
+      constMeta: kChangeStoragePassphraseConstMeta,
+      argValues: [oldPassphrase, newPassphrase],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kInitAppConstMeta => const TaskConstMeta(
-        debugName: "init_app",
-        argNames: [],
+  TaskConstMeta get kChangeStoragePassphraseConstMeta => const TaskConstMeta(
+        debugName: "change_storage_passphrase",
+        argNames: ["old_passphrase", "new_passphrase"],
       );
 
   @override
-  void removeWorkerApi({required Worker worker, dynamic hint}) {
+  int clearSelection({dynamic hint}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_worker(worker, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kRemoveWorkerApiConstMeta,
-      argValues: [worker],
+      constMeta: kClearSelectionConstMeta,
+      argValues: [],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kRemoveWorkerApiConstMeta => const TaskConstMeta(
-        debugName: "remove_worker_api",
-        argNames: ["worker"],
+  TaskConstMeta get kClearSelectionConstMeta => const TaskConstMeta(
+        debugName: "clear_selection",
+        argNames: [],
       );
 
   @override
-  void updateWorker({required Worker worker, dynamic hint}) {
+  Crew createCrew(
+      {required String name,
+      required List<UuidValue> workerIds,
+      dynamic hint}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_worker(worker, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+        sse_encode_String(name, serializer);
+        sse_encode_list_Uuid(workerIds, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_crew,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kUpdateWorkerConstMeta,
-      argValues: [worker],
+      constMeta: kCreateCrewConstMeta,
+      argValues: [name, workerIds],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kUpdateWorkerConstMeta => const TaskConstMeta(
-        debugName: "update_worker",
-        argNames: ["worker"],
+  TaskConstMeta get kCreateCrewConstMeta => const TaskConstMeta(
+        debugName: "create_crew",
+        argNames: ["name", "worker_ids"],
       );
 
   @override
-  Worker workerCloned({required Worker that, dynamic hint}) {
+  Crew createCrewFromSelection({required String name, dynamic hint}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_worker(that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+        sse_encode_String(name, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_worker,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_crew,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kWorkerClonedConstMeta,
-      argValues: [that],
+      constMeta: kCreateCrewFromSelectionConstMeta,
+      argValues: [name],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kWorkerClonedConstMeta => const TaskConstMeta(
-        debugName: "Worker_cloned",
-        argNames: ["that"],
+  TaskConstMeta get kCreateCrewFromSelectionConstMeta => const TaskConstMeta(
+        debugName: "create_crew_from_selection",
+        argNames: ["name"],
       );
 
   @override
-  bool workerHasValidBirthdate({required Worker that, dynamic hint}) {
+  ReportDraft createDraft(
+      {required String date, required String employer, dynamic hint}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_worker(that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+        sse_encode_String(date, serializer);
+        sse_encode_String(employer, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_report_draft,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kWorkerHasValidBirthdateConstMeta,
-      argValues: [that],
+      constMeta: kCreateDraftConstMeta,
+      argValues: [date, employer],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kWorkerHasValidBirthdateConstMeta => const TaskConstMeta(
-        debugName: "Worker_has_valid_birthdate",
-        argNames: ["that"],
+  TaskConstMeta get kCreateDraftConstMeta => const TaskConstMeta(
+        debugName: "create_draft",
+        argNames: ["date", "employer"],
       );
 
   @override
-  Worker workerSetSelected(
-      {required Worker that, required bool to, dynamic hint}) {
+  Snapshot createSnapshot({dynamic hint}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_worker(that, serializer);
-        sse_encode_bool(to, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_worker,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_snapshot,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kWorkerSetSelectedConstMeta,
-      argValues: [that, to],
+      constMeta: kCreateSnapshotConstMeta,
+      argValues: [],
       apiImpl: this,
       hint: hint,
     ));
   }
 
-  TaskConstMeta get kWorkerSetSelectedConstMeta => const TaskConstMeta(
-        debugName: "Worker_set_selected",
-        argNames: ["that", "to"],
+  TaskConstMeta get kCreateSnapshotConstMeta => const TaskConstMeta(
+        debugName: "create_snapshot",
+        argNames: [],
       );
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  @override
+  void deleteCrew({required UuidValue id, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Uuid(id, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kDeleteCrewConstMeta,
+      argValues: [id],
+      apiImpl: this,
+      hint: hint,
+    ));
   }
 
-  @protected
-  UuidValue dco_decode_Uuid(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return UuidValue.fromByteList(dco_decode_list_prim_u_8_strict(raw));
-  }
+  TaskConstMeta get kDeleteCrewConstMeta => const TaskConstMeta(
+        debugName: "delete_crew",
+        argNames: ["id"],
+      );
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  @override
+  void deleteDraft({required UuidValue id, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Uuid(id, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kDeleteDraftConstMeta,
+      argValues: [id],
+      apiImpl: this,
+      hint: hint,
+    ));
   }
 
-  @protected
-  Worker dco_decode_box_autoadd_worker(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_worker(raw);
-  }
+  TaskConstMeta get kDeleteDraftConstMeta => const TaskConstMeta(
+        debugName: "delete_draft",
+        argNames: ["id"],
+      );
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  @override
+  void deleteImportProfile({required String name, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kDeleteImportProfileConstMeta,
+      argValues: [name],
+      apiImpl: this,
+      hint: hint,
+    ));
   }
 
-  @protected
-  List<Worker> dco_decode_list_worker(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_worker).toList();
-  }
+  TaskConstMeta get kDeleteImportProfileConstMeta => const TaskConstMeta(
+        debugName: "delete_import_profile",
+        argNames: ["name"],
+      );
 
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  @override
+  void disableStorageEncryption({required String passphrase, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(passphrase, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kDisableStorageEncryptionConstMeta,
+      argValues: [passphrase],
+      apiImpl: this,
+      hint: hint,
+    ));
   }
 
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  TaskConstMeta get kDisableStorageEncryptionConstMeta => const TaskConstMeta(
+        debugName: "disable_storage_encryption",
+        argNames: ["passphrase"],
+      );
+
+  @override
+  void enableStorageEncryption({required String passphrase, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(passphrase, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kEnableStorageEncryptionConstMeta,
+      argValues: [passphrase],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kEnableStorageEncryptionConstMeta => const TaskConstMeta(
+        debugName: "enable_storage_encryption",
+        argNames: ["passphrase"],
+      );
+
+  @override
+  void exportBackupFile(
+      {required String path, required String password, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(password, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kExportBackupFileConstMeta,
+      argValues: [path, password],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kExportBackupFileConstMeta => const TaskConstMeta(
+        debugName: "export_backup_file",
+        argNames: ["path", "password"],
+      );
+
+  @override
+  void exportCrewXml(
+      {required UuidValue id,
+      required String to,
+      required String date,
+      dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Uuid(id, serializer);
+        sse_encode_String(to, serializer);
+        sse_encode_String(date, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kExportCrewXmlConstMeta,
+      argValues: [id, to, date],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kExportCrewXmlConstMeta => const TaskConstMeta(
+        debugName: "export_crew_xml",
+        argNames: ["id", "to", "date"],
+      );
+
+  @override
+  String exportDraftXml(
+      {required UuidValue id, required String to, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Uuid(id, serializer);
+        sse_encode_String(to, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kExportDraftXmlConstMeta,
+      argValues: [id, to],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kExportDraftXmlConstMeta => const TaskConstMeta(
+        debugName: "export_draft_xml",
+        argNames: ["id", "to"],
+      );
+
+  @override
+  String exportRoster(
+      {required WorkerQuery query,
+      required List<RosterColumn> columns,
+      required RosterFormat format,
+      required String to,
+      dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_worker_query(query, serializer);
+        sse_encode_list_roster_column(columns, serializer);
+        sse_encode_roster_format(format, serializer);
+        sse_encode_String(to, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kExportRosterConstMeta,
+      argValues: [query, columns, format, to],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kExportRosterConstMeta => const TaskConstMeta(
+        debugName: "export_roster",
+        argNames: ["query", "columns", "format", "to"],
+      );
+
+  @override
+  List<String> exportWorkerData(
+      {required UuidValue id, required String to, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Uuid(id, serializer);
+        sse_encode_String(to, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kExportWorkerDataConstMeta,
+      argValues: [id, to],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kExportWorkerDataConstMeta => const TaskConstMeta(
+        debugName: "export_worker_data",
+        argNames: ["id", "to"],
+      );
+
+  @override
+  void exportXmlApi({required String to, required String date, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(to, serializer);
+        sse_encode_String(date, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kExportXmlApiConstMeta,
+      argValues: [to, date],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kExportXmlApiConstMeta => const TaskConstMeta(
+        debugName: "export_xml_api",
+        argNames: ["to", "date"],
+      );
+
+  @override
+  Future<WorkerPage> fetchWorkersPage(
+      {required WorkerQuery query,
+      required int offset,
+      required int limit,
+      dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_worker_query(query, serializer);
+        sse_encode_u_32(offset, serializer);
+        sse_encode_u_32(limit, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 25, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_worker_page,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kFetchWorkersPageConstMeta,
+      argValues: [query, offset, limit],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kFetchWorkersPageConstMeta => const TaskConstMeta(
+        debugName: "fetch_workers_page",
+        argNames: ["query", "offset", "limit"],
+      );
+
+  @override
+  List<Worker> findDuplicateWorkers({required Worker worker, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_worker(worker, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_worker,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kFindDuplicateWorkersConstMeta,
+      argValues: [worker],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kFindDuplicateWorkersConstMeta => const TaskConstMeta(
+        debugName: "find_duplicate_workers",
+        argNames: ["worker"],
+      );
+
+  @override
+  List<AuditEntry> getAuditLog(
+      {required UuidValue? workerId,
+      required String? from,
+      required String? to,
+      dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_Uuid(workerId, serializer);
+        sse_encode_opt_String(from, serializer);
+        sse_encode_opt_String(to, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_audit_entry,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetAuditLogConstMeta,
+      argValues: [workerId, from, to],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetAuditLogConstMeta => const TaskConstMeta(
+        debugName: "get_audit_log",
+        argNames: ["worker_id", "from", "to"],
+      );
+
+  @override
+  List<Worker> getCrewWorkers({required UuidValue id, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Uuid(id, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_worker,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetCrewWorkersConstMeta,
+      argValues: [id],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetCrewWorkersConstMeta => const TaskConstMeta(
+        debugName: "get_crew_workers",
+        argNames: ["id"],
+      );
+
+  @override
+  List<Crew> getCrews({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_crew,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetCrewsConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetCrewsConstMeta => const TaskConstMeta(
+        debugName: "get_crews",
+        argNames: [],
+      );
+
+  @override
+  ReportDraft getDraft({required UuidValue id, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Uuid(id, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_report_draft,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetDraftConstMeta,
+      argValues: [id],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetDraftConstMeta => const TaskConstMeta(
+        debugName: "get_draft",
+        argNames: ["id"],
+      );
+
+  @override
+  List<ReportDraft> getDrafts({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_report_draft,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetDraftsConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetDraftsConstMeta => const TaskConstMeta(
+        debugName: "get_drafts",
+        argNames: [],
+      );
+
+  @override
+  Worker getEmptyWorker({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_worker,
+        decodeErrorData: null,
+      ),
+      constMeta: kGetEmptyWorkerConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetEmptyWorkerConstMeta => const TaskConstMeta(
+        debugName: "get_empty_worker",
+        argNames: [],
+      );
+
+  @override
+  List<ExportRecord> getExportHistory({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_export_record,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetExportHistoryConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetExportHistoryConstMeta => const TaskConstMeta(
+        debugName: "get_export_history",
+        argNames: [],
+      );
+
+  @override
+  String getExportXml({required UuidValue id, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Uuid(id, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetExportXmlConstMeta,
+      argValues: [id],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetExportXmlConstMeta => const TaskConstMeta(
+        debugName: "get_export_xml",
+        argNames: ["id"],
+      );
+
+  @override
+  List<HistoryItem> getHistory({required int limit, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_usize(limit, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_history_item,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetHistoryConstMeta,
+      argValues: [limit],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetHistoryConstMeta => const TaskConstMeta(
+        debugName: "get_history",
+        argNames: ["limit"],
+      );
+
+  @override
+  List<ImportProfile> getImportProfiles({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_import_profile,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetImportProfilesConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetImportProfilesConstMeta => const TaskConstMeta(
+        debugName: "get_import_profiles",
+        argNames: [],
+      );
+
+  @override
+  List<Worker> getSelectedWorkers({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_worker,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetSelectedWorkersConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetSelectedWorkersConstMeta => const TaskConstMeta(
+        debugName: "get_selected_workers",
+        argNames: [],
+      );
+
+  @override
+  SelectionSession getSelectionSession({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_selection_session,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetSelectionSessionConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetSelectionSessionConstMeta => const TaskConstMeta(
+        debugName: "get_selection_session",
+        argNames: [],
+      );
+
+  @override
+  Settings getSettings({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_settings,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetSettingsConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetSettingsConstMeta => const TaskConstMeta(
+        debugName: "get_settings",
+        argNames: [],
+      );
+
+  @override
+  List<String> getSpreadsheetHeaders({required String path, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetSpreadsheetHeadersConstMeta,
+      argValues: [path],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetSpreadsheetHeadersConstMeta => const TaskConstMeta(
+        debugName: "get_spreadsheet_headers",
+        argNames: ["path"],
+      );
+
+  @override
+  SubjectReport getWorkerDataReport({required UuidValue id, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Uuid(id, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_subject_report,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetWorkerDataReportConstMeta,
+      argValues: [id],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetWorkerDataReportConstMeta => const TaskConstMeta(
+        debugName: "get_worker_data_report",
+        argNames: ["id"],
+      );
+
+  @override
+  List<Worker> getWorkers({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_worker,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetWorkersConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetWorkersConstMeta => const TaskConstMeta(
+        debugName: "get_workers",
+        argNames: [],
+      );
+
+  @override
+  WorkerPage getWorkersPage(
+      {required WorkerQuery query,
+      required int offset,
+      required int limit,
+      dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_worker_query(query, serializer);
+        sse_encode_u_32(offset, serializer);
+        sse_encode_u_32(limit, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_worker_page,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kGetWorkersPageConstMeta,
+      argValues: [query, offset, limit],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kGetWorkersPageConstMeta => const TaskConstMeta(
+        debugName: "get_workers_page",
+        argNames: ["query", "offset", "limit"],
+      );
+
+  @override
+  bool hasPin({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kHasPinConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kHasPinConstMeta => const TaskConstMeta(
+        debugName: "has_pin",
+        argNames: [],
+      );
+
+  @override
+  ImportSummary importBackupFile(
+      {required String path,
+      required String password,
+      required ImportMode mode,
+      dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(password, serializer);
+        sse_encode_import_mode(mode, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_import_summary,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kImportBackupFileConstMeta,
+      argValues: [path, password, mode],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kImportBackupFileConstMeta => const TaskConstMeta(
+        debugName: "import_backup_file",
+        argNames: ["path", "password", "mode"],
+      );
+
+  @override
+  SpreadsheetImport importSpreadsheet(
+      {required String path, required ImportProfile profile, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_box_autoadd_import_profile(profile, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_spreadsheet_import,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kImportSpreadsheetConstMeta,
+      argValues: [path, profile],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kImportSpreadsheetConstMeta => const TaskConstMeta(
+        debugName: "import_spreadsheet",
+        argNames: ["path", "profile"],
+      );
+
+  @override
+  XmlImportSummary importXmlFiles({required List<String> paths, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_String(paths, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_xml_import_summary,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kImportXmlFilesConstMeta,
+      argValues: [paths],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kImportXmlFilesConstMeta => const TaskConstMeta(
+        debugName: "import_xml_files",
+        argNames: ["paths"],
+      );
+
+  @override
+  Future<void> initApp({dynamic hint}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 78, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kInitAppConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kInitAppConstMeta => const TaskConstMeta(
+        debugName: "init_app",
+        argNames: [],
+      );
+
+  @override
+  int invertSelection({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kInvertSelectionConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kInvertSelectionConstMeta => const TaskConstMeta(
+        debugName: "invert_selection",
+        argNames: [],
+      );
+
+  @override
+  bool isLocked({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kIsLockedConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kIsLockedConstMeta => const TaskConstMeta(
+        debugName: "is_locked",
+        argNames: [],
+      );
+
+  @override
+  bool isStorageEncrypted({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kIsStorageEncryptedConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kIsStorageEncryptedConstMeta => const TaskConstMeta(
+        debugName: "is_storage_encrypted",
+        argNames: [],
+      );
+
+  @override
+  bool isStorageUnlocked({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kIsStorageUnlockedConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kIsStorageUnlockedConstMeta => const TaskConstMeta(
+        debugName: "is_storage_unlocked",
+        argNames: [],
+      );
+
+  @override
+  List<Snapshot> listSnapshots({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_snapshot,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kListSnapshotsConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kListSnapshotsConstMeta => const TaskConstMeta(
+        debugName: "list_snapshots",
+        argNames: [],
+      );
+
+  @override
+  void lockApp({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kLockAppConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kLockAppConstMeta => const TaskConstMeta(
+        debugName: "lock_app",
+        argNames: [],
+      );
+
+  @override
+  void openSelectionSession(
+      {required String employer, required String date, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(employer, serializer);
+        sse_encode_String(date, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kOpenSelectionSessionConstMeta,
+      argValues: [employer, date],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kOpenSelectionSessionConstMeta => const TaskConstMeta(
+        debugName: "open_selection_session",
+        argNames: ["employer", "date"],
+      );
+
+  @override
+  ImportSummary previewBackupImport(
+      {required String path,
+      required String password,
+      required ImportMode mode,
+      dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(password, serializer);
+        sse_encode_import_mode(mode, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_import_summary,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kPreviewBackupImportConstMeta,
+      argValues: [path, password, mode],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kPreviewBackupImportConstMeta => const TaskConstMeta(
+        debugName: "preview_backup_import",
+        argNames: ["path", "password", "mode"],
+      );
+
+  @override
+  List<RetentionCandidate> previewRetention({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_retention_candidate,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kPreviewRetentionConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kPreviewRetentionConstMeta => const TaskConstMeta(
+        debugName: "preview_retention",
+        argNames: [],
+      );
+
+  @override
+  SpreadsheetImport previewSpreadsheetImport(
+      {required String path, required ImportProfile profile, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_box_autoadd_import_profile(profile, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_spreadsheet_import,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kPreviewSpreadsheetImportConstMeta,
+      argValues: [path, profile],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kPreviewSpreadsheetImportConstMeta => const TaskConstMeta(
+        debugName: "preview_spreadsheet_import",
+        argNames: ["path", "profile"],
+      );
+
+  @override
+  XmlImportSummary previewXmlImport(
+      {required List<String> paths, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_String(paths, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_xml_import_summary,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kPreviewXmlImportConstMeta,
+      argValues: [paths],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kPreviewXmlImportConstMeta => const TaskConstMeta(
+        debugName: "preview_xml_import",
+        argNames: ["paths"],
+      );
+
+  @override
+  List<Worker> queryWorkersApi({required WorkerQuery query, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_worker_query(query, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_worker,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kQueryWorkersApiConstMeta,
+      argValues: [query],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kQueryWorkersApiConstMeta => const TaskConstMeta(
+        debugName: "query_workers_api",
+        argNames: ["query"],
+      );
+
+  @override
+  HistoryItem? redo({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_history_item,
+        decodeErrorData: sse_decode_update_error,
+      ),
+      constMeta: kRedoConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kRedoConstMeta => const TaskConstMeta(
+        debugName: "redo",
+        argNames: [],
+      );
+
+  @override
+  void removePin({required String pin, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(pin, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kRemovePinConstMeta,
+      argValues: [pin],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kRemovePinConstMeta => const TaskConstMeta(
+        debugName: "remove_pin",
+        argNames: ["pin"],
+      );
+
+  @override
+  void removeWorkerApi({required Worker worker, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_worker(worker, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_update_error,
+      ),
+      constMeta: kRemoveWorkerApiConstMeta,
+      argValues: [worker],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kRemoveWorkerApiConstMeta => const TaskConstMeta(
+        debugName: "remove_worker_api",
+        argNames: ["worker"],
+      );
+
+  @override
+  String resaveExport(
+      {required UuidValue id, required String to, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Uuid(id, serializer);
+        sse_encode_String(to, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kResaveExportConstMeta,
+      argValues: [id, to],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kResaveExportConstMeta => const TaskConstMeta(
+        debugName: "resave_export",
+        argNames: ["id", "to"],
+      );
+
+  @override
+  void restoreSnapshot({required String id, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(id, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kRestoreSnapshotConstMeta,
+      argValues: [id],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kRestoreSnapshotConstMeta => const TaskConstMeta(
+        debugName: "restore_snapshot",
+        argNames: ["id"],
+      );
+
+  @override
+  int runRetention({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kRunRetentionConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kRunRetentionConstMeta => const TaskConstMeta(
+        debugName: "run_retention",
+        argNames: [],
+      );
+
+  @override
+  ReportDraft saveDraft({required ReportDraft draft, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_report_draft(draft, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_report_draft,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kSaveDraftConstMeta,
+      argValues: [draft],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kSaveDraftConstMeta => const TaskConstMeta(
+        debugName: "save_draft",
+        argNames: ["draft"],
+      );
+
+  @override
+  ImportProfile saveImportProfile(
+      {required ImportProfile profile, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_import_profile(profile, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_import_profile,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kSaveImportProfileConstMeta,
+      argValues: [profile],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kSaveImportProfileConstMeta => const TaskConstMeta(
+        debugName: "save_import_profile",
+        argNames: ["profile"],
+      );
+
+  @override
+  int selectAllWorkers({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kSelectAllWorkersConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kSelectAllWorkersConstMeta => const TaskConstMeta(
+        debugName: "select_all_workers",
+        argNames: [],
+      );
+
+  @override
+  int selectWorkersByIds(
+      {required List<UuidValue> ids, required bool selected, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_Uuid(ids, serializer);
+        sse_encode_bool(selected, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kSelectWorkersByIdsConstMeta,
+      argValues: [ids, selected],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kSelectWorkersByIdsConstMeta => const TaskConstMeta(
+        debugName: "select_workers_by_ids",
+        argNames: ["ids", "selected"],
+      );
+
+  @override
+  int selectWorkersByQuery(
+      {required WorkerQuery query, required bool selected, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_worker_query(query, serializer);
+        sse_encode_bool(selected, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kSelectWorkersByQueryConstMeta,
+      argValues: [query, selected],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kSelectWorkersByQueryConstMeta => const TaskConstMeta(
+        debugName: "select_workers_by_query",
+        argNames: ["query", "selected"],
+      );
+
+  @override
+  void setPin(
+      {required String? currentPin, required String newPin, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_String(currentPin, serializer);
+        sse_encode_String(newPin, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kSetPinConstMeta,
+      argValues: [currentPin, newPin],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kSetPinConstMeta => const TaskConstMeta(
+        debugName: "set_pin",
+        argNames: ["current_pin", "new_pin"],
+      );
+
+  @override
+  void setSettings({required Settings settings, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_settings(settings, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kSetSettingsConstMeta,
+      argValues: [settings],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kSetSettingsConstMeta => const TaskConstMeta(
+        debugName: "set_settings",
+        argNames: ["settings"],
+      );
+
+  @override
+  void setWorkerSelected(
+      {required UuidValue id, required bool selected, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Uuid(id, serializer);
+        sse_encode_bool(selected, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kSetWorkerSelectedConstMeta,
+      argValues: [id, selected],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kSetWorkerSelectedConstMeta => const TaskConstMeta(
+        debugName: "set_worker_selected",
+        argNames: ["id", "selected"],
+      );
+
+  @override
+  Stream<DbEvent> subscribeEvents({dynamic hint}) {
+    return handler.executeStream(StreamTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 27, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_db_event,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kSubscribeEventsConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kSubscribeEventsConstMeta => const TaskConstMeta(
+        debugName: "subscribe_events",
+        argNames: [],
+      );
+
+  @override
+  HistoryItem? undo({dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_history_item,
+        decodeErrorData: sse_decode_update_error,
+      ),
+      constMeta: kUndoConstMeta,
+      argValues: [],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kUndoConstMeta => const TaskConstMeta(
+        debugName: "undo",
+        argNames: [],
+      );
+
+  @override
+  void unlockApp({required String pin, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(pin, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kUnlockAppConstMeta,
+      argValues: [pin],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kUnlockAppConstMeta => const TaskConstMeta(
+        debugName: "unlock_app",
+        argNames: ["pin"],
+      );
+
+  @override
+  void unlockStorage({required String passphrase, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(passphrase, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kUnlockStorageConstMeta,
+      argValues: [passphrase],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kUnlockStorageConstMeta => const TaskConstMeta(
+        debugName: "unlock_storage",
+        argNames: ["passphrase"],
+      );
+
+  @override
+  Crew updateCrew({required Crew crew, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_crew(crew, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_crew,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kUpdateCrewConstMeta,
+      argValues: [crew],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kUpdateCrewConstMeta => const TaskConstMeta(
+        debugName: "update_crew",
+        argNames: ["crew"],
+      );
+
+  @override
+  void updateWorker({required Worker worker, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_worker(worker, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_update_error,
+      ),
+      constMeta: kUpdateWorkerConstMeta,
+      argValues: [worker],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kUpdateWorkerConstMeta => const TaskConstMeta(
+        debugName: "update_worker",
+        argNames: ["worker"],
+      );
+
+  @override
+  List<DraftIssue> validateDraft({required UuidValue id, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Uuid(id, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_draft_issue,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kValidateDraftConstMeta,
+      argValues: [id],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kValidateDraftConstMeta => const TaskConstMeta(
+        debugName: "validate_draft",
+        argNames: ["id"],
+      );
+
+  @override
+  Worker workerCloned({required Worker that, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_worker(that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_worker,
+        decodeErrorData: null,
+      ),
+      constMeta: kWorkerClonedConstMeta,
+      argValues: [that],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kWorkerClonedConstMeta => const TaskConstMeta(
+        debugName: "Worker_cloned",
+        argNames: ["that"],
+      );
+
+  @override
+  bool workerHasValidBirthdate({required Worker that, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_worker(that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kWorkerHasValidBirthdateConstMeta,
+      argValues: [that],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kWorkerHasValidBirthdateConstMeta => const TaskConstMeta(
+        debugName: "Worker_has_valid_birthdate",
+        argNames: ["that"],
+      );
+
+  @override
+  Worker workerSetSelected(
+      {required Worker that, required bool to, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_worker(that, serializer);
+        sse_encode_bool(to, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_worker,
+        decodeErrorData: null,
+      ),
+      constMeta: kWorkerSetSelectedConstMeta,
+      argValues: [that, to],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kWorkerSetSelectedConstMeta => const TaskConstMeta(
+        debugName: "Worker_set_selected",
+        argNames: ["that", "to"],
+      );
+
+  @override
+  List<String> workerValidationErrors({required Worker that, dynamic hint}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_worker(that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kWorkerValidationErrorsConstMeta,
+      argValues: [that],
+      apiImpl: this,
+      hint: hint,
+    ));
+  }
+
+  TaskConstMeta get kWorkerValidationErrorsConstMeta => const TaskConstMeta(
+        debugName: "Worker_validation_errors",
+        argNames: ["that"],
+      );
+
+  @protected
+  DateTime dco_decode_Chrono_Local(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeTimestamp(ts: dco_decode_i_64(raw), isUtc: false);
+  }
+
+  @protected
+  DateTime dco_decode_Chrono_Naive(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeTimestamp(ts: dco_decode_i_64(raw), isUtc: true);
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  UuidValue dco_decode_Uuid(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return UuidValue.fromByteList(dco_decode_list_prim_u_8_strict(raw));
+  }
+
+  @protected
+  AuditAction dco_decode_audit_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AuditAction.values[raw as int];
+  }
+
+  @protected
+  AuditEntry dco_decode_audit_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return AuditEntry(
+      id: dco_decode_Uuid(arr[0]),
+      timestamp: dco_decode_Chrono_Local(arr[1]),
+      operator: dco_decode_String(arr[2]),
+      action: dco_decode_audit_action(arr[3]),
+      workerIds: dco_decode_list_Uuid(arr[4]),
+      changes: dco_decode_list_field_change(arr[5]),
+      note: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  DateTime dco_decode_box_autoadd_Chrono_Local(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_Chrono_Local(raw);
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  Crew dco_decode_box_autoadd_crew(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_crew(raw);
+  }
+
+  @protected
+  HistoryItem dco_decode_box_autoadd_history_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_history_item(raw);
+  }
+
+  @protected
+  ImportProfile dco_decode_box_autoadd_import_profile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_import_profile(raw);
+  }
+
+  @protected
+  ReportDraft dco_decode_box_autoadd_report_draft(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_report_draft(raw);
+  }
+
+  @protected
+  Settings dco_decode_box_autoadd_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_settings(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  Worker dco_decode_box_autoadd_worker(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_worker(raw);
+  }
+
+  @protected
+  WorkerQuery dco_decode_box_autoadd_worker_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_worker_query(raw);
+  }
+
+  @protected
+  Worker dco_decode_box_worker(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_worker(raw);
+  }
+
+  @protected
+  ColumnMapping dco_decode_column_mapping(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ColumnMapping(
+      header: dco_decode_String(arr[0]),
+      field: dco_decode_worker_field(arr[1]),
+    );
+  }
+
+  @protected
+  Crew dco_decode_crew(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Crew(
+      id: dco_decode_Uuid(arr[0]),
+      name: dco_decode_String(arr[1]),
+      workerIds: dco_decode_list_Uuid(arr[2]),
+      updatedAt: dco_decode_Chrono_Local(arr[3]),
+    );
+  }
+
+  @protected
+  DbEvent dco_decode_db_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return DbEvent_WorkerAdded(worker: dco_decode_worker(raw[1]));
+      case 1:
+        return DbEvent_WorkerUpdated(worker: dco_decode_worker(raw[1]));
+      case 2:
+        return DbEvent_WorkerRemoved(id: dco_decode_Uuid(raw[1]));
+      case 3:
+        return DbEvent_SelectionChanged(
+            id: dco_decode_Uuid(raw[1]), selected: dco_decode_bool(raw[2]));
+      case 4:
+        return DbEvent_SelectionsChanged(
+            selected: dco_decode_list_Uuid(raw[1]),
+            unselected: dco_decode_list_Uuid(raw[2]));
+      case 5:
+        return DbEvent_ExportDone(
+            path: dco_decode_String(raw[1]),
+            workerIds: dco_decode_list_Uuid(raw[2]));
+      case 6:
+        return DbEvent_Conflict(
+            local: dco_decode_worker(raw[1]), deleted: dco_decode_bool(raw[2]));
+      case 7:
+        return DbEvent_Reloaded();
+      case 8:
+        return DbEvent_Changed(ids: dco_decode_list_Uuid(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  DraftIssue dco_decode_draft_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DraftIssue(
+      workerId: dco_decode_opt_Uuid(arr[0]),
+      message: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  DraftRow dco_decode_draft_row(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DraftRow(
+      workerId: dco_decode_Uuid(arr[0]),
+      overrides: dco_decode_row_override(arr[1]),
+    );
+  }
+
+  @protected
+  Employer dco_decode_employer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Employer(
+      taxNumber: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      phone: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ExportAppearance dco_decode_export_appearance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ExportAppearance(
+      exportId: dco_decode_opt_Uuid(arr[0]),
+      timestamp: dco_decode_Chrono_Local(arr[1]),
+      reportDate: dco_decode_String(arr[2]),
+      operator: dco_decode_String(arr[3]),
+      file: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  ExportRecord dco_decode_export_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return ExportRecord(
+      id: dco_decode_Uuid(arr[0]),
+      timestamp: dco_decode_Chrono_Local(arr[1]),
+      operator: dco_decode_String(arr[2]),
+      employer: dco_decode_employer(arr[3]),
+      reportDate: dco_decode_String(arr[4]),
+      workerIds: dco_decode_list_Uuid(arr[5]),
+      workers: dco_decode_list_reported_worker(arr[6]),
+      fileName: dco_decode_String(arr[7]),
+      path: dco_decode_String(arr[8]),
+      sha256: dco_decode_String(arr[9]),
+    );
+  }
+
+  @protected
+  FieldChange dco_decode_field_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FieldChange(
+      field: dco_decode_String(arr[0]),
+      before: dco_decode_String(arr[1]),
+      after: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  HistoryAction dco_decode_history_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return HistoryAction.values[raw as int];
+  }
+
+  @protected
+  HistoryItem dco_decode_history_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return HistoryItem(
+      id: dco_decode_Uuid(arr[0]),
+      action: dco_decode_history_action(arr[1]),
+      description: dco_decode_String(arr[2]),
+      workerIds: dco_decode_list_Uuid(arr[3]),
+      createdAt: dco_decode_Chrono_Local(arr[4]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64OrU64(raw);
+  }
+
+  @protected
+  ImportMode dco_decode_import_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ImportMode.values[raw as int];
+  }
+
+  @protected
+  ImportProfile dco_decode_import_profile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ImportProfile(
+      name: dco_decode_String(arr[0]),
+      columns: dco_decode_list_column_mapping(arr[1]),
+    );
+  }
+
+  @protected
+  ImportRow dco_decode_import_row(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ImportRow(
+      row: dco_decode_u_32(arr[0]),
+      worker: dco_decode_worker(arr[1]),
+      errors: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  ImportSummary dco_decode_import_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ImportSummary(
+      added: dco_decode_list_String(arr[0]),
+      updated: dco_decode_list_String(arr[1]),
+      removed: dco_decode_list_String(arr[2]),
+      unchanged: dco_decode_u_32(arr[3]),
+      replacesSettings: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<UuidValue> dco_decode_list_Uuid(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_Uuid).toList();
+  }
+
+  @protected
+  List<AuditEntry> dco_decode_list_audit_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_audit_entry).toList();
+  }
+
+  @protected
+  List<ColumnMapping> dco_decode_list_column_mapping(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_column_mapping).toList();
+  }
+
+  @protected
+  List<Crew> dco_decode_list_crew(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_crew).toList();
+  }
+
+  @protected
+  List<DraftIssue> dco_decode_list_draft_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_draft_issue).toList();
+  }
+
+  @protected
+  List<DraftRow> dco_decode_list_draft_row(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_draft_row).toList();
+  }
+
+  @protected
+  List<Employer> dco_decode_list_employer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_employer).toList();
+  }
+
+  @protected
+  List<ExportAppearance> dco_decode_list_export_appearance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_export_appearance).toList();
+  }
+
+  @protected
+  List<ExportRecord> dco_decode_list_export_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_export_record).toList();
+  }
+
+  @protected
+  List<FieldChange> dco_decode_list_field_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_field_change).toList();
+  }
+
+  @protected
+  List<HistoryItem> dco_decode_list_history_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_history_item).toList();
+  }
+
+  @protected
+  List<ImportProfile> dco_decode_list_import_profile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_import_profile).toList();
+  }
+
+  @protected
+  List<ImportRow> dco_decode_list_import_row(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_import_row).toList();
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<ReportDraft> dco_decode_list_report_draft(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_report_draft).toList();
+  }
+
+  @protected
+  List<ReportedWorker> dco_decode_list_reported_worker(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_reported_worker).toList();
+  }
+
+  @protected
+  List<RetentionCandidate> dco_decode_list_retention_candidate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_retention_candidate).toList();
+  }
+
+  @protected
+  List<RosterColumn> dco_decode_list_roster_column(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_roster_column).toList();
+  }
+
+  @protected
+  List<Snapshot> dco_decode_list_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_snapshot).toList();
+  }
+
+  @protected
+  List<Worker> dco_decode_list_worker(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_worker).toList();
+  }
+
+  @protected
+  List<WorkerSort> dco_decode_list_worker_sort(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_worker_sort).toList();
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  UuidValue? dco_decode_opt_Uuid(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_Uuid(raw);
+  }
+
+  @protected
+  DateTime? dco_decode_opt_box_autoadd_Chrono_Local(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_Chrono_Local(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  HistoryItem? dco_decode_opt_box_autoadd_history_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_history_item(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  ReportDraft dco_decode_report_draft(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ReportDraft(
+      id: dco_decode_Uuid(arr[0]),
+      date: dco_decode_String(arr[1]),
+      employer: dco_decode_String(arr[2]),
+      category: dco_decode_String(arr[3]),
+      rows: dco_decode_list_draft_row(arr[4]),
+      updatedAt: dco_decode_Chrono_Local(arr[5]),
+      exportedAt: dco_decode_opt_box_autoadd_Chrono_Local(arr[6]),
+    );
+  }
+
+  @protected
+  ReportedWorker dco_decode_reported_worker(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ReportedWorker(
+      workerId: dco_decode_Uuid(arr[0]),
+      category: dco_decode_String(arr[1]),
+      days: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  RetentionCandidate dco_decode_retention_candidate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RetentionCandidate(
+      id: dco_decode_Uuid(arr[0]),
+      name: dco_decode_String(arr[1]),
+      lastActivity: dco_decode_opt_box_autoadd_Chrono_Local(arr[2]),
+      reportedDays: dco_decode_u_32(arr[3]),
+      keptExports: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  RosterColumn dco_decode_roster_column(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RosterColumn.values[raw as int];
+  }
+
+  @protected
+  RosterFormat dco_decode_roster_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RosterFormat.values[raw as int];
+  }
+
+  @protected
+  RowOverride dco_decode_row_override(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RowOverride(
+      name: dco_decode_opt_String(arr[0]),
+      taj: dco_decode_opt_String(arr[1]),
+      taxnumber: dco_decode_opt_String(arr[2]),
+      category: dco_decode_opt_String(arr[3]),
+      days: dco_decode_opt_box_autoadd_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  SelectionSession dco_decode_selection_session(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SelectionSession(
+      employer: dco_decode_String(arr[0]),
+      date: dco_decode_String(arr[1]),
+      workerIds: dco_decode_list_Uuid(arr[2]),
+    );
+  }
+
+  @protected
+  Settings dco_decode_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return Settings(
+      operator: dco_decode_String(arr[0]),
+      backupKeep: dco_decode_u_32(arr[1]),
+      autoLockMinutes: dco_decode_u_32(arr[2]),
+      retentionYears: dco_decode_u_32(arr[3]),
+      employers: dco_decode_list_employer(arr[4]),
+    );
+  }
+
+  @protected
+  Snapshot dco_decode_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Snapshot(
+      id: dco_decode_String(arr[0]),
+      createdAt: dco_decode_Chrono_Naive(arr[1]),
+      reason: dco_decode_snapshot_reason(arr[2]),
+      size: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  SnapshotReason dco_decode_snapshot_reason(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SnapshotReason.values[raw as int];
+  }
+
+  @protected
+  SpreadsheetImport dco_decode_spreadsheet_import(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SpreadsheetImport(
+      rows: dco_decode_list_import_row(arr[0]),
+      valid: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  SubjectReport dco_decode_subject_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SubjectReport(
+      generatedAt: dco_decode_Chrono_Local(arr[0]),
+      worker: dco_decode_worker(arr[1]),
+      reportedDays: dco_decode_list_String(arr[2]),
+      exports: dco_decode_list_export_appearance(arr[3]),
+      auditEntries: dco_decode_list_audit_entry(arr[4]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64OrU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  UpdateError dco_decode_update_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return UpdateError_Conflict(
+            local: dco_decode_box_worker(raw[1]),
+            remote: dco_decode_box_worker(raw[2]));
+      case 1:
+        return UpdateError_Failed(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  int dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64OrU64(raw);
+  }
+
+  @protected
+  Worker dco_decode_worker(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 14)
+      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+    return Worker(
+      id: dco_decode_Uuid(arr[0]),
+      name: dco_decode_String(arr[1]),
+      taj: dco_decode_String(arr[2]),
+      taxnumber: dco_decode_String(arr[3]),
+      mothersname: dco_decode_String(arr[4]),
+      birthdate: dco_decode_String(arr[5]),
+      birthplace: dco_decode_String(arr[6]),
+      zip: dco_decode_String(arr[7]),
+      city: dco_decode_String(arr[8]),
+      street: dco_decode_String(arr[9]),
+      isSelected: dco_decode_bool(arr[10]),
+      anonymizedAt: dco_decode_opt_box_autoadd_Chrono_Local(arr[11]),
+      revision: dco_decode_u_64(arr[12]),
+      tags: dco_decode_list_String(arr[13]),
+    );
+  }
+
+  @protected
+  WorkerField dco_decode_worker_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WorkerField.values[raw as int];
+  }
+
+  @protected
+  WorkerPage dco_decode_worker_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WorkerPage(
+      workers: dco_decode_list_worker(arr[0]),
+      total: dco_decode_u_32(arr[1]),
+      offset: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  WorkerQuery dco_decode_worker_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return WorkerQuery(
+      text: dco_decode_opt_String(arr[0]),
+      selected: dco_decode_opt_box_autoadd_bool(arr[1]),
+      city: dco_decode_opt_String(arr[2]),
+      hasValidationErrors: dco_decode_opt_box_autoadd_bool(arr[3]),
+      tags: dco_decode_list_String(arr[4]),
+      lastWorkedFrom: dco_decode_opt_String(arr[5]),
+      lastWorkedTo: dco_decode_opt_String(arr[6]),
+      sort: dco_decode_list_worker_sort(arr[7]),
+    );
+  }
+
+  @protected
+  WorkerSort dco_decode_worker_sort(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WorkerSort(
+      key: dco_decode_worker_sort_key(arr[0]),
+      descending: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  WorkerSortKey dco_decode_worker_sort_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WorkerSortKey.values[raw as int];
+  }
+
+  @protected
+  XmlImportSummary dco_decode_xml_import_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return XmlImportSummary(
+      files: dco_decode_u_32(arr[0]),
+      skipped: dco_decode_list_String(arr[1]),
+      added: dco_decode_list_String(arr[2]),
+      matched: dco_decode_u_32(arr[3]),
+      reports: dco_decode_u_32(arr[4]),
+      errors: dco_decode_list_String(arr[5]),
+    );
+  }
+
+  @protected
+  DateTime sse_decode_Chrono_Local(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_64(deserializer);
+    return DateTime.fromMicrosecondsSinceEpoch(inner, isUtc: false);
+  }
+
+  @protected
+  DateTime sse_decode_Chrono_Naive(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_64(deserializer);
+    return DateTime.fromMicrosecondsSinceEpoch(inner, isUtc: true);
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  UuidValue sse_decode_Uuid(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return UuidValue.fromByteList(inner);
+  }
+
+  @protected
+  AuditAction sse_decode_audit_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AuditAction.values[inner];
+  }
+
+  @protected
+  AuditEntry sse_decode_audit_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_Uuid(deserializer);
+    var var_timestamp = sse_decode_Chrono_Local(deserializer);
+    var var_operator = sse_decode_String(deserializer);
+    var var_action = sse_decode_audit_action(deserializer);
+    var var_workerIds = sse_decode_list_Uuid(deserializer);
+    var var_changes = sse_decode_list_field_change(deserializer);
+    var var_note = sse_decode_String(deserializer);
+    return AuditEntry(
+        id: var_id,
+        timestamp: var_timestamp,
+        operator: var_operator,
+        action: var_action,
+        workerIds: var_workerIds,
+        changes: var_changes,
+        note: var_note);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  DateTime sse_decode_box_autoadd_Chrono_Local(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_Chrono_Local(deserializer));
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  Crew sse_decode_box_autoadd_crew(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_crew(deserializer));
+  }
+
+  @protected
+  HistoryItem sse_decode_box_autoadd_history_item(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_history_item(deserializer));
+  }
+
+  @protected
+  ImportProfile sse_decode_box_autoadd_import_profile(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_import_profile(deserializer));
+  }
+
+  @protected
+  ReportDraft sse_decode_box_autoadd_report_draft(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_report_draft(deserializer));
+  }
+
+  @protected
+  Settings sse_decode_box_autoadd_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_settings(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  Worker sse_decode_box_autoadd_worker(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_worker(deserializer));
+  }
+
+  @protected
+  WorkerQuery sse_decode_box_autoadd_worker_query(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_worker_query(deserializer));
+  }
+
+  @protected
+  Worker sse_decode_box_worker(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_worker(deserializer));
+  }
+
+  @protected
+  ColumnMapping sse_decode_column_mapping(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_header = sse_decode_String(deserializer);
+    var var_field = sse_decode_worker_field(deserializer);
+    return ColumnMapping(header: var_header, field: var_field);
+  }
+
+  @protected
+  Crew sse_decode_crew(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_Uuid(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_workerIds = sse_decode_list_Uuid(deserializer);
+    var var_updatedAt = sse_decode_Chrono_Local(deserializer);
+    return Crew(
+        id: var_id,
+        name: var_name,
+        workerIds: var_workerIds,
+        updatedAt: var_updatedAt);
+  }
+
+  @protected
+  DbEvent sse_decode_db_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_worker = sse_decode_worker(deserializer);
+        return DbEvent_WorkerAdded(worker: var_worker);
+      case 1:
+        var var_worker = sse_decode_worker(deserializer);
+        return DbEvent_WorkerUpdated(worker: var_worker);
+      case 2:
+        var var_id = sse_decode_Uuid(deserializer);
+        return DbEvent_WorkerRemoved(id: var_id);
+      case 3:
+        var var_id = sse_decode_Uuid(deserializer);
+        var var_selected = sse_decode_bool(deserializer);
+        return DbEvent_SelectionChanged(id: var_id, selected: var_selected);
+      case 4:
+        var var_selected = sse_decode_list_Uuid(deserializer);
+        var var_unselected = sse_decode_list_Uuid(deserializer);
+        return DbEvent_SelectionsChanged(
+            selected: var_selected, unselected: var_unselected);
+      case 5:
+        var var_path = sse_decode_String(deserializer);
+        var var_workerIds = sse_decode_list_Uuid(deserializer);
+        return DbEvent_ExportDone(path: var_path, workerIds: var_workerIds);
+      case 6:
+        var var_local = sse_decode_worker(deserializer);
+        var var_deleted = sse_decode_bool(deserializer);
+        return DbEvent_Conflict(local: var_local, deleted: var_deleted);
+      case 7:
+        return DbEvent_Reloaded();
+      case 8:
+        var var_ids = sse_decode_list_Uuid(deserializer);
+        return DbEvent_Changed(ids: var_ids);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  DraftIssue sse_decode_draft_issue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_workerId = sse_decode_opt_Uuid(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return DraftIssue(workerId: var_workerId, message: var_message);
+  }
+
+  @protected
+  DraftRow sse_decode_draft_row(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_workerId = sse_decode_Uuid(deserializer);
+    var var_overrides = sse_decode_row_override(deserializer);
+    return DraftRow(workerId: var_workerId, overrides: var_overrides);
+  }
+
+  @protected
+  Employer sse_decode_employer(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_taxNumber = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_phone = sse_decode_String(deserializer);
+    return Employer(taxNumber: var_taxNumber, name: var_name, phone: var_phone);
+  }
+
+  @protected
+  ExportAppearance sse_decode_export_appearance(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_exportId = sse_decode_opt_Uuid(deserializer);
+    var var_timestamp = sse_decode_Chrono_Local(deserializer);
+    var var_reportDate = sse_decode_String(deserializer);
+    var var_operator = sse_decode_String(deserializer);
+    var var_file = sse_decode_String(deserializer);
+    return ExportAppearance(
+        exportId: var_exportId,
+        timestamp: var_timestamp,
+        reportDate: var_reportDate,
+        operator: var_operator,
+        file: var_file);
+  }
+
+  @protected
+  ExportRecord sse_decode_export_record(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_Uuid(deserializer);
+    var var_timestamp = sse_decode_Chrono_Local(deserializer);
+    var var_operator = sse_decode_String(deserializer);
+    var var_employer = sse_decode_employer(deserializer);
+    var var_reportDate = sse_decode_String(deserializer);
+    var var_workerIds = sse_decode_list_Uuid(deserializer);
+    var var_workers = sse_decode_list_reported_worker(deserializer);
+    var var_fileName = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_sha256 = sse_decode_String(deserializer);
+    return ExportRecord(
+        id: var_id,
+        timestamp: var_timestamp,
+        operator: var_operator,
+        employer: var_employer,
+        reportDate: var_reportDate,
+        workerIds: var_workerIds,
+        workers: var_workers,
+        fileName: var_fileName,
+        path: var_path,
+        sha256: var_sha256);
+  }
+
+  @protected
+  FieldChange sse_decode_field_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_String(deserializer);
+    var var_before = sse_decode_String(deserializer);
+    var var_after = sse_decode_String(deserializer);
+    return FieldChange(field: var_field, before: var_before, after: var_after);
+  }
+
+  @protected
+  HistoryAction sse_decode_history_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return HistoryAction.values[inner];
+  }
+
+  @protected
+  HistoryItem sse_decode_history_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_Uuid(deserializer);
+    var var_action = sse_decode_history_action(deserializer);
+    var var_description = sse_decode_String(deserializer);
+    var var_workerIds = sse_decode_list_Uuid(deserializer);
+    var var_createdAt = sse_decode_Chrono_Local(deserializer);
+    return HistoryItem(
+        id: var_id,
+        action: var_action,
+        description: var_description,
+        workerIds: var_workerIds,
+        createdAt: var_createdAt);
+  }
+
+  @protected
+  int sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt64();
+  }
+
+  @protected
+  ImportMode sse_decode_import_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ImportMode.values[inner];
+  }
+
+  @protected
+  ImportProfile sse_decode_import_profile(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_columns = sse_decode_list_column_mapping(deserializer);
+    return ImportProfile(name: var_name, columns: var_columns);
+  }
+
+  @protected
+  ImportRow sse_decode_import_row(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_row = sse_decode_u_32(deserializer);
+    var var_worker = sse_decode_worker(deserializer);
+    var var_errors = sse_decode_list_String(deserializer);
+    return ImportRow(row: var_row, worker: var_worker, errors: var_errors);
+  }
+
+  @protected
+  ImportSummary sse_decode_import_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_added = sse_decode_list_String(deserializer);
+    var var_updated = sse_decode_list_String(deserializer);
+    var var_removed = sse_decode_list_String(deserializer);
+    var var_unchanged = sse_decode_u_32(deserializer);
+    var var_replacesSettings = sse_decode_bool(deserializer);
+    return ImportSummary(
+        added: var_added,
+        updated: var_updated,
+        removed: var_removed,
+        unchanged: var_unchanged,
+        replacesSettings: var_replacesSettings);
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<UuidValue> sse_decode_list_Uuid(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <UuidValue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_Uuid(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AuditEntry> sse_decode_list_audit_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AuditEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_audit_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ColumnMapping> sse_decode_list_column_mapping(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ColumnMapping>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_column_mapping(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Crew> sse_decode_list_crew(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Crew>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_crew(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DraftIssue> sse_decode_list_draft_issue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DraftIssue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_draft_issue(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DraftRow> sse_decode_list_draft_row(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DraftRow>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_draft_row(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Employer> sse_decode_list_employer(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Employer>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_employer(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ExportAppearance> sse_decode_list_export_appearance(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ExportAppearance>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_export_appearance(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ExportRecord> sse_decode_list_export_record(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ExportRecord>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_export_record(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FieldChange> sse_decode_list_field_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FieldChange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_field_change(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<HistoryItem> sse_decode_list_history_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <HistoryItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_history_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ImportProfile> sse_decode_list_import_profile(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ImportProfile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_import_profile(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ImportRow> sse_decode_list_import_row(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ImportRow>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_import_row(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<ReportDraft> sse_decode_list_report_draft(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReportDraft>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_report_draft(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ReportedWorker> sse_decode_list_reported_worker(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReportedWorker>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_reported_worker(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RetentionCandidate> sse_decode_list_retention_candidate(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RetentionCandidate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_retention_candidate(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RosterColumn> sse_decode_list_roster_column(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RosterColumn>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_roster_column(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Snapshot> sse_decode_list_snapshot(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Snapshot>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_snapshot(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Worker> sse_decode_list_worker(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Worker>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_worker(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WorkerSort> sse_decode_list_worker_sort(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WorkerSort>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_worker_sort(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  UuidValue? sse_decode_opt_Uuid(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_Uuid(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DateTime? sse_decode_opt_box_autoadd_Chrono_Local(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_Chrono_Local(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  HistoryItem? sse_decode_opt_box_autoadd_history_item(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_history_item(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ReportDraft sse_decode_report_draft(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_Uuid(deserializer);
+    var var_date = sse_decode_String(deserializer);
+    var var_employer = sse_decode_String(deserializer);
+    var var_category = sse_decode_String(deserializer);
+    var var_rows = sse_decode_list_draft_row(deserializer);
+    var var_updatedAt = sse_decode_Chrono_Local(deserializer);
+    var var_exportedAt = sse_decode_opt_box_autoadd_Chrono_Local(deserializer);
+    return ReportDraft(
+        id: var_id,
+        date: var_date,
+        employer: var_employer,
+        category: var_category,
+        rows: var_rows,
+        updatedAt: var_updatedAt,
+        exportedAt: var_exportedAt);
+  }
+
+  @protected
+  ReportedWorker sse_decode_reported_worker(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_workerId = sse_decode_Uuid(deserializer);
+    var var_category = sse_decode_String(deserializer);
+    var var_days = sse_decode_u_32(deserializer);
+    return ReportedWorker(
+        workerId: var_workerId, category: var_category, days: var_days);
+  }
+
+  @protected
+  RetentionCandidate sse_decode_retention_candidate(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_Uuid(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_lastActivity =
+        sse_decode_opt_box_autoadd_Chrono_Local(deserializer);
+    var var_reportedDays = sse_decode_u_32(deserializer);
+    var var_keptExports = sse_decode_u_32(deserializer);
+    return RetentionCandidate(
+        id: var_id,
+        name: var_name,
+        lastActivity: var_lastActivity,
+        reportedDays: var_reportedDays,
+        keptExports: var_keptExports);
+  }
+
+  @protected
+  RosterColumn sse_decode_roster_column(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RosterColumn.values[inner];
+  }
+
+  @protected
+  RosterFormat sse_decode_roster_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RosterFormat.values[inner];
+  }
+
+  @protected
+  RowOverride sse_decode_row_override(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_taj = sse_decode_opt_String(deserializer);
+    var var_taxnumber = sse_decode_opt_String(deserializer);
+    var var_category = sse_decode_opt_String(deserializer);
+    var var_days = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return RowOverride(
+        name: var_name,
+        taj: var_taj,
+        taxnumber: var_taxnumber,
+        category: var_category,
+        days: var_days);
+  }
+
+  @protected
+  SelectionSession sse_decode_selection_session(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_employer = sse_decode_String(deserializer);
+    var var_date = sse_decode_String(deserializer);
+    var var_workerIds = sse_decode_list_Uuid(deserializer);
+    return SelectionSession(
+        employer: var_employer, date: var_date, workerIds: var_workerIds);
+  }
+
+  @protected
+  Settings sse_decode_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_operator = sse_decode_String(deserializer);
+    var var_backupKeep = sse_decode_u_32(deserializer);
+    var var_autoLockMinutes = sse_decode_u_32(deserializer);
+    var var_retentionYears = sse_decode_u_32(deserializer);
+    var var_employers = sse_decode_list_employer(deserializer);
+    return Settings(
+        operator: var_operator,
+        backupKeep: var_backupKeep,
+        autoLockMinutes: var_autoLockMinutes,
+        retentionYears: var_retentionYears,
+        employers: var_employers);
+  }
+
+  @protected
+  Snapshot sse_decode_snapshot(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_createdAt = sse_decode_Chrono_Naive(deserializer);
+    var var_reason = sse_decode_snapshot_reason(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    return Snapshot(
+        id: var_id,
+        createdAt: var_createdAt,
+        reason: var_reason,
+        size: var_size);
+  }
+
+  @protected
+  SnapshotReason sse_decode_snapshot_reason(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SnapshotReason.values[inner];
+  }
+
+  @protected
+  SpreadsheetImport sse_decode_spreadsheet_import(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rows = sse_decode_list_import_row(deserializer);
+    var var_valid = sse_decode_u_32(deserializer);
+    return SpreadsheetImport(rows: var_rows, valid: var_valid);
+  }
+
+  @protected
+  SubjectReport sse_decode_subject_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_generatedAt = sse_decode_Chrono_Local(deserializer);
+    var var_worker = sse_decode_worker(deserializer);
+    var var_reportedDays = sse_decode_list_String(deserializer);
+    var var_exports = sse_decode_list_export_appearance(deserializer);
+    var var_auditEntries = sse_decode_list_audit_entry(deserializer);
+    return SubjectReport(
+        generatedAt: var_generatedAt,
+        worker: var_worker,
+        reportedDays: var_reportedDays,
+        exports: var_exports,
+        auditEntries: var_auditEntries);
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  int sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
   }
 
   @protected
-  Worker dco_decode_worker(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 11)
-      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  UpdateError sse_decode_update_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_local = sse_decode_box_worker(deserializer);
+        var var_remote = sse_decode_box_worker(deserializer);
+        return UpdateError_Conflict(local: var_local, remote: var_remote);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        return UpdateError_Failed(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  int sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint64();
+  }
+
+  @protected
+  Worker sse_decode_worker(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_Uuid(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_taj = sse_decode_String(deserializer);
+    var var_taxnumber = sse_decode_String(deserializer);
+    var var_mothersname = sse_decode_String(deserializer);
+    var var_birthdate = sse_decode_String(deserializer);
+    var var_birthplace = sse_decode_String(deserializer);
+    var var_zip = sse_decode_String(deserializer);
+    var var_city = sse_decode_String(deserializer);
+    var var_street = sse_decode_String(deserializer);
+    var var_isSelected = sse_decode_bool(deserializer);
+    var var_anonymizedAt =
+        sse_decode_opt_box_autoadd_Chrono_Local(deserializer);
+    var var_revision = sse_decode_u_64(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
     return Worker(
-      id: dco_decode_Uuid(arr[0]),
-      name: dco_decode_String(arr[1]),
-      taj: dco_decode_String(arr[2]),
-      taxnumber: dco_decode_String(arr[3]),
-      mothersname: dco_decode_String(arr[4]),
-      birthdate: dco_decode_String(arr[5]),
-      birthplace: dco_decode_String(arr[6]),
-      zip: dco_decode_String(arr[7]),
-      city: dco_decode_String(arr[8]),
-      street: dco_decode_String(arr[9]),
-      isSelected: dco_decode_bool(arr[10]),
-    );
+        id: var_id,
+        name: var_name,
+        taj: var_taj,
+        taxnumber: var_taxnumber,
+        mothersname: var_mothersname,
+        birthdate: var_birthdate,
+        birthplace: var_birthplace,
+        zip: var_zip,
+        city: var_city,
+        street: var_street,
+        isSelected: var_isSelected,
+        anonymizedAt: var_anonymizedAt,
+        revision: var_revision,
+        tags: var_tags);
+  }
+
+  @protected
+  WorkerField sse_decode_worker_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WorkerField.values[inner];
+  }
+
+  @protected
+  WorkerPage sse_decode_worker_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_workers = sse_decode_list_worker(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    var var_offset = sse_decode_u_32(deserializer);
+    return WorkerPage(
+        workers: var_workers, total: var_total, offset: var_offset);
+  }
+
+  @protected
+  WorkerQuery sse_decode_worker_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_opt_String(deserializer);
+    var var_selected = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_city = sse_decode_opt_String(deserializer);
+    var var_hasValidationErrors = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    var var_lastWorkedFrom = sse_decode_opt_String(deserializer);
+    var var_lastWorkedTo = sse_decode_opt_String(deserializer);
+    var var_sort = sse_decode_list_worker_sort(deserializer);
+    return WorkerQuery(
+        text: var_text,
+        selected: var_selected,
+        city: var_city,
+        hasValidationErrors: var_hasValidationErrors,
+        tags: var_tags,
+        lastWorkedFrom: var_lastWorkedFrom,
+        lastWorkedTo: var_lastWorkedTo,
+        sort: var_sort);
+  }
+
+  @protected
+  WorkerSort sse_decode_worker_sort(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_worker_sort_key(deserializer);
+    var var_descending = sse_decode_bool(deserializer);
+    return WorkerSort(key: var_key, descending: var_descending);
+  }
+
+  @protected
+  WorkerSortKey sse_decode_worker_sort_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WorkerSortKey.values[inner];
+  }
+
+  @protected
+  XmlImportSummary sse_decode_xml_import_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_files = sse_decode_u_32(deserializer);
+    var var_skipped = sse_decode_list_String(deserializer);
+    var var_added = sse_decode_list_String(deserializer);
+    var var_matched = sse_decode_u_32(deserializer);
+    var var_reports = sse_decode_u_32(deserializer);
+    var var_errors = sse_decode_list_String(deserializer);
+    return XmlImportSummary(
+        files: var_files,
+        skipped: var_skipped,
+        added: var_added,
+        matched: var_matched,
+        reports: var_reports,
+        errors: var_errors);
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  void sse_encode_Chrono_Local(DateTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.microsecondsSinceEpoch, serializer);
+  }
+
+  @protected
+  void sse_encode_Chrono_Naive(DateTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.microsecondsSinceEpoch, serializer);
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_Uuid(UuidValue self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(self.toBytes(), serializer);
+  }
+
+  @protected
+  void sse_encode_audit_action(AuditAction self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_audit_entry(AuditEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Uuid(self.id, serializer);
+    sse_encode_Chrono_Local(self.timestamp, serializer);
+    sse_encode_String(self.operator, serializer);
+    sse_encode_audit_action(self.action, serializer);
+    sse_encode_list_Uuid(self.workerIds, serializer);
+    sse_encode_list_field_change(self.changes, serializer);
+    sse_encode_String(self.note, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_Chrono_Local(
+      DateTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Chrono_Local(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_crew(Crew self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_crew(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_history_item(
+      HistoryItem self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_history_item(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_import_profile(
+      ImportProfile self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_import_profile(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_report_draft(
+      ReportDraft self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_report_draft(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_settings(
+      Settings self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_settings(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_worker(Worker self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_worker(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_worker_query(
+      WorkerQuery self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_worker_query(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_worker(Worker self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_worker(self, serializer);
+  }
+
+  @protected
+  void sse_encode_column_mapping(ColumnMapping self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.header, serializer);
+    sse_encode_worker_field(self.field, serializer);
+  }
+
+  @protected
+  void sse_encode_crew(Crew self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Uuid(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_list_Uuid(self.workerIds, serializer);
+    sse_encode_Chrono_Local(self.updatedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_db_event(DbEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case DbEvent_WorkerAdded(:final worker):
+        sse_encode_i_32(0, serializer);
+        sse_encode_worker(worker, serializer);
+      case DbEvent_WorkerUpdated(:final worker):
+        sse_encode_i_32(1, serializer);
+        sse_encode_worker(worker, serializer);
+      case DbEvent_WorkerRemoved(:final id):
+        sse_encode_i_32(2, serializer);
+        sse_encode_Uuid(id, serializer);
+      case DbEvent_SelectionChanged(:final id, :final selected):
+        sse_encode_i_32(3, serializer);
+        sse_encode_Uuid(id, serializer);
+        sse_encode_bool(selected, serializer);
+      case DbEvent_SelectionsChanged(:final selected, :final unselected):
+        sse_encode_i_32(4, serializer);
+        sse_encode_list_Uuid(selected, serializer);
+        sse_encode_list_Uuid(unselected, serializer);
+      case DbEvent_ExportDone(:final path, :final workerIds):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_list_Uuid(workerIds, serializer);
+      case DbEvent_Conflict(:final local, :final deleted):
+        sse_encode_i_32(6, serializer);
+        sse_encode_worker(local, serializer);
+        sse_encode_bool(deleted, serializer);
+      case DbEvent_Reloaded():
+        sse_encode_i_32(7, serializer);
+      case DbEvent_Changed(:final ids):
+        sse_encode_i_32(8, serializer);
+        sse_encode_list_Uuid(ids, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_draft_issue(DraftIssue self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_Uuid(self.workerId, serializer);
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_draft_row(DraftRow self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Uuid(self.workerId, serializer);
+    sse_encode_row_override(self.overrides, serializer);
+  }
+
+  @protected
+  void sse_encode_employer(Employer self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.taxNumber, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.phone, serializer);
+  }
+
+  @protected
+  void sse_encode_export_appearance(
+      ExportAppearance self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_Uuid(self.exportId, serializer);
+    sse_encode_Chrono_Local(self.timestamp, serializer);
+    sse_encode_String(self.reportDate, serializer);
+    sse_encode_String(self.operator, serializer);
+    sse_encode_String(self.file, serializer);
+  }
+
+  @protected
+  void sse_encode_export_record(ExportRecord self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Uuid(self.id, serializer);
+    sse_encode_Chrono_Local(self.timestamp, serializer);
+    sse_encode_String(self.operator, serializer);
+    sse_encode_employer(self.employer, serializer);
+    sse_encode_String(self.reportDate, serializer);
+    sse_encode_list_Uuid(self.workerIds, serializer);
+    sse_encode_list_reported_worker(self.workers, serializer);
+    sse_encode_String(self.fileName, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_String(self.sha256, serializer);
+  }
+
+  @protected
+  void sse_encode_field_change(FieldChange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.field, serializer);
+    sse_encode_String(self.before, serializer);
+    sse_encode_String(self.after, serializer);
+  }
+
+  @protected
+  void sse_encode_history_action(HistoryAction self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_history_item(HistoryItem self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Uuid(self.id, serializer);
+    sse_encode_history_action(self.action, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_list_Uuid(self.workerIds, serializer);
+    sse_encode_Chrono_Local(self.createdAt, serializer);
+  }
+
+  @protected
+  void sse_encode_i_64(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt64(self);
+  }
+
+  @protected
+  void sse_encode_import_mode(ImportMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_import_profile(ImportProfile self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_list_column_mapping(self.columns, serializer);
+  }
+
+  @protected
+  void sse_encode_import_row(ImportRow self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.row, serializer);
+    sse_encode_worker(self.worker, serializer);
+    sse_encode_list_String(self.errors, serializer);
+  }
+
+  @protected
+  void sse_encode_import_summary(ImportSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.added, serializer);
+    sse_encode_list_String(self.updated, serializer);
+    sse_encode_list_String(self.removed, serializer);
+    sse_encode_u_32(self.unchanged, serializer);
+    sse_encode_bool(self.replacesSettings, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_Uuid(List<UuidValue> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Uuid(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_audit_entry(
+      List<AuditEntry> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_audit_entry(item, serializer);
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_list_column_mapping(
+      List<ColumnMapping> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_column_mapping(item, serializer);
+    }
   }
 
   @protected
-  UuidValue sse_decode_Uuid(SseDeserializer deserializer) {
+  void sse_encode_list_crew(List<Crew> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return UuidValue.fromByteList(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_crew(item, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_list_draft_issue(
+      List<DraftIssue> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_draft_issue(item, serializer);
+    }
   }
 
   @protected
-  Worker sse_decode_box_autoadd_worker(SseDeserializer deserializer) {
+  void sse_encode_list_draft_row(
+      List<DraftRow> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_worker(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_draft_row(item, serializer);
+    }
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_list_employer(List<Employer> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_employer(item, serializer);
+    }
   }
 
   @protected
-  List<Worker> sse_decode_list_worker(SseDeserializer deserializer) {
+  void sse_encode_list_export_appearance(
+      List<ExportAppearance> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_export_appearance(item, serializer);
+    }
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Worker>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_worker(deserializer));
+  @protected
+  void sse_encode_list_export_record(
+      List<ExportRecord> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_export_record(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_list_field_change(
+      List<FieldChange> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_field_change(item, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_list_history_item(
+      List<HistoryItem> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_history_item(item, serializer);
+    }
   }
 
   @protected
-  Worker sse_decode_worker(SseDeserializer deserializer) {
+  void sse_encode_list_import_profile(
+      List<ImportProfile> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_Uuid(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_taj = sse_decode_String(deserializer);
-    var var_taxnumber = sse_decode_String(deserializer);
-    var var_mothersname = sse_decode_String(deserializer);
-    var var_birthdate = sse_decode_String(deserializer);
-    var var_birthplace = sse_decode_String(deserializer);
-    var var_zip = sse_decode_String(deserializer);
-    var var_city = sse_decode_String(deserializer);
-    var var_street = sse_decode_String(deserializer);
-    var var_isSelected = sse_decode_bool(deserializer);
-    return Worker(
-        id: var_id,
-        name: var_name,
-        taj: var_taj,
-        taxnumber: var_taxnumber,
-        mothersname: var_mothersname,
-        birthdate: var_birthdate,
-        birthplace: var_birthplace,
-        zip: var_zip,
-        city: var_city,
-        street: var_street,
-        isSelected: var_isSelected);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_import_profile(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_list_import_row(
+      List<ImportRow> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_import_row(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_list_prim_u_8_strict(
+      Uint8List self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  void sse_encode_Uuid(UuidValue self, SseSerializer serializer) {
+  void sse_encode_list_report_draft(
+      List<ReportDraft> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.toBytes(), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_report_draft(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_list_reported_worker(
+      List<ReportedWorker> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_reported_worker(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_worker(Worker self, SseSerializer serializer) {
+  void sse_encode_list_retention_candidate(
+      List<RetentionCandidate> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_worker(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_retention_candidate(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
+  void sse_encode_list_roster_column(
+      List<RosterColumn> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    for (final item in self) {
+      sse_encode_roster_column(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_snapshot(List<Snapshot> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_snapshot(item, serializer);
+    }
   }
 
   @protected
@@ -536,6 +4586,197 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_worker_sort(
+      List<WorkerSort> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_worker_sort(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_Uuid(UuidValue? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_Uuid(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_Chrono_Local(
+      DateTime? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_Chrono_Local(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_history_item(
+      HistoryItem? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_history_item(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_report_draft(ReportDraft self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Uuid(self.id, serializer);
+    sse_encode_String(self.date, serializer);
+    sse_encode_String(self.employer, serializer);
+    sse_encode_String(self.category, serializer);
+    sse_encode_list_draft_row(self.rows, serializer);
+    sse_encode_Chrono_Local(self.updatedAt, serializer);
+    sse_encode_opt_box_autoadd_Chrono_Local(self.exportedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_reported_worker(
+      ReportedWorker self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Uuid(self.workerId, serializer);
+    sse_encode_String(self.category, serializer);
+    sse_encode_u_32(self.days, serializer);
+  }
+
+  @protected
+  void sse_encode_retention_candidate(
+      RetentionCandidate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Uuid(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_box_autoadd_Chrono_Local(self.lastActivity, serializer);
+    sse_encode_u_32(self.reportedDays, serializer);
+    sse_encode_u_32(self.keptExports, serializer);
+  }
+
+  @protected
+  void sse_encode_roster_column(RosterColumn self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_roster_format(RosterFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_row_override(RowOverride self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.taj, serializer);
+    sse_encode_opt_String(self.taxnumber, serializer);
+    sse_encode_opt_String(self.category, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.days, serializer);
+  }
+
+  @protected
+  void sse_encode_selection_session(
+      SelectionSession self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.employer, serializer);
+    sse_encode_String(self.date, serializer);
+    sse_encode_list_Uuid(self.workerIds, serializer);
+  }
+
+  @protected
+  void sse_encode_settings(Settings self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.operator, serializer);
+    sse_encode_u_32(self.backupKeep, serializer);
+    sse_encode_u_32(self.autoLockMinutes, serializer);
+    sse_encode_u_32(self.retentionYears, serializer);
+    sse_encode_list_employer(self.employers, serializer);
+  }
+
+  @protected
+  void sse_encode_snapshot(Snapshot self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_Chrono_Naive(self.createdAt, serializer);
+    sse_encode_snapshot_reason(self.reason, serializer);
+    sse_encode_u_64(self.size, serializer);
+  }
+
+  @protected
+  void sse_encode_snapshot_reason(
+      SnapshotReason self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_spreadsheet_import(
+      SpreadsheetImport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_import_row(self.rows, serializer);
+    sse_encode_u_32(self.valid, serializer);
+  }
+
+  @protected
+  void sse_encode_subject_report(SubjectReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Chrono_Local(self.generatedAt, serializer);
+    sse_encode_worker(self.worker, serializer);
+    sse_encode_list_String(self.reportedDays, serializer);
+    sse_encode_list_export_appearance(self.exports, serializer);
+    sse_encode_list_audit_entry(self.auditEntries, serializer);
+  }
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
+  @protected
+  void sse_encode_u_64(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint64(self);
+  }
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -547,6 +4788,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  void sse_encode_update_error(UpdateError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case UpdateError_Conflict(:final local, :final remote):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_worker(local, serializer);
+        sse_encode_box_worker(remote, serializer);
+      case UpdateError_Failed(:final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_usize(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint64(self);
+  }
+
   @protected
   void sse_encode_worker(Worker self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -561,6 +4822,62 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.city, serializer);
     sse_encode_String(self.street, serializer);
     sse_encode_bool(self.isSelected, serializer);
+    sse_encode_opt_box_autoadd_Chrono_Local(self.anonymizedAt, serializer);
+    sse_encode_u_64(self.revision, serializer);
+    sse_encode_list_String(self.tags, serializer);
+  }
+
+  @protected
+  void sse_encode_worker_field(WorkerField self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_worker_page(WorkerPage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_worker(self.workers, serializer);
+    sse_encode_u_32(self.total, serializer);
+    sse_encode_u_32(self.offset, serializer);
+  }
+
+  @protected
+  void sse_encode_worker_query(WorkerQuery self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.text, serializer);
+    sse_encode_opt_box_autoadd_bool(self.selected, serializer);
+    sse_encode_opt_String(self.city, serializer);
+    sse_encode_opt_box_autoadd_bool(self.hasValidationErrors, serializer);
+    sse_encode_list_String(self.tags, serializer);
+    sse_encode_opt_String(self.lastWorkedFrom, serializer);
+    sse_encode_opt_String(self.lastWorkedTo, serializer);
+    sse_encode_list_worker_sort(self.sort, serializer);
+  }
+
+  @protected
+  void sse_encode_worker_sort(WorkerSort self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_worker_sort_key(self.key, serializer);
+    sse_encode_bool(self.descending, serializer);
+  }
+
+  @protected
+  void sse_encode_worker_sort_key(
+      WorkerSortKey self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_xml_import_summary(
+      XmlImportSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.files, serializer);
+    sse_encode_list_String(self.skipped, serializer);
+    sse_encode_list_String(self.added, serializer);
+    sse_encode_u_32(self.matched, serializer);
+    sse_encode_u_32(self.reports, serializer);
+    sse_encode_list_String(self.errors, serializer);
   }
 
   @protected
@@ -3,8 +3,22 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/audit.dart';
+import 'api/backup.dart';
+import 'api/crew.dart';
+import 'api/draft.dart';
+import 'api/events.dart';
+import 'api/export_history.dart';
+import 'api/history.dart';
+import 'api/privacy.dart';
+import 'api/query.dart';
+import 'api/roster_export.dart';
+import 'api/session.dart';
+import 'api/settings.dart';
 import 'api/simple.dart';
+import 'api/spreadsheet_import.dart';
 import 'api/worker.dart';
+import 'api/xml_import.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -19,91 +33,832 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  @protected
+  DateTime dco_decode_Chrono_Local(dynamic raw);
+
+  @protected
+  DateTime dco_decode_Chrono_Naive(dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
   @protected
   UuidValue dco_decode_Uuid(dynamic raw);
 
+  @protected
+  AuditAction dco_decode_audit_action(dynamic raw);
+
+  @protected
+  AuditEntry dco_decode_audit_entry(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  DateTime dco_decode_box_autoadd_Chrono_Local(dynamic raw);
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  Crew dco_decode_box_autoadd_crew(dynamic raw);
+
+  @protected
+  HistoryItem dco_decode_box_autoadd_history_item(dynamic raw);
+
+  @protected
+  ImportProfile dco_decode_box_autoadd_import_profile(dynamic raw);
+
+  @protected
+  ReportDraft dco_decode_box_autoadd_report_draft(dynamic raw);
+
+  @protected
+  Settings dco_decode_box_autoadd_settings(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
   @protected
   Worker dco_decode_box_autoadd_worker(dynamic raw);
 
+  @protected
+  WorkerQuery dco_decode_box_autoadd_worker_query(dynamic raw);
+
+  @protected
+  Worker dco_decode_box_worker(dynamic raw);
+
+  @protected
+  ColumnMapping dco_decode_column_mapping(dynamic raw);
+
+  @protected
+  Crew dco_decode_crew(dynamic raw);
+
+  @protected
+  DbEvent dco_decode_db_event(dynamic raw);
+
+  @protected
+  DraftIssue dco_decode_draft_issue(dynamic raw);
+
+  @protected
+  DraftRow dco_decode_draft_row(dynamic raw);
+
+  @protected
+  Employer dco_decode_employer(dynamic raw);
+
+  @protected
+  ExportAppearance dco_decode_export_appearance(dynamic raw);
+
+  @protected
+  ExportRecord dco_decode_export_record(dynamic raw);
+
+  @protected
+  FieldChange dco_decode_field_change(dynamic raw);
+
+  @protected
+  HistoryAction dco_decode_history_action(dynamic raw);
+
+  @protected
+  HistoryItem dco_decode_history_item(dynamic raw);
+
+  @protected
+  int dco_decode_i_64(dynamic raw);
+
+  @protected
+  ImportMode dco_decode_import_mode(dynamic raw);
+
+  @protected
+  ImportProfile dco_decode_import_profile(dynamic raw);
+
+  @protected
+  ImportRow dco_decode_import_row(dynamic raw);
+
+  @protected
+  ImportSummary dco_decode_import_summary(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<UuidValue> dco_decode_list_Uuid(dynamic raw);
+
+  @protected
+  List<AuditEntry> dco_decode_list_audit_entry(dynamic raw);
+
+  @protected
+  List<ColumnMapping> dco_decode_list_column_mapping(dynamic raw);
+
+  @protected
+  List<Crew> dco_decode_list_crew(dynamic raw);
+
+  @protected
+  List<DraftIssue> dco_decode_list_draft_issue(dynamic raw);
+
+  @protected
+  List<DraftRow> dco_decode_list_draft_row(dynamic raw);
+
+  @protected
+  List<Employer> dco_decode_list_employer(dynamic raw);
+
+  @protected
+  List<ExportAppearance> dco_decode_list_export_appearance(dynamic raw);
+
+  @protected
+  List<ExportRecord> dco_decode_list_export_record(dynamic raw);
+
+  @protected
+  List<FieldChange> dco_decode_list_field_change(dynamic raw);
+
+  @protected
+  List<HistoryItem> dco_decode_list_history_item(dynamic raw);
+
+  @protected
+  List<ImportProfile> dco_decode_list_import_profile(dynamic raw);
+
+  @protected
+  List<ImportRow> dco_decode_list_import_row(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<ReportDraft> dco_decode_list_report_draft(dynamic raw);
+
+  @protected
+  List<ReportedWorker> dco_decode_list_reported_worker(dynamic raw);
+
+  @protected
+  List<RetentionCandidate> dco_decode_list_retention_candidate(dynamic raw);
+
+  @protected
+  List<RosterColumn> dco_decode_list_roster_column(dynamic raw);
+
+  @protected
+  List<Snapshot> dco_decode_list_snapshot(dynamic raw);
+
   @protected
   List<Worker> dco_decode_list_worker(dynamic raw);
 
+  @protected
+  List<WorkerSort> dco_decode_list_worker_sort(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  UuidValue? dco_decode_opt_Uuid(dynamic raw);
+
+  @protected
+  DateTime? dco_decode_opt_box_autoadd_Chrono_Local(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  HistoryItem? dco_decode_opt_box_autoadd_history_item(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  ReportDraft dco_decode_report_draft(dynamic raw);
+
+  @protected
+  ReportedWorker dco_decode_reported_worker(dynamic raw);
+
+  @protected
+  RetentionCandidate dco_decode_retention_candidate(dynamic raw);
+
+  @protected
+  RosterColumn dco_decode_roster_column(dynamic raw);
+
+  @protected
+  RosterFormat dco_decode_roster_format(dynamic raw);
+
+  @protected
+  RowOverride dco_decode_row_override(dynamic raw);
+
+  @protected
+  SelectionSession dco_decode_selection_session(dynamic raw);
+
+  @protected
+  Settings dco_decode_settings(dynamic raw);
+
+  @protected
+  Snapshot dco_decode_snapshot(dynamic raw);
+
+  @protected
+  SnapshotReason dco_decode_snapshot_reason(dynamic raw);
+
+  @protected
+  SpreadsheetImport dco_decode_spreadsheet_import(dynamic raw);
+
+  @protected
+  SubjectReport dco_decode_subject_report(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  int dco_decode_u_64(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  UpdateError dco_decode_update_error(dynamic raw);
+
+  @protected
+  int dco_decode_usize(dynamic raw);
+
   @protected
   Worker dco_decode_worker(dynamic raw);
 
+  @protected
+  WorkerField dco_decode_worker_field(dynamic raw);
+
+  @protected
+  WorkerPage dco_decode_worker_page(dynamic raw);
+
+  @protected
+  WorkerQuery dco_decode_worker_query(dynamic raw);
+
+  @protected
+  WorkerSort dco_decode_worker_sort(dynamic raw);
+
+  @protected
+  WorkerSortKey dco_decode_worker_sort_key(dynamic raw);
+
+  @protected
+  XmlImportSummary dco_decode_xml_import_summary(dynamic raw);
+
+  @protected
+  DateTime sse_decode_Chrono_Local(SseDeserializer deserializer);
+
+  @protected
+  DateTime sse_decode_Chrono_Naive(SseDeserializer deserializer);
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
   @protected
   UuidValue sse_decode_Uuid(SseDeserializer deserializer);
 
+  @protected
+  AuditAction sse_decode_audit_action(SseDeserializer deserializer);
+
+  @protected
+  AuditEntry sse_decode_audit_entry(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
+  @protected
+  DateTime sse_decode_box_autoadd_Chrono_Local(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  Crew sse_decode_box_autoadd_crew(SseDeserializer deserializer);
+
+  @protected
+  HistoryItem sse_decode_box_autoadd_history_item(SseDeserializer deserializer);
+
+  @protected
+  ImportProfile sse_decode_box_autoadd_import_profile(
+      SseDeserializer deserializer);
+
+  @protected
+  ReportDraft sse_decode_box_autoadd_report_draft(SseDeserializer deserializer);
+
+  @protected
+  Settings sse_decode_box_autoadd_settings(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
   @protected
   Worker sse_decode_box_autoadd_worker(SseDeserializer deserializer);
 
+  @protected
+  WorkerQuery sse_decode_box_autoadd_worker_query(SseDeserializer deserializer);
+
+  @protected
+  Worker sse_decode_box_worker(SseDeserializer deserializer);
+
+  @protected
+  ColumnMapping sse_decode_column_mapping(SseDeserializer deserializer);
+
+  @protected
+  Crew sse_decode_crew(SseDeserializer deserializer);
+
+  @protected
+  DbEvent sse_decode_db_event(SseDeserializer deserializer);
+
+  @protected
+  DraftIssue sse_decode_draft_issue(SseDeserializer deserializer);
+
+  @protected
+  DraftRow sse_decode_draft_row(SseDeserializer deserializer);
+
+  @protected
+  Employer sse_decode_employer(SseDeserializer deserializer);
+
+  @protected
+  ExportAppearance sse_decode_export_appearance(SseDeserializer deserializer);
+
+  @protected
+  ExportRecord sse_decode_export_record(SseDeserializer deserializer);
+
+  @protected
+  FieldChange sse_decode_field_change(SseDeserializer deserializer);
+
+  @protected
+  HistoryAction sse_decode_history_action(SseDeserializer deserializer);
+
+  @protected
+  HistoryItem sse_decode_history_item(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImportMode sse_decode_import_mode(SseDeserializer deserializer);
+
+  @protected
+  ImportProfile sse_decode_import_profile(SseDeserializer deserializer);
+
+  @protected
+  ImportRow sse_decode_import_row(SseDeserializer deserializer);
+
+  @protected
+  ImportSummary sse_decode_import_summary(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<UuidValue> sse_decode_list_Uuid(SseDeserializer deserializer);
+
+  @protected
+  List<AuditEntry> sse_decode_list_audit_entry(SseDeserializer deserializer);
+
+  @protected
+  List<ColumnMapping> sse_decode_list_column_mapping(
+      SseDeserializer deserializer);
+
+  @protected
+  List<Crew> sse_decode_list_crew(SseDeserializer deserializer);
+
+  @protected
+  List<DraftIssue> sse_decode_list_draft_issue(SseDeserializer deserializer);
+
+  @protected
+  List<DraftRow> sse_decode_list_draft_row(SseDeserializer deserializer);
+
+  @protected
+  List<Employer> sse_decode_list_employer(SseDeserializer deserializer);
+
+  @protected
+  List<ExportAppearance> sse_decode_list_export_appearance(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ExportRecord> sse_decode_list_export_record(
+      SseDeserializer deserializer);
+
+  @protected
+  List<FieldChange> sse_decode_list_field_change(SseDeserializer deserializer);
+
+  @protected
+  List<HistoryItem> sse_decode_list_history_item(SseDeserializer deserializer);
+
+  @protected
+  List<ImportProfile> sse_decode_list_import_profile(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ImportRow> sse_decode_list_import_row(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<ReportDraft> sse_decode_list_report_draft(SseDeserializer deserializer);
+
+  @protected
+  List<ReportedWorker> sse_decode_list_reported_worker(
+      SseDeserializer deserializer);
+
+  @protected
+  List<RetentionCandidate> sse_decode_list_retention_candidate(
+      SseDeserializer deserializer);
+
+  @protected
+  List<RosterColumn> sse_decode_list_roster_column(
+      SseDeserializer deserializer);
+
+  @protected
+  List<Snapshot> sse_decode_list_snapshot(SseDeserializer deserializer);
+
   @protected
   List<Worker> sse_decode_list_worker(SseDeserializer deserializer);
 
+  @protected
+  List<WorkerSort> sse_decode_list_worker_sort(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  UuidValue? sse_decode_opt_Uuid(SseDeserializer deserializer);
+
+  @protected
+  DateTime? sse_decode_opt_box_autoadd_Chrono_Local(
+      SseDeserializer deserializer);
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  HistoryItem? sse_decode_opt_box_autoadd_history_item(
+      SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  ReportDraft sse_decode_report_draft(SseDeserializer deserializer);
+
+  @protected
+  ReportedWorker sse_decode_reported_worker(SseDeserializer deserializer);
+
+  @protected
+  RetentionCandidate sse_decode_retention_candidate(
+      SseDeserializer deserializer);
+
+  @protected
+  RosterColumn sse_decode_roster_column(SseDeserializer deserializer);
+
+  @protected
+  RosterFormat sse_decode_roster_format(SseDeserializer deserializer);
+
+  @protected
+  RowOverride sse_decode_row_override(SseDeserializer deserializer);
+
+  @protected
+  SelectionSession sse_decode_selection_session(SseDeserializer deserializer);
+
+  @protected
+  Settings sse_decode_settings(SseDeserializer deserializer);
+
+  @protected
+  Snapshot sse_decode_snapshot(SseDeserializer deserializer);
+
+  @protected
+  SnapshotReason sse_decode_snapshot_reason(SseDeserializer deserializer);
+
+  @protected
+  SpreadsheetImport sse_decode_spreadsheet_import(SseDeserializer deserializer);
+
+  @protected
+  SubjectReport sse_decode_subject_report(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_64(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
   @protected
   void sse_decode_unit(SseDeserializer deserializer);
 
+  @protected
+  UpdateError sse_decode_update_error(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_usize(SseDeserializer deserializer);
+
   @protected
   Worker sse_decode_worker(SseDeserializer deserializer);
 
+  @protected
+  WorkerField sse_decode_worker_field(SseDeserializer deserializer);
+
+  @protected
+  WorkerPage sse_decode_worker_page(SseDeserializer deserializer);
+
+  @protected
+  WorkerQuery sse_decode_worker_query(SseDeserializer deserializer);
+
+  @protected
+  WorkerSort sse_decode_worker_sort(SseDeserializer deserializer);
+
+  @protected
+  WorkerSortKey sse_decode_worker_sort_key(SseDeserializer deserializer);
+
+  @protected
+  XmlImportSummary sse_decode_xml_import_summary(SseDeserializer deserializer);
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
+  @protected
+  void sse_encode_Chrono_Local(DateTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Chrono_Naive(DateTime self, SseSerializer serializer);
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
   @protected
   void sse_encode_Uuid(UuidValue self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_audit_action(AuditAction self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audit_entry(AuditEntry self, SseSerializer serializer);
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_Chrono_Local(
+      DateTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_crew(Crew self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_history_item(
+      HistoryItem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_import_profile(
+      ImportProfile self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_report_draft(
+      ReportDraft self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_settings(Settings self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_worker(Worker self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_worker_query(
+      WorkerQuery self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_worker(Worker self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_column_mapping(ColumnMapping self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_crew(Crew self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_db_event(DbEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_draft_issue(DraftIssue self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_draft_row(DraftRow self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_employer(Employer self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_export_appearance(
+      ExportAppearance self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_export_record(ExportRecord self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_field_change(FieldChange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_history_action(HistoryAction self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_history_item(HistoryItem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_64(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_mode(ImportMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_profile(ImportProfile self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_row(ImportRow self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_summary(ImportSummary self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_Uuid(List<UuidValue> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_audit_entry(
+      List<AuditEntry> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_column_mapping(
+      List<ColumnMapping> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_crew(List<Crew> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_draft_issue(
+      List<DraftIssue> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_draft_row(List<DraftRow> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_employer(List<Employer> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_export_appearance(
+      List<ExportAppearance> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_export_record(
+      List<ExportRecord> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_field_change(
+      List<FieldChange> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_history_item(
+      List<HistoryItem> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_import_profile(
+      List<ImportProfile> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_import_row(
+      List<ImportRow> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_strict(
       Uint8List self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_report_draft(
+      List<ReportDraft> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_reported_worker(
+      List<ReportedWorker> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_retention_candidate(
+      List<RetentionCandidate> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_roster_column(
+      List<RosterColumn> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_snapshot(List<Snapshot> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_worker(List<Worker> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_worker_sort(
+      List<WorkerSort> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_Uuid(UuidValue? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_Chrono_Local(
+      DateTime? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_history_item(
+      HistoryItem? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_report_draft(ReportDraft self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_reported_worker(
+      ReportedWorker self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_retention_candidate(
+      RetentionCandidate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_roster_column(RosterColumn self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_roster_format(RosterFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_row_override(RowOverride self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_selection_session(
+      SelectionSession self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_settings(Settings self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_snapshot(Snapshot self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_snapshot_reason(
+      SnapshotReason self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_spreadsheet_import(
+      SpreadsheetImport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_subject_report(SubjectReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_update_error(UpdateError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_usize(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_worker(Worker self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_worker_field(WorkerField self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_worker_page(WorkerPage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_worker_query(WorkerQuery self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_worker_sort(WorkerSort self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_worker_sort_key(WorkerSortKey self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_xml_import_summary(
+      XmlImportSummary self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 }
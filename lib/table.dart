@@ -8,7 +8,7 @@ import 'package:anykexport/src/rust/api/worker.dart';
 /// Example without a datasource
 class DataTable2SimpleDemo extends StatelessWidget {
   List<Worker> workers;
-  Function on_change;
+  bool Function(Worker) on_change;
   Function delete;
   bool full;
   String query;
@@ -70,9 +70,7 @@ class DataTable2SimpleDemo extends StatelessWidget {
                         ? [
                             DataCell(Checkbox(
                               onChanged: (b) {
-                                var nw = w;
-                                nw.isSelected = b!;
-                                on_change(nw);
+                                on_change(w.setSelected(to: b!));
                               },
                               value: w.isSelected,
                             )),
@@ -86,16 +84,12 @@ class DataTable2SimpleDemo extends StatelessWidget {
                                 delete: (d) {
                                   delete(d);
                                 },
-                                on_save: (d) {
-                                  on_change(d);
-                                }))
+                                on_save: (d) => on_change(d)))
                           ]
                         : [
                             DataCell(Checkbox(
                               onChanged: (b) {
-                                var nw = w;
-                                nw.isSelected = b!;
-                                on_change(nw);
+                                on_change(w.setSelected(to: b!));
                               },
                               value: w.isSelected,
                             )),
@@ -113,7 +107,7 @@ class DataTable2SimpleDemo extends StatelessWidget {
 
 class EditButton extends StatelessWidget {
   Worker worker;
-  Function(Worker) on_save;
+  bool Function(Worker) on_save;
   Function(Worker) delete;
 
   EditButton(
@@ -146,7 +140,7 @@ class EditButton extends StatelessWidget {
 
 class EditDialog extends StatelessWidget {
   Worker worker;
-  Function(Worker) on_save;
+  bool Function(Worker) on_save;
   Function(Worker) delete;
   final _formKey = GlobalKey<FormState>();
 
@@ -344,8 +338,9 @@ class EditDialog extends StatelessWidget {
             if (_formKey.currentState!.validate()) {
               _formKey.currentState?.save();
               print('Name is: ${worker.name}');
-              on_save(worker);
-              Navigator.of(context).pop();
+              if (on_save(worker)) {
+                Navigator.of(context).pop();
+              }
             }
           },
         ),
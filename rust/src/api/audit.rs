@@ -0,0 +1,305 @@
+use std::{
+    collections::HashSet,
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::PathBuf,
+};
+
+use chrono::{DateTime, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{
+    history::HistoryAction,
+    storage,
+    worker::{get_data_dir, Worker},
+};
+
+pub(crate) const AUDIT_LOG: &str = "audit.log";
+
+fn get_audit_path() -> Result<PathBuf, String> {
+    Ok(get_data_dir()?.join(AUDIT_LOG))
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum AuditAction {
+    Add,
+    Update,
+    Remove,
+    Select,
+    Export,
+    Restore,
+    Import,
+    SubjectAccess,
+    Anonymize,
+    RosterExport,
+}
+
+impl From<HistoryAction> for AuditAction {
+    fn from(action: HistoryAction) -> Self {
+        match action {
+            HistoryAction::Add => AuditAction::Add,
+            HistoryAction::Update => AuditAction::Update,
+            HistoryAction::Remove => AuditAction::Remove,
+            HistoryAction::Select => AuditAction::Select,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AuditEntry {
+    pub id: Uuid,
+    pub timestamp: DateTime<Local>,
+    pub operator: String,
+    pub action: AuditAction,
+    pub worker_ids: Vec<Uuid>,
+    pub changes: Vec<FieldChange>,
+    pub note: String,
+}
+
+impl AuditEntry {
+    pub(crate) fn new(operator: &str, action: AuditAction, worker_ids: Vec<Uuid>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            timestamp: Local::now(),
+            operator: operator.to_string(),
+            action,
+            worker_ids,
+            changes: vec![],
+            note: String::new(),
+        }
+    }
+    pub(crate) fn with_changes(mut self, before: Option<&Worker>, after: Option<&Worker>) -> Self {
+        self.changes = diff_workers(before, after);
+        self
+    }
+    pub(crate) fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.note = note.into();
+        self
+    }
+    // Append only, existing lines are never rewritten
+    pub(crate) fn append(&self) -> Result<(), String> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(get_audit_path()?)
+            .map_err(|e| e.to_string())?;
+        let line = storage::seal_line(&serde_json::to_string(&self).map_err(|e| e.to_string())?)?;
+        file.write_all(format!("{}\n", line).as_bytes())
+            .map_err(|e| e.to_string())?;
+        file.flush().map_err(|e| e.to_string())
+    }
+}
+
+pub(crate) fn read_audit_log(
+    worker_id: Option<Uuid>,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) -> Result<Vec<AuditEntry>, String> {
+    let path = get_audit_path()?;
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let file = File::open(&path).map_err(|e| e.to_string())?;
+    let mut res = vec![];
+    for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| e.to_string())?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let line = storage::unseal_line(&line)?;
+        let entry: AuditEntry = serde_json::from_str(&line).map_err(|e| e.to_string())?;
+        let date = entry.timestamp.date_naive();
+        if worker_id.is_some_and(|id| !entry.worker_ids.contains(&id))
+            || from.is_some_and(|from| date < from)
+            || to.is_some_and(|to| date > to)
+        {
+            continue;
+        }
+        res.push(entry);
+    }
+    Ok(res)
+}
+
+// Clear the changed values of the given workers.
+// The only case when the log is rewritten, required by the retention policy.
+pub(crate) fn redact_audit_log(ids: &[Uuid]) -> Result<(), String> {
+    let path = get_audit_path()?;
+    if !path.exists() {
+        return Ok(());
+    }
+    let content = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    let content = redact_audit_content(&content, ids)?;
+    // Write next to it first, so a failure never leaves a broken log
+    let tmp_path = path.with_extension("tmp");
+    std::fs::write(&tmp_path, content).map_err(|e| e.to_string())?;
+    std::fs::rename(&tmp_path, &path).map_err(|e| e.to_string())
+}
+
+// Same for a log as stored in the data folder, used for the snapshots
+pub(crate) fn redact_audit_content(content: &str, ids: &[Uuid]) -> Result<String, String> {
+    let mut res = String::new();
+    for line in content.lines().filter(|l| !l.trim().is_empty()) {
+        let mut entry: AuditEntry =
+            serde_json::from_str(&storage::unseal_line(line)?).map_err(|e| e.to_string())?;
+        if entry.worker_ids.iter().any(|id| ids.contains(id)) {
+            for change in &mut entry.changes {
+                change.before.clear();
+                change.after.clear();
+            }
+        }
+        let line = serde_json::to_string(&entry).map_err(|e| e.to_string())?;
+        res.push_str(&storage::seal_line(&line)?);
+        res.push('\n');
+    }
+    Ok(res)
+}
+
+// Append entries from another log that are not present yet
+pub(crate) fn merge_audit_log(content: &str) -> Result<usize, String> {
+    let existing = read_audit_log(None, None, None)?
+        .into_iter()
+        .map(|e| e.id)
+        .collect::<HashSet<Uuid>>();
+    let mut count = 0;
+    for line in content.lines().filter(|l| !l.trim().is_empty()) {
+        let entry: AuditEntry = serde_json::from_str(line).map_err(|e| e.to_string())?;
+        if !existing.contains(&entry.id) {
+            entry.append()?;
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+// Field level diff between two worker states
+fn diff_workers(before: Option<&Worker>, after: Option<&Worker>) -> Vec<FieldChange> {
+    let to_map = |w: Option<&Worker>| match w.map(serde_json::to_value) {
+        Some(Ok(serde_json::Value::Object(map))) => map,
+        _ => serde_json::Map::new(),
+    };
+    let to_string = |v: Option<&serde_json::Value>| match v {
+        Some(serde_json::Value::String(s)) => s.to_owned(),
+        Some(v) => v.to_string(),
+        None => String::new(),
+    };
+    let before = to_map(before);
+    let after = to_map(after);
+    let mut fields = before.keys().chain(after.keys()).collect::<Vec<&String>>();
+    fields.sort();
+    fields.dedup();
+    fields
+        .into_iter()
+        // Revision changes on every save
+        .filter(|f| *f != "revision" && before.get(*f) != after.get(*f))
+        .map(|f| FieldChange {
+            field: f.to_owned(),
+            before: to_string(before.get(f)),
+            after: to_string(after.get(f)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn worker() -> Worker {
+        Worker {
+            name: "Kiss Anna".to_string(),
+            city: "Szeged".to_string(),
+            ..Worker::default()
+        }
+    }
+
+    #[test]
+    fn diff_lists_the_changed_fields() {
+        let before = worker();
+        let after = Worker {
+            city: "Pécs".to_string(),
+            revision: before.revision + 1,
+            ..before.clone()
+        };
+        let changes = diff_workers(Some(&before), Some(&after));
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field, "city");
+        assert_eq!(changes[0].before, "Szeged");
+        assert_eq!(changes[0].after, "Pécs");
+    }
+
+    #[test]
+    fn diff_of_an_add_has_every_set_field() {
+        let changes = diff_workers(None, Some(&worker()));
+        let fields = changes.iter().map(|c| c.field.as_str()).collect::<Vec<_>>();
+        assert!(fields.contains(&"name") && fields.contains(&"city"));
+        assert!(!fields.contains(&"revision"));
+        assert!(changes.iter().all(|c| c.before.is_empty()));
+    }
+
+    #[test]
+    fn selection_is_not_in_the_diff() {
+        let before = worker();
+        assert!(diff_workers(Some(&before), Some(&before.set_selected(true))).is_empty());
+    }
+
+    #[test]
+    fn read_filters_by_worker() {
+        let anna = worker();
+        let bela = Worker::default();
+        AuditEntry::new("op", AuditAction::Add, vec![anna.id])
+            .with_changes(None, Some(&anna))
+            .append()
+            .unwrap();
+        AuditEntry::new("op", AuditAction::Export, vec![anna.id, bela.id])
+            .with_note("export.xml")
+            .append()
+            .unwrap();
+        assert_eq!(read_audit_log(None, None, None).unwrap().len(), 2);
+        let entries = read_audit_log(Some(bela.id), None, None).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].action, AuditAction::Export);
+        assert_eq!(entries[0].note, "export.xml");
+        let tomorrow = Local::now().date_naive().succ_opt().unwrap();
+        assert!(read_audit_log(None, Some(tomorrow), None)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn redact_clears_the_values_only() {
+        let anna = worker();
+        AuditEntry::new("op", AuditAction::Add, vec![anna.id])
+            .with_changes(None, Some(&anna))
+            .append()
+            .unwrap();
+        redact_audit_log(&[anna.id]).unwrap();
+        let entries = read_audit_log(None, None, None).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert!(!entries[0].changes.is_empty());
+        assert!(entries[0]
+            .changes
+            .iter()
+            .all(|c| c.before.is_empty() && c.after.is_empty()));
+    }
+
+    #[test]
+    fn merge_skips_known_entries() {
+        let entry = AuditEntry::new("op", AuditAction::Add, vec![Uuid::new_v4()]);
+        entry.append().unwrap();
+        let other = AuditEntry::new("op", AuditAction::Remove, vec![Uuid::new_v4()]);
+        let content = [&entry, &other]
+            .iter()
+            .map(|e| serde_json::to_string(e).unwrap())
+            .collect::<Vec<String>>()
+            .join("\n");
+        assert_eq!(merge_audit_log(&content).unwrap(), 1);
+        assert_eq!(merge_audit_log(&content).unwrap(), 0);
+    }
+}
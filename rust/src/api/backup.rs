@@ -0,0 +1,643 @@
+use std::{
+    collections::HashMap,
+    fs::{File, OpenOptions},
+    io::{Cursor, Read, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use chrono::{Duration, Local, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use zip::{write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::crypto::{decrypt_with_password, encrypt_with_password};
+
+use super::{
+    audit::{redact_audit_content, AUDIT_LOG},
+    draft::{redact_draft_file, DRAFTS_DIR},
+    export_history::EXPORTS_DIR,
+    export_lock::{EXPORT_LOCK_FILE, WORKER_LOCKS_DIR},
+    lock::PIN_FILE,
+    settings::{Settings, SETTINGS_FILE},
+    storage::{unseal_file, ENCRYPTION_FILE, PENDING_ENCRYPTION_FILE},
+    worker::{get_data_dir, get_worker_path, Db, Worker, WORKERS_DIR},
+};
+
+// With milliseconds, so snapshots made in the same second do not collide
+const SNAPSHOT_TIME_FORMAT: &str = "%Y%m%d-%H%M%S%3f";
+// Snapshots made before milliseconds were added
+const LEGACY_SNAPSHOT_TIME_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+const BACKUPS_DIR: &str = "backups";
+
+// Data folder and day of the last daily snapshot seen, so writes check the folder once a day
+static LAST_DAILY: Mutex<Option<(PathBuf, NaiveDate)>> = Mutex::new(None);
+
+pub(crate) type FileTransform<'a> = &'a dyn Fn(&str, Vec<u8>) -> Result<Vec<u8>, String>;
+
+fn get_backups_dir() -> Result<PathBuf, String> {
+    let backups_dir = get_data_dir()?.join(BACKUPS_DIR);
+    // Check if exist
+    if !backups_dir.exists() {
+        // Create path if needed
+        let _ = std::fs::create_dir_all(&backups_dir);
+    }
+    Ok(backups_dir)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum SnapshotReason {
+    Daily,
+    Export,
+    Manual,
+    Restore,
+}
+
+impl SnapshotReason {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SnapshotReason::Daily => "daily",
+            SnapshotReason::Export => "export",
+            SnapshotReason::Manual => "manual",
+            SnapshotReason::Restore => "restore",
+        }
+    }
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "daily" => Some(SnapshotReason::Daily),
+            "export" => Some(SnapshotReason::Export),
+            "manual" => Some(SnapshotReason::Manual),
+            "restore" => Some(SnapshotReason::Restore),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Snapshot {
+    // File name inside the backups folder
+    pub id: String,
+    pub created_at: NaiveDateTime,
+    pub reason: SnapshotReason,
+    pub size: u64,
+}
+
+impl Snapshot {
+    // {timestamp}-{reason}.zip
+    fn from_path(path: &Path) -> Option<Self> {
+        let id = path.file_name()?.to_str()?.to_string();
+        let (timestamp, reason) = id.strip_suffix(".zip")?.rsplit_once('-')?;
+        let created_at = NaiveDateTime::parse_from_str(timestamp, SNAPSHOT_TIME_FORMAT)
+            .or_else(|_| NaiveDateTime::parse_from_str(timestamp, LEGACY_SNAPSHOT_TIME_FORMAT))
+            .ok()?;
+        Some(Self {
+            created_at,
+            reason: SnapshotReason::parse(reason)?,
+            size: path.metadata().ok()?.len(),
+            id,
+        })
+    }
+}
+
+pub(crate) fn list_snapshots() -> Result<Vec<Snapshot>, String> {
+    let mut res = std::fs::read_dir(get_backups_dir()?)
+        .map_err(|e| e.to_string())?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| Snapshot::from_path(&entry.path()))
+        .collect::<Vec<Snapshot>>();
+    // Most recent first
+    res.sort_by_key(|s| std::cmp::Reverse(s.created_at));
+    Ok(res)
+}
+
+pub(crate) fn create_snapshot(reason: SnapshotReason) -> Result<Snapshot, String> {
+    let data = archive_data_dir(&keep_file)?;
+    let backups_dir = get_backups_dir()?;
+    // Always after the newest one, so the order follows creation
+    // even for snapshots taken within the same millisecond
+    let mut created_at = Local::now().naive_local();
+    if let Some(newest) = list_snapshots()?.first() {
+        created_at = created_at.max(newest.created_at + Duration::milliseconds(1));
+    }
+    // Another instance may have taken the name
+    let (path, mut file) = loop {
+        let path = backups_dir.join(format!(
+            "{}-{}.zip",
+            created_at.format(SNAPSHOT_TIME_FORMAT),
+            reason.as_str()
+        ));
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => break (path, file),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                created_at += Duration::milliseconds(1);
+            }
+            Err(e) => return Err(e.to_string()),
+        }
+    };
+    file.write_all(&data).map_err(|e| e.to_string())?;
+    file.flush().map_err(|e| e.to_string())?;
+    rotate_snapshots(Settings::load()?.backup_keep as usize)?;
+    Snapshot::from_path(&path).ok_or_else(|| "Error while creating snapshot".to_string())
+}
+
+// Called on startup and before every write, creates at most one snapshot a day
+pub(crate) fn ensure_daily_snapshot() -> Result<(), String> {
+    let data_dir = get_data_dir()?;
+    let today = Local::now().date_naive();
+    let mut last = LAST_DAILY.lock().unwrap();
+    if last.as_ref() == Some(&(data_dir.to_owned(), today)) {
+        return Ok(());
+    }
+    let exists = list_snapshots()?
+        .iter()
+        .any(|s| s.reason == SnapshotReason::Daily && s.created_at.date() == today);
+    if !exists {
+        create_snapshot(SnapshotReason::Daily)?;
+    }
+    *last = Some((data_dir, today));
+    Ok(())
+}
+
+// Replace the data folder content with the given snapshot.
+// The audit log and the export history are kept as they are, as they must never be rolled back.
+pub(crate) fn restore_snapshot(id: &str) -> Result<(), String> {
+    // Only a file listed in the backups folder, never a path
+    if id.contains(['/', '\\'])
+        || id.contains("..")
+        || !list_snapshots()?.iter().any(|s| s.id == id)
+    {
+        return Err(format!("Snapshot not found: {}", id));
+    }
+    let path = get_backups_dir()?.join(id);
+    let mut buffer = vec![];
+    File::open(&path)
+        .map_err(|e| e.to_string())?
+        .read_to_end(&mut buffer)
+        .map_err(|e| e.to_string())?;
+    replace_data_dir(&buffer, &keep_file)
+}
+
+pub(crate) fn replace_data_dir(bytes: &[u8], transform: FileTransform) -> Result<(), String> {
+    // Check the archive before touching anything
+    ZipArchive::new(Cursor::new(bytes)).map_err(|e| e.to_string())?;
+    // Current state can be restored later
+    create_snapshot(SnapshotReason::Restore)?;
+    clear_data_dir()?;
+    extract_archive(bytes, transform)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ImportMode {
+    // Add new and overwrite changed workers, keep the rest
+    Merge,
+    // Replace the whole data folder
+    Replace,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ImportSummary {
+    pub added: Vec<String>,
+    pub updated: Vec<String>,
+    pub removed: Vec<String>,
+    pub unchanged: u32,
+    pub replaces_settings: bool,
+}
+
+pub(crate) struct BackupContent {
+    pub(crate) archive: Vec<u8>,
+    pub(crate) workers: Vec<Worker>,
+    pub(crate) audit_log: String,
+    pub(crate) has_settings: bool,
+}
+
+impl BackupContent {
+    pub(crate) fn summary(&self, current: &Db, mode: ImportMode) -> ImportSummary {
+        let mut summary = ImportSummary {
+            replaces_settings: mode == ImportMode::Replace && self.has_settings,
+            ..Default::default()
+        };
+        for worker in &self.workers {
+            match current.get_by_id(worker.id) {
+                None => summary.added.push(worker.name.to_owned()),
+                // Selection is not part of the backup
+                Some(w) if w.set_selected(false) != *worker => {
+                    summary.updated.push(worker.name.to_owned())
+                }
+                Some(_) => summary.unchanged += 1,
+            }
+        }
+        if mode == ImportMode::Replace {
+            summary.removed = current
+                .workers()
+                .filter(|w| !self.workers.iter().any(|n| n.id == w.id))
+                .map(|w| w.name.to_owned())
+                .collect();
+        }
+        summary
+    }
+    // Workers to write when merging
+    pub(crate) fn merge_changes(&self, current: &Db) -> Vec<(Uuid, Option<Worker>)> {
+        self.workers
+            .iter()
+            .filter_map(|worker| match current.get_by_id(worker.id) {
+                Some(w) if w.set_selected(false) == *worker => None,
+                Some(w) => Some((worker.id, Some(worker.set_selected(w.is_selected)))),
+                None => Some((worker.id, Some(worker.clone()))),
+            })
+            .collect()
+    }
+}
+
+// Whole data folder in a single password protected file
+pub(crate) fn write_backup_file(path: &str, password: &str) -> Result<(), String> {
+    if password.is_empty() {
+        return Err("Password is required".into());
+    }
+    // Stored in plain form inside, so it can be opened with the password only
+    let data = encrypt_with_password(password, &archive_data_dir(&unseal_file)?)?;
+    std::fs::write(path, data).map_err(|e| e.to_string())
+}
+
+pub(crate) fn read_backup_file(path: &str, password: &str) -> Result<BackupContent, String> {
+    let data = std::fs::read(path).map_err(|e| e.to_string())?;
+    let archive = decrypt_with_password(password, &data)?;
+    let mut workers = vec![];
+    let mut audit_log = String::new();
+    let mut has_settings = false;
+    let mut zip = ZipArchive::new(Cursor::new(&archive)).map_err(|e| e.to_string())?;
+    for i in 0..zip.len() {
+        let mut file = zip.by_index(i).map_err(|e| e.to_string())?;
+        let name = file.name().to_string();
+        let mut buffer = String::new();
+        if name.starts_with("workers/") && name.ends_with(".json") {
+            file.read_to_string(&mut buffer)
+                .map_err(|e| e.to_string())?;
+            let worker: Worker = serde_json::from_str(&buffer)
+                .map_err(|e| format!("Invalid worker file {}: {}", name, e))?;
+            // Older backups still contain the selection
+            workers.push(worker.set_selected(false));
+        } else if name == AUDIT_LOG {
+            file.read_to_string(&mut audit_log)
+                .map_err(|e| e.to_string())?;
+        } else if name == SETTINGS_FILE {
+            file.read_to_string(&mut buffer)
+                .map_err(|e| e.to_string())?;
+            serde_json::from_str::<Settings>(&buffer)
+                .map_err(|e| format!("Invalid settings file: {}", e))?;
+            has_settings = true;
+        }
+    }
+    Ok(BackupContent {
+        archive,
+        workers,
+        audit_log,
+        has_settings,
+    })
+}
+
+fn rotate_snapshots(keep: usize) -> Result<(), String> {
+    let backups_dir = get_backups_dir()?;
+    for snapshot in list_snapshots()?.iter().skip(keep.max(1)) {
+        std::fs::remove_file(backups_dir.join(&snapshot.id)).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+// Data folder files with their relative paths, except local only ones
+fn data_files() -> Result<Vec<(String, PathBuf)>, String> {
+    let data_dir = get_data_dir()?;
+    let mut files = vec![];
+    collect_files(&data_dir, &mut files)?;
+    let mut res = vec![];
+    for path in files {
+        let name = path
+            .strip_prefix(&data_dir)
+            .map_err(|e| e.to_string())?
+            .to_string_lossy()
+            .replace('\\', "/");
+        if !is_local_file(&name) {
+            res.push((name, path));
+        }
+    }
+    Ok(res)
+}
+
+// Backups, the encryption key info and the PIN belong to this machine only,
+// the locks to the running instances
+fn is_local_file(name: &str) -> bool {
+    name.starts_with(BACKUPS_DIR)
+        || name == ENCRYPTION_FILE
+        || name == PENDING_ENCRYPTION_FILE
+        || name == PIN_FILE
+        || name == EXPORT_LOCK_FILE
+        || name.starts_with(WORKER_LOCKS_DIR)
+}
+
+// Zip the data folder content into memory
+pub(crate) fn archive_data_dir(transform: FileTransform) -> Result<Vec<u8>, String> {
+    let mut zip = ZipWriter::new(Cursor::new(vec![]));
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+    for (name, path) in data_files()? {
+        let buffer = std::fs::read(&path).map_err(|e| e.to_string())?;
+        let buffer = transform(&name, buffer)?;
+        zip.start_file(name, options).map_err(|e| e.to_string())?;
+        zip.write_all(&buffer).map_err(|e| e.to_string())?;
+    }
+    Ok(zip.finish().map_err(|e| e.to_string())?.into_inner())
+}
+
+// Unzip an archive created by archive_data_dir into the data folder
+fn extract_archive(bytes: &[u8], transform: FileTransform) -> Result<(), String> {
+    let data_dir = get_data_dir()?;
+    let mut zip = ZipArchive::new(Cursor::new(bytes)).map_err(|e| e.to_string())?;
+    for i in 0..zip.len() {
+        let mut file = zip.by_index(i).map_err(|e| e.to_string())?;
+        let name = match file.enclosed_name() {
+            Some(name) => name.to_string_lossy().replace('\\', "/"),
+            None => continue,
+        };
+        if file.is_dir() || is_local_file(&name) || name == AUDIT_LOG {
+            continue;
+        }
+        let path = data_dir.join(&name);
+        // Exports are only ever added
+        if name.starts_with(&format!("{}/", EXPORTS_DIR)) && path.exists() {
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let mut buffer = vec![];
+        file.read_to_end(&mut buffer).map_err(|e| e.to_string())?;
+        std::fs::write(&path, transform(&name, buffer)?).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+// Apply the transform to every file of the data folder in place
+pub(crate) fn rewrite_data_dir(transform: FileTransform) -> Result<(), String> {
+    for (name, path) in data_files()? {
+        let buffer = std::fs::read(&path).map_err(|e| e.to_string())?;
+        let buffer = transform(&name, buffer)?;
+        // Write next to it first, so a failure never leaves a broken file
+        let mut tmp_path = path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        std::fs::write(&tmp_path, buffer).map_err(|e| e.to_string())?;
+        std::fs::rename(&tmp_path, &path).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+// Apply the transform to every file of every snapshot
+pub(crate) fn rewrite_snapshots(transform: FileTransform) -> Result<(), String> {
+    let backups_dir = get_backups_dir()?;
+    for snapshot in list_snapshots()? {
+        let path = backups_dir.join(&snapshot.id);
+        let buffer = std::fs::read(&path).map_err(|e| e.to_string())?;
+        let mut source = ZipArchive::new(Cursor::new(buffer)).map_err(|e| e.to_string())?;
+        let mut zip = ZipWriter::new(Cursor::new(vec![]));
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        for i in 0..source.len() {
+            let mut file = source.by_index(i).map_err(|e| e.to_string())?;
+            let name = file.name().to_string();
+            let mut buffer = vec![];
+            file.read_to_end(&mut buffer).map_err(|e| e.to_string())?;
+            zip.start_file(name.as_str(), options)
+                .map_err(|e| e.to_string())?;
+            zip.write_all(&transform(&name, buffer)?)
+                .map_err(|e| e.to_string())?;
+        }
+        let buffer = zip.finish().map_err(|e| e.to_string())?.into_inner();
+        // Write next to it first, so a failure never leaves a broken snapshot
+        let tmp_path = path.with_extension("tmp");
+        std::fs::write(&tmp_path, buffer).map_err(|e| e.to_string())?;
+        std::fs::rename(&tmp_path, &path).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+// Anonymized workers are replaced by their current file, their audit values
+// and draft overrides cleared in every snapshot. Exports are replaced by
+// their current copy, redacted already.
+pub(crate) fn redact_snapshots(ids: &[Uuid]) -> Result<(), String> {
+    let names = ids
+        .iter()
+        .map(|id| (format!("{}/{}.json", WORKERS_DIR, id.as_simple()), *id))
+        .collect::<HashMap<String, Uuid>>();
+    rewrite_snapshots(&|name, data| {
+        if let Some(id) = names.get(name) {
+            return std::fs::read(get_worker_path(*id)?).map_err(|e| e.to_string());
+        }
+        if name == AUDIT_LOG {
+            let content = String::from_utf8(data).map_err(|e| e.to_string())?;
+            return Ok(redact_audit_content(&content, ids)?.into_bytes());
+        }
+        if name.starts_with(&format!("{}/", DRAFTS_DIR)) {
+            return redact_draft_file(data, ids);
+        }
+        let path = get_data_dir()?.join(name);
+        if name.starts_with(&format!("{}/", EXPORTS_DIR)) && path.exists() {
+            return std::fs::read(path).map_err(|e| e.to_string());
+        }
+        Ok(data)
+    })
+}
+
+// Remove everything except local files and the audit log
+fn clear_data_dir() -> Result<(), String> {
+    for entry in std::fs::read_dir(get_data_dir()?).map_err(|e| e.to_string())? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        if is_local_file(name) || name == AUDIT_LOG || name == EXPORTS_DIR {
+            continue;
+        }
+        if path.is_dir() {
+            std::fs::remove_dir_all(&path).map_err(|e| e.to_string())?;
+        } else {
+            std::fs::remove_file(&path).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+    for entry in std::fs::read_dir(dir).map_err(|e| e.to_string())? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+// Snapshots keep files as they are stored
+fn keep_file(_name: &str, data: Vec<u8>) -> Result<Vec<u8>, String> {
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{audit::read_audit_log, history::HistoryAction, worker::Db};
+
+    fn add_worker(db: &mut Db, name: &str) -> Worker {
+        let worker = Worker {
+            name: name.to_string(),
+            ..Worker::default()
+        };
+        db.add_new_worker(worker.clone()).unwrap();
+        // As saved, with its revision
+        db.get_by_id(worker.id).unwrap().clone()
+    }
+
+    #[test]
+    fn snapshots_in_the_same_second_do_not_collide() {
+        let ids = (0..5)
+            .map(|_| create_snapshot(SnapshotReason::Manual).unwrap().id)
+            .collect::<Vec<String>>();
+        let listed = list_snapshots().unwrap();
+        assert_eq!(listed.len(), 5);
+        // Newest first
+        assert_eq!(
+            listed.iter().map(|s| s.id.to_owned()).collect::<Vec<_>>(),
+            ids.into_iter().rev().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn legacy_snapshot_names_are_listed() {
+        let path = get_backups_dir().unwrap().join("20240314-183005-daily.zip");
+        std::fs::write(&path, archive_data_dir(&keep_file).unwrap()).unwrap();
+        let snapshot = Snapshot::from_path(&path).unwrap();
+        assert_eq!(snapshot.reason, SnapshotReason::Daily);
+        assert_eq!(snapshot.created_at.to_string(), "2024-03-14 18:30:05");
+        assert!(Snapshot::from_path(&path.with_file_name("notes-daily.zip")).is_none());
+    }
+
+    #[test]
+    fn rotation_keeps_the_configured_number() {
+        Settings {
+            backup_keep: 2,
+            ..Settings::default()
+        }
+        .save()
+        .unwrap();
+        for _ in 0..4 {
+            create_snapshot(SnapshotReason::Manual).unwrap();
+        }
+        assert_eq!(list_snapshots().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn first_write_of_the_day_makes_a_daily_snapshot() {
+        let mut db = Db::open().unwrap();
+        add_worker(&mut db, "Kiss Anna");
+        add_worker(&mut db, "Nagy Béla");
+        ensure_daily_snapshot().unwrap();
+        let snapshots = list_snapshots().unwrap();
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[0].reason, SnapshotReason::Daily);
+    }
+
+    #[test]
+    fn restore_brings_back_the_workers_but_not_the_log() {
+        let mut db = Db::open().unwrap();
+        let anna = add_worker(&mut db, "Kiss Anna");
+        let snapshot = create_snapshot(SnapshotReason::Manual).unwrap();
+        db.remove_worker(anna.clone()).unwrap();
+        let entries = read_audit_log(None, None, None).unwrap().len();
+        restore_snapshot(&snapshot.id).unwrap();
+        assert!(Db::open().unwrap().get_by_id(anna.id).is_some());
+        assert_eq!(read_audit_log(None, None, None).unwrap().len(), entries);
+        // The state before the restore is kept
+        assert!(list_snapshots()
+            .unwrap()
+            .iter()
+            .any(|s| s.reason == SnapshotReason::Restore));
+    }
+
+    #[test]
+    fn restore_accepts_listed_snapshots_only() {
+        let snapshot = create_snapshot(SnapshotReason::Manual).unwrap();
+        let outside = get_data_dir().unwrap().join("20240314-183005-manual.zip");
+        std::fs::write(&outside, archive_data_dir(&keep_file).unwrap()).unwrap();
+        for id in [
+            "../20240314-183005-manual.zip",
+            "..\\20240314-183005-manual.zip",
+            "20240314-183005-manual.zip",
+            "",
+        ] {
+            assert!(restore_snapshot(id).is_err(), "{}", id);
+        }
+        restore_snapshot(&snapshot.id).unwrap();
+    }
+
+    #[test]
+    fn backup_file_needs_the_password() {
+        let mut db = Db::open().unwrap();
+        add_worker(&mut db, "Tóth Géza");
+        let path = get_data_dir().unwrap().with_extension("dwb");
+        let path = path.to_str().unwrap();
+        assert!(write_backup_file(path, "").is_err());
+        write_backup_file(path, "titok").unwrap();
+        assert!(read_backup_file(path, "rossz").is_err());
+        let backup = read_backup_file(path, "titok").unwrap();
+        assert_eq!(backup.workers.len(), 1);
+        assert!(!backup.audit_log.is_empty());
+    }
+
+    #[test]
+    fn backup_summary_and_merge() {
+        let mut db = Db::open().unwrap();
+        let geza = add_worker(&mut db, "Tóth Géza");
+        let anna = add_worker(&mut db, "Kiss Anna");
+        let path = get_data_dir().unwrap().with_extension("dwb");
+        let path = path.to_str().unwrap();
+        write_backup_file(path, "titok").unwrap();
+        let backup = read_backup_file(path, "titok").unwrap();
+        db.remove_worker(geza.clone()).unwrap();
+        let bela = add_worker(&mut db, "Nagy Béla");
+        let summary = backup.summary(&db, ImportMode::Merge);
+        assert_eq!(summary.added, vec!["Tóth Géza".to_string()]);
+        assert_eq!(summary.unchanged, 1);
+        assert!(summary.removed.is_empty());
+        let summary = backup.summary(&db, ImportMode::Replace);
+        assert_eq!(summary.removed, vec!["Nagy Béla".to_string()]);
+        let changes = backup.merge_changes(&db);
+        assert_eq!(changes.len(), 1);
+        db.apply_changes(HistoryAction::Update, "Import".into(), changes, "Import")
+            .unwrap();
+        let db = Db::open().unwrap();
+        for id in [geza.id, anna.id, bela.id] {
+            assert!(db.get_by_id(id).is_some());
+        }
+    }
+
+    #[test]
+    fn anonymizing_cleans_the_snapshots() {
+        let mut db = Db::open().unwrap();
+        let anna = add_worker(&mut db, "Kiss Anna");
+        let snapshot = create_snapshot(SnapshotReason::Manual).unwrap();
+        db.anonymize_workers(&[anna.id]).unwrap();
+        let buffer = std::fs::read(get_backups_dir().unwrap().join(&snapshot.id)).unwrap();
+        let mut zip = ZipArchive::new(Cursor::new(buffer)).unwrap();
+        let mut read = |name: &str| {
+            let mut content = String::new();
+            zip.by_name(name)
+                .unwrap()
+                .read_to_string(&mut content)
+                .unwrap();
+            content
+        };
+        let worker: Worker =
+            serde_json::from_str(&read(&format!("workers/{}.json", anna.id.as_simple()))).unwrap();
+        assert!(worker.anonymized_at.is_some());
+        assert!(!read(AUDIT_LOG).contains("Kiss Anna"));
+    }
+}
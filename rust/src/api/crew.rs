@@ -0,0 +1,144 @@
+use std::path::PathBuf;
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::text::collation_key;
+
+use super::worker::get_data_dir;
+
+pub(crate) const CREWS_FILE: &str = "crews.json";
+
+fn get_crews_path() -> Result<PathBuf, String> {
+    Ok(get_data_dir()?.join(CREWS_FILE))
+}
+
+// Saved set of workers, selected together
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Crew {
+    pub id: Uuid,
+    pub name: String,
+    pub worker_ids: Vec<Uuid>,
+    pub updated_at: DateTime<Local>,
+}
+
+impl Crew {
+    pub(crate) fn new(name: String, worker_ids: Vec<Uuid>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            worker_ids,
+            updated_at: Local::now(),
+        }
+    }
+}
+
+// Sorted by name
+pub(crate) fn load_crews() -> Result<Vec<Crew>, String> {
+    let path = get_crews_path()?;
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let buffer = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    let mut crews: Vec<Crew> = serde_json::from_str(&buffer).map_err(|e| e.to_string())?;
+    crews.sort_by_cached_key(|c| collation_key(&c.name));
+    Ok(crews)
+}
+
+fn save_crews(crews: &[Crew]) -> Result<(), String> {
+    let buffer = serde_json::to_string(crews).map_err(|e| e.to_string())?;
+    std::fs::write(get_crews_path()?, buffer).map_err(|e| e.to_string())
+}
+
+pub(crate) fn get_crew(id: Uuid) -> Result<Crew, String> {
+    load_crews()?
+        .into_iter()
+        .find(|c| c.id == id)
+        .ok_or_else(|| "Crew not found by ID".to_string())
+}
+
+// Added when the ID is new, replaced otherwise
+pub(crate) fn save_crew(mut crew: Crew) -> Result<Crew, String> {
+    crew.name = crew.name.trim().to_string();
+    if crew.name.is_empty() {
+        return Err("Crew name is required".into());
+    }
+    let mut crews = load_crews()?;
+    if crews.iter().any(|c| c.id != crew.id && c.name == crew.name) {
+        return Err("A crew with this name already exists".into());
+    }
+    crew.updated_at = Local::now();
+    crew.worker_ids.sort();
+    crew.worker_ids.dedup();
+    crews.retain(|c| c.id != crew.id);
+    crews.push(crew.clone());
+    save_crews(&crews)?;
+    Ok(crew)
+}
+
+pub(crate) fn delete_crew(id: Uuid) -> Result<(), String> {
+    let mut crews = load_crews()?;
+    crews.retain(|c| c.id != id);
+    save_crews(&crews)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(crews: &[Crew]) -> Vec<&str> {
+        crews.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    #[test]
+    fn crews_are_saved_and_sorted_by_name() {
+        assert!(load_crews().unwrap().is_empty());
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let saved = save_crew(Crew::new("  Szüret ".to_string(), vec![b, a, b])).unwrap();
+        assert_eq!(saved.name, "Szüret");
+        let mut ids = vec![a, b];
+        ids.sort();
+        assert_eq!(saved.worker_ids, ids);
+        save_crew(Crew::new("Csomagolás".to_string(), vec![])).unwrap();
+        save_crew(Crew::new("Cukorrépa".to_string(), vec![])).unwrap();
+        assert_eq!(
+            names(&load_crews().unwrap()),
+            vec!["Cukorrépa", "Csomagolás", "Szüret"]
+        );
+        assert_eq!(get_crew(saved.id).unwrap().worker_ids, ids);
+    }
+
+    #[test]
+    fn names_are_required_and_unique() {
+        assert!(save_crew(Crew::new(" ".to_string(), vec![])).is_err());
+        let crew = save_crew(Crew::new("Szüret".to_string(), vec![])).unwrap();
+        assert!(save_crew(Crew::new("Szüret ".to_string(), vec![])).is_err());
+        // Saving it again keeps its own name
+        save_crew(crew).unwrap();
+        assert_eq!(load_crews().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn update_replaces_and_delete_removes() {
+        let crew = save_crew(Crew::new("Szüret".to_string(), vec![])).unwrap();
+        let other = save_crew(Crew::new("Metszés".to_string(), vec![])).unwrap();
+        let worker = Uuid::new_v4();
+        save_crew(Crew {
+            name: "Őszi szüret".to_string(),
+            worker_ids: vec![worker],
+            ..crew.clone()
+        })
+        .unwrap();
+        assert_eq!(
+            names(&load_crews().unwrap()),
+            vec!["Metszés", "Őszi szüret"]
+        );
+        assert_eq!(get_crew(crew.id).unwrap().worker_ids, vec![worker]);
+        delete_crew(other.id).unwrap();
+        assert_eq!(names(&load_crews().unwrap()), vec!["Őszi szüret"]);
+        assert!(get_crew(other.id).is_err());
+        // Deleting it again is not an error
+        delete_crew(other.id).unwrap();
+    }
+}
@@ -0,0 +1,376 @@
+use std::{collections::HashSet, path::PathBuf};
+
+use chrono::{DateTime, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::xml::{render_xml, validate_xml, RenderContext, ReportRow, DEFAULT_CATEGORY};
+
+use super::{
+    settings::{Employer, Settings},
+    storage,
+    worker::{format_date, get_data_dir, parse_date, Db, Worker},
+};
+
+pub(crate) const DRAFTS_DIR: &str = "drafts";
+
+fn get_drafts_dir() -> Result<PathBuf, String> {
+    let dir = get_data_dir()?.join(DRAFTS_DIR);
+    if !dir.exists() {
+        std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    }
+    Ok(dir)
+}
+
+// Values reported instead of the ones of the worker
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct RowOverride {
+    pub name: Option<String>,
+    pub taj: Option<String>,
+    pub taxnumber: Option<String>,
+    pub category: Option<String>,
+    pub days: Option<u32>,
+}
+
+impl RowOverride {
+    // Worker as it will be reported
+    fn apply(&self, worker: &Worker) -> Worker {
+        Worker {
+            name: self.name.clone().unwrap_or_else(|| worker.name.to_owned()),
+            taj: self.taj.clone().unwrap_or_else(|| worker.taj.to_owned()),
+            taxnumber: self
+                .taxnumber
+                .clone()
+                .unwrap_or_else(|| worker.taxnumber.to_owned()),
+            ..worker.clone()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DraftRow {
+    pub worker_id: Uuid,
+    #[serde(default)]
+    pub overrides: RowOverride,
+}
+
+// Report prepared ahead, turned into XML when exported
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReportDraft {
+    pub id: Uuid,
+    // Day of the report, as %Y-%m-%d
+    pub date: String,
+    // Tax number of the employer
+    pub employer: String,
+    pub category: String,
+    pub rows: Vec<DraftRow>,
+    pub updated_at: DateTime<Local>,
+    pub exported_at: Option<DateTime<Local>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DraftIssue {
+    // None if it is about the whole draft
+    pub worker_id: Option<Uuid>,
+    pub message: String,
+}
+
+impl DraftIssue {
+    fn new(worker_id: Option<Uuid>, message: impl Into<String>) -> Self {
+        Self {
+            worker_id,
+            message: message.into(),
+        }
+    }
+}
+
+impl ReportDraft {
+    pub(crate) fn new(date: NaiveDate, employer: String, worker_ids: Vec<Uuid>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            date: format_date(date),
+            employer,
+            category: DEFAULT_CATEGORY.to_string(),
+            rows: worker_ids
+                .into_iter()
+                .map(|worker_id| DraftRow {
+                    worker_id,
+                    overrides: RowOverride::default(),
+                })
+                .collect(),
+            updated_at: Local::now(),
+            exported_at: None,
+        }
+    }
+    fn path(id: Uuid) -> Result<PathBuf, String> {
+        Ok(get_drafts_dir()?.join(format!("{}.json", id.as_simple())))
+    }
+    pub(crate) fn load(id: Uuid) -> Result<Self, String> {
+        let path = Self::path(id)?;
+        if !path.exists() {
+            return Err("Draft not found by ID".into());
+        }
+        let buffer = std::fs::read(&path).map_err(|e| e.to_string())?;
+        serde_json::from_slice(&storage::unseal(&buffer)?).map_err(|e| e.to_string())
+    }
+    // Overrides may hold personal data, so it is encrypted like the workers
+    pub(crate) fn save(&mut self) -> Result<(), String> {
+        self.updated_at = Local::now();
+        let data = storage::seal(
+            serde_json::to_string(&self)
+                .map_err(|e| e.to_string())?
+                .as_bytes(),
+        )?;
+        std::fs::write(Self::path(self.id)?, data).map_err(|e| e.to_string())
+    }
+    pub(crate) fn delete(id: Uuid) -> Result<(), String> {
+        std::fs::remove_file(Self::path(id)?).map_err(|e| e.to_string())
+    }
+    pub(crate) fn employer(&self) -> Result<Employer, String> {
+        Settings::load()?
+            .employers
+            .into_iter()
+            .find(|e| e.tax_number == self.employer)
+            .ok_or_else(|| "Unknown employer".to_string())
+    }
+    // Workers with the overrides applied, one for each row in the order of the
+    // draft, validate relies on it to tell the worker of a form issue
+    pub(crate) fn report_rows(&self, db: &Db) -> Result<Vec<ReportRow>, String> {
+        self.rows
+            .iter()
+            .map(|row| {
+                let worker = db
+                    .get_by_id(row.worker_id)
+                    .ok_or_else(|| "Worker not found by ID".to_string())?;
+                let o = &row.overrides;
+                Ok(ReportRow {
+                    category: o
+                        .category
+                        .clone()
+                        .unwrap_or_else(|| self.category.to_owned()),
+                    days: o.days.unwrap_or(1),
+                    ..ReportRow::from(&o.apply(worker))
+                })
+            })
+            .collect()
+    }
+    // Empty if it can be exported
+    pub(crate) fn validate(&self, db: &Db) -> Vec<DraftIssue> {
+        let mut res = vec![];
+        if self.employer().is_err() {
+            res.push(DraftIssue::new(None, "Unknown employer"));
+        }
+        if parse_date(&self.date).is_err() {
+            res.push(DraftIssue::new(None, "Invalid report date"));
+        }
+        if self.rows.is_empty() {
+            res.push(DraftIssue::new(None, "The draft has no workers"));
+        }
+        let mut seen = HashSet::new();
+        for row in &self.rows {
+            let id = Some(row.worker_id);
+            if !seen.insert(row.worker_id) {
+                res.push(DraftIssue::new(id, "Worker is listed more than once"));
+            }
+            let worker = match db.get_by_id(row.worker_id) {
+                Some(worker) => worker,
+                None => {
+                    res.push(DraftIssue::new(id, "Worker not found by ID"));
+                    continue;
+                }
+            };
+            if worker.anonymized_at.is_some() {
+                res.push(DraftIssue::new(id, "Worker is anonymized"));
+            }
+            for field in row.overrides.apply(worker).validation_errors() {
+                res.push(DraftIssue::new(id, format!("Invalid field: {}", field)));
+            }
+            if row.overrides.days == Some(0) {
+                res.push(DraftIssue::new(id, "Days must be at least 1"));
+            }
+        }
+        // What is left for the form itself, like categories and lengths
+        if res.is_empty() {
+            if let (Ok(employer), Ok(rows), Ok(date)) = (
+                self.employer(),
+                self.report_rows(db),
+                parse_date(&self.date),
+            ) {
+                let context = RenderContext::new(employer, date);
+                for issue in validate_xml(&render_xml(&context, &rows)) {
+                    // Row i of the form is self.rows[i - 1], as report_rows
+                    // keeps their order and makes exactly one row of each
+                    let id = issue
+                        .row
+                        .and_then(|i| self.rows.get(i - 1))
+                        .map(|r| r.worker_id);
+                    res.push(DraftIssue::new(id, issue.to_string()));
+                }
+            }
+        }
+        res
+    }
+}
+
+// Overrides of the workers are cleared in a stored draft, the file is
+// returned as it is if there were none
+pub(crate) fn redact_draft_file(data: Vec<u8>, ids: &[Uuid]) -> Result<Vec<u8>, String> {
+    let mut draft: ReportDraft =
+        serde_json::from_slice(&storage::unseal(&data)?).map_err(|e| e.to_string())?;
+    let mut changed = false;
+    for row in draft.rows.iter_mut().filter(|r| ids.contains(&r.worker_id)) {
+        let o = &mut row.overrides;
+        changed |= o.name.take().is_some() | o.taj.take().is_some() | o.taxnumber.take().is_some();
+    }
+    if !changed {
+        return Ok(data);
+    }
+    storage::seal(
+        serde_json::to_string(&draft)
+            .map_err(|e| e.to_string())?
+            .as_bytes(),
+    )
+}
+
+pub(crate) fn redact_drafts(ids: &[Uuid]) -> Result<(), String> {
+    for entry in std::fs::read_dir(get_drafts_dir()?).map_err(|e| e.to_string())? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if path.extension().is_some_and(|e| e == "json") {
+            let data = std::fs::read(&path).map_err(|e| e.to_string())?;
+            let redacted = redact_draft_file(data.clone(), ids)?;
+            if redacted != data {
+                std::fs::write(&path, redacted).map_err(|e| e.to_string())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// Most recent date first
+pub(crate) fn list_drafts() -> Result<Vec<ReportDraft>, String> {
+    let mut res = vec![];
+    for entry in std::fs::read_dir(get_drafts_dir()?).map_err(|e| e.to_string())? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if path.extension().is_some_and(|e| e == "json") {
+            let buffer = std::fs::read(&path).map_err(|e| e.to_string())?;
+            res.push(
+                serde_json::from_slice::<ReportDraft>(&storage::unseal(&buffer)?)
+                    .map_err(|e| e.to_string())?,
+            );
+        }
+    }
+    res.sort_by(|a, b| b.date.cmp(&a.date).then(b.updated_at.cmp(&a.updated_at)));
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::setup;
+
+    fn draft(ids: Vec<Uuid>) -> ReportDraft {
+        ReportDraft::new(
+            NaiveDate::from_ymd_opt(2024, 3, 5).unwrap(),
+            Settings::default().default_employer(),
+            ids,
+        )
+    }
+
+    fn messages(issues: &[DraftIssue], id: Option<Uuid>) -> Vec<&str> {
+        issues
+            .iter()
+            .filter(|i| i.worker_id == id)
+            .map(|i| i.message.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn complete_draft_has_no_issues() {
+        let (db, ids) = setup();
+        let mut draft = draft(ids.to_vec());
+        draft.rows[1].overrides = RowOverride {
+            name: Some("Nagy Béla Ferenc".to_string()),
+            category: Some("05".to_string()),
+            days: Some(3),
+            ..RowOverride::default()
+        };
+        assert!(draft.validate(&db).is_empty());
+        let rows = draft.report_rows(&db).unwrap();
+        assert_eq!(rows[0].name, "Kiss Anna");
+        assert_eq!((rows[0].category.as_str(), rows[0].days), ("03", 1));
+        assert_eq!(rows[1].name, "Nagy Béla Ferenc");
+        assert_eq!((rows[1].category.as_str(), rows[1].days), ("05", 3));
+    }
+
+    #[test]
+    fn draft_issues() {
+        let (db, _) = setup();
+        let draft = ReportDraft {
+            date: "március 5.".to_string(),
+            employer: "12345678901".to_string(),
+            ..draft(vec![])
+        };
+        assert_eq!(
+            messages(&draft.validate(&db), None),
+            vec![
+                "Unknown employer",
+                "Invalid report date",
+                "The draft has no workers"
+            ]
+        );
+    }
+
+    #[test]
+    fn row_issues_point_to_the_worker() {
+        let (mut db, ids) = setup();
+        let missing = Uuid::new_v4();
+        let mut draft = draft(vec![ids[0], ids[1], ids[0], missing]);
+        draft.rows[1].overrides = RowOverride {
+            taj: Some("12345".to_string()),
+            days: Some(0),
+            ..RowOverride::default()
+        };
+        let issues = draft.validate(&db);
+        assert_eq!(
+            messages(&issues, Some(ids[0])),
+            vec!["Worker is listed more than once"]
+        );
+        assert_eq!(
+            messages(&issues, Some(ids[1])),
+            vec!["Invalid field: taj", "Days must be at least 1"]
+        );
+        assert_eq!(
+            messages(&issues, Some(missing)),
+            vec!["Worker not found by ID"]
+        );
+        assert!(messages(&issues, None).is_empty());
+        // Anonymized workers can not be reported, even with overrides
+        let anonymized = Worker {
+            anonymized_at: Some(Local::now()),
+            ..db.get_by_id(ids[0]).unwrap().clone()
+        };
+        db.update_worker(anonymized).unwrap();
+        let issues = draft.validate(&db);
+        assert!(messages(&issues, Some(ids[0])).contains(&"Worker is anonymized"));
+    }
+
+    #[test]
+    fn form_issues_point_to_the_row_of_the_worker() {
+        let (db, ids) = setup();
+        let mut draft = draft(ids.to_vec());
+        draft.rows[1].overrides = RowOverride {
+            category: Some("3".to_string()),
+            days: Some(40),
+            ..RowOverride::default()
+        };
+        let issues = draft.validate(&db);
+        assert_eq!(
+            messages(&issues, Some(ids[1])),
+            vec![
+                "0B0002D0007AA: Must be 2 digits",
+                "0B0002D0009AA: Must be a number from 1 to 31"
+            ]
+        );
+        assert_eq!(issues.len(), 2);
+    }
+}
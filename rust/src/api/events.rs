@@ -0,0 +1,224 @@
+use std::sync::Mutex;
+
+use uuid::Uuid;
+
+use crate::frb_generated::StreamSink;
+
+use super::{lock, worker::Worker};
+
+#[derive(Debug, Clone)]
+pub enum DbEvent {
+    WorkerAdded {
+        worker: Worker,
+    },
+    WorkerUpdated {
+        worker: Worker,
+    },
+    WorkerRemoved {
+        id: Uuid,
+    },
+    SelectionChanged {
+        id: Uuid,
+        selected: bool,
+    },
+    // Selection of many workers changed by a single operation
+    SelectionsChanged {
+        selected: Vec<Uuid>,
+        unselected: Vec<Uuid>,
+    },
+    ExportDone {
+        path: String,
+        worker_ids: Vec<Uuid>,
+    },
+    // A worker edited here was changed or deleted by another instance,
+    // local is the overwritten version, followed by WorkerUpdated or WorkerRemoved
+    Conflict {
+        local: Worker,
+        deleted: bool,
+    },
+    // The whole list has to be fetched again
+    Reloaded,
+    // Sent instead of the others while the app is locked, the workers are
+    // fetched again after unlocking
+    Changed {
+        ids: Vec<Uuid>,
+    },
+}
+
+impl DbEvent {
+    fn worker_ids(&self) -> Vec<Uuid> {
+        match self {
+            DbEvent::WorkerAdded { worker } | DbEvent::WorkerUpdated { worker } => vec![worker.id],
+            DbEvent::Conflict { local, .. } => vec![local.id],
+            DbEvent::WorkerRemoved { id } | DbEvent::SelectionChanged { id, .. } => vec![*id],
+            DbEvent::SelectionsChanged {
+                selected,
+                unselected,
+            } => selected.iter().chain(unselected).copied().collect(),
+            DbEvent::ExportDone { worker_ids, .. } => worker_ids.clone(),
+            DbEvent::Changed { ids } => ids.clone(),
+            DbEvent::Reloaded => vec![],
+        }
+    }
+}
+
+static SINKS: Mutex<Vec<StreamSink<DbEvent>>> = Mutex::new(Vec::new());
+
+pub(crate) fn subscribe(sink: StreamSink<DbEvent>) {
+    SINKS.lock().unwrap().push(sink);
+}
+
+// Events emitted on the current test thread
+#[cfg(test)]
+thread_local! {
+    static EMITTED: std::cell::RefCell<Vec<DbEvent>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+#[cfg(test)]
+pub(crate) fn take_emitted() -> Vec<DbEvent> {
+    EMITTED.with(|e| e.take())
+}
+
+// Closed streams are dropped
+pub(crate) fn emit(event: DbEvent) {
+    // No personal data leaves while locked
+    let event = if lock::is_locked() && !matches!(event, DbEvent::Reloaded) {
+        DbEvent::Changed {
+            ids: event.worker_ids(),
+        }
+    } else {
+        event
+    };
+    #[cfg(test)]
+    EMITTED.with(|e| e.borrow_mut().push(event.clone()));
+    SINKS
+        .lock()
+        .unwrap()
+        .retain(|sink| sink.add(event.clone()).is_ok());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{
+        audit::{read_audit_log, AuditAction},
+        worker::{get_data_dir, Db},
+    };
+
+    fn worker(name: &str) -> Worker {
+        Worker {
+            name: name.to_string(),
+            ..Worker::default()
+        }
+    }
+
+    #[test]
+    fn every_change_is_emitted() {
+        let mut db = Db::open().unwrap();
+        let anna = worker("Kiss Anna");
+        db.add_new_worker(anna.clone()).unwrap();
+        let saved = db.get_by_id(anna.id).unwrap().clone();
+        db.update_worker(Worker {
+            city: "Pécs".to_string(),
+            ..saved
+        })
+        .unwrap();
+        let saved = db.get_by_id(anna.id).unwrap().clone();
+        db.remove_worker(saved).unwrap();
+        let events = take_emitted();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(&events[0], DbEvent::WorkerAdded { worker } if worker.id == anna.id));
+        assert!(matches!(&events[1], DbEvent::WorkerUpdated { worker } if worker.city == "Pécs"));
+        assert!(matches!(events[2], DbEvent::WorkerRemoved { id } if id == anna.id));
+    }
+
+    #[test]
+    fn selection_and_exports_are_emitted() {
+        let mut db = Db::open().unwrap();
+        let anna = worker("Kiss Anna");
+        db.add_new_worker(anna.clone()).unwrap();
+        take_emitted();
+        db.set_worker_selected_by_id(anna.id, true).unwrap();
+        db.log_event(AuditAction::Export, vec![anna.id], "/tmp/a.xml")
+            .unwrap();
+        let events = take_emitted();
+        assert!(matches!(
+            events[0],
+            DbEvent::SelectionChanged { id, selected: true } if id == anna.id
+        ));
+        assert!(matches!(
+            events.last().unwrap(),
+            DbEvent::ExportDone { path, worker_ids } if path == "/tmp/a.xml" && worker_ids == &vec![anna.id]
+        ));
+    }
+
+    #[test]
+    fn bulk_selection_is_one_event() {
+        let mut db = Db::open().unwrap();
+        let workers = [
+            worker("Kiss Anna"),
+            worker("Nagy Béla"),
+            worker("Tóth Géza"),
+        ];
+        for w in &workers {
+            db.add_new_worker(w.clone()).unwrap();
+        }
+        db.set_worker_selected_by_id(workers[0].id, true).unwrap();
+        take_emitted();
+        db.set_selection("Invert selection", |w| !w.is_selected)
+            .unwrap();
+        let events = take_emitted();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            DbEvent::SelectionsChanged {
+                selected,
+                unselected,
+            } => {
+                assert_eq!(selected.len(), 2);
+                assert_eq!(unselected, &vec![workers[0].id]);
+            }
+            e => panic!("{:?}", e),
+        }
+        let entries = read_audit_log(None, None, None)
+            .unwrap()
+            .into_iter()
+            .filter(|e| e.action == AuditAction::Select)
+            .collect::<Vec<_>>();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].worker_ids.len(), 3);
+        db.undo().unwrap();
+        assert_eq!(take_emitted().len(), 1);
+    }
+
+    #[test]
+    fn only_ids_are_emitted_while_locked() {
+        let mut db = Db::open().unwrap();
+        let anna = worker("Kiss Anna");
+        db.add_new_worker(anna.clone()).unwrap();
+        std::fs::create_dir_all(get_data_dir().unwrap()).unwrap();
+        lock::set_pin(None, "1234").unwrap();
+        lock::lock().unwrap();
+        take_emitted();
+        let saved = db.get_by_id(anna.id).unwrap().clone();
+        db.update_worker(Worker {
+            city: "Pécs".to_string(),
+            ..saved
+        })
+        .unwrap();
+        db.set_worker_selected_by_id(anna.id, true).unwrap();
+        let events = take_emitted();
+        assert_eq!(events.len(), 2);
+        assert!(events
+            .iter()
+            .all(|e| matches!(e, DbEvent::Changed { ids } if ids == &vec![anna.id])));
+        lock::unlock("1234").unwrap();
+        db.set_worker_selected_by_id(anna.id, false).unwrap();
+        assert!(matches!(
+            take_emitted()[0],
+            DbEvent::SelectionChanged {
+                selected: false,
+                ..
+            }
+        ));
+    }
+}
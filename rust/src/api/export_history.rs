@@ -0,0 +1,386 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, HashMap, HashSet},
+    path::PathBuf,
+};
+
+use chrono::{DateTime, Days, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::xml::{redact_rows, ReportRow};
+
+use super::{
+    audit::{read_audit_log, AuditAction},
+    settings::Employer,
+    storage,
+    worker::{format_date, get_data_dir, index_key, parse_date, Worker},
+};
+
+pub(crate) const EXPORTS_DIR: &str = "exports";
+
+fn get_exports_dir() -> Result<PathBuf, String> {
+    let dir = get_data_dir()?.join(EXPORTS_DIR);
+    if !dir.exists() {
+        std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    }
+    Ok(dir)
+}
+
+pub(crate) fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+// One worker of a report, as it was reported
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReportedWorker {
+    pub worker_id: Uuid,
+    pub category: String,
+    // Starting with the report date
+    pub days: u32,
+}
+
+impl ReportedWorker {
+    pub(crate) fn new(worker_id: Uuid, row: &ReportRow) -> Self {
+        Self {
+            worker_id,
+            category: row.category.to_owned(),
+            days: row.days,
+        }
+    }
+}
+
+// Default category, a single day each
+#[cfg(test)]
+pub(crate) fn one_day_each(ids: Vec<Uuid>) -> Vec<ReportedWorker> {
+    ids.into_iter()
+        .map(|worker_id| ReportedWorker {
+            worker_id,
+            category: crate::xml::DEFAULT_CATEGORY.to_string(),
+            days: 1,
+        })
+        .collect()
+}
+
+// A submitted report, the XML itself is stored next to it
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ExportRecord {
+    pub id: Uuid,
+    pub timestamp: DateTime<Local>,
+    pub operator: String,
+    // As it was at the time of the export
+    pub employer: Employer,
+    // As %Y-%m-%d
+    pub report_date: String,
+    pub worker_ids: Vec<Uuid>,
+    // Empty for records made before these were kept, those are 1 day each
+    #[serde(default)]
+    pub workers: Vec<ReportedWorker>,
+    pub file_name: String,
+    // Where the file was written
+    pub path: String,
+    // SHA-256 of the XML, hex encoded
+    pub sha256: String,
+}
+
+impl ExportRecord {
+    fn record_path(id: Uuid) -> Result<PathBuf, String> {
+        Ok(get_exports_dir()?.join(format!("{}.json", id.as_simple())))
+    }
+    fn xml_path(id: Uuid) -> Result<PathBuf, String> {
+        Ok(get_exports_dir()?.join(format!("{}.xml", id.as_simple())))
+    }
+    // Timestamped when the file was written
+    pub(crate) fn create(
+        operator: &str,
+        employer: &Employer,
+        report_date: NaiveDate,
+        workers: Vec<ReportedWorker>,
+        path: &str,
+        xml: &str,
+        timestamp: DateTime<Local>,
+    ) -> Result<Self, String> {
+        let record = Self {
+            id: Uuid::new_v4(),
+            timestamp,
+            operator: operator.to_owned(),
+            employer: employer.clone(),
+            report_date: format_date(report_date),
+            worker_ids: workers.iter().map(|w| w.worker_id).collect(),
+            workers,
+            file_name: PathBuf::from(path)
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            path: path.to_owned(),
+            sha256: sha256_hex(xml.as_bytes()),
+        };
+        record.store(xml)?;
+        Ok(record)
+    }
+    // Both files are encrypted like the workers
+    fn store(&self, xml: &str) -> Result<(), String> {
+        std::fs::write(Self::xml_path(self.id)?, storage::seal(xml.as_bytes())?)
+            .map_err(|e| e.to_string())?;
+        let data = storage::seal(
+            serde_json::to_string(&self)
+                .map_err(|e| e.to_string())?
+                .as_bytes(),
+        )?;
+        std::fs::write(Self::record_path(self.id)?, data).map_err(|e| e.to_string())
+    }
+    pub(crate) fn load(id: Uuid) -> Result<Self, String> {
+        let path = Self::record_path(id)?;
+        if !path.exists() {
+            return Err("Export not found by ID".into());
+        }
+        let buffer = std::fs::read(&path).map_err(|e| e.to_string())?;
+        serde_json::from_slice(&storage::unseal(&buffer)?).map_err(|e| e.to_string())
+    }
+    // Fails if the stored file is not the one submitted
+    pub(crate) fn xml(&self) -> Result<String, String> {
+        let path = Self::xml_path(self.id)?;
+        if !path.exists() {
+            return Err("Exported XML is missing".into());
+        }
+        let data = storage::unseal(&std::fs::read(&path).map_err(|e| e.to_string())?)?;
+        if sha256_hex(&data) != self.sha256 {
+            return Err("Exported XML does not match its hash".into());
+        }
+        String::from_utf8(data).map_err(|e| e.to_string())
+    }
+    // Used when the rest of an import fails
+    pub(crate) fn delete(&self) -> Result<(), String> {
+        std::fs::remove_file(Self::record_path(self.id)?).map_err(|e| e.to_string())?;
+        std::fs::remove_file(Self::xml_path(self.id)?).map_err(|e| e.to_string())
+    }
+    // Clear the rows of the workers in the stored XML, matched by their TAJ or
+    // tax ID like on import. The hash follows the redacted file.
+    fn redact(&mut self, workers: &[&Worker]) -> Result<(), String> {
+        if !Self::xml_path(self.id)?.exists() {
+            return Ok(());
+        }
+        let xml = self.xml()?;
+        let same = |a: &str, b: &str| index_key(a).is_some_and(|key| index_key(b) == Some(key));
+        let redacted = redact_rows(&xml, |taj, taxnumber| {
+            workers
+                .iter()
+                .any(|w| same(taj, &w.taj) || same(taxnumber, &w.taxnumber))
+        })?;
+        if redacted != xml {
+            self.sha256 = sha256_hex(redacted.as_bytes());
+            self.store(&redacted)?;
+        }
+        Ok(())
+    }
+    // Write the XML again under its original name, returns the path
+    pub(crate) fn resave(&self, to: &str) -> Result<String, String> {
+        let path = PathBuf::from(to).join(&self.file_name);
+        std::fs::write(&path, self.xml()?).map_err(|e| e.to_string())?;
+        Ok(path.to_string_lossy().to_string())
+    }
+}
+
+// Most recent first
+pub(crate) fn list_exports() -> Result<Vec<ExportRecord>, String> {
+    let mut res = vec![];
+    for entry in std::fs::read_dir(get_exports_dir()?).map_err(|e| e.to_string())? {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if path.extension().is_some_and(|e| e == "json") {
+            let buffer = std::fs::read(&path).map_err(|e| e.to_string())?;
+            res.push(
+                serde_json::from_slice::<ExportRecord>(&storage::unseal(&buffer)?)
+                    .map_err(|e| e.to_string())?,
+            );
+        }
+    }
+    res.sort_by_key(|r| Reverse(r.timestamp));
+    Ok(res)
+}
+
+// Personal data of the workers is removed from the stored copy of every
+// report they were part of, given as they were before anonymizing
+pub(crate) fn redact_exports(workers: &[Worker]) -> Result<(), String> {
+    for mut record in list_exports()? {
+        let reported = workers
+            .iter()
+            .filter(|w| record.worker_ids.contains(&w.id))
+            .collect::<Vec<&Worker>>();
+        if !reported.is_empty() {
+            record.redact(&reported)?;
+        }
+    }
+    Ok(())
+}
+
+// An export a worker was part of
+#[derive(Debug, Clone)]
+pub(crate) struct ReportedExport {
+    // None for exports made before the history was kept
+    pub(crate) id: Option<Uuid>,
+    pub(crate) timestamp: DateTime<Local>,
+    pub(crate) report_date: NaiveDate,
+    pub(crate) operator: String,
+    pub(crate) file: String,
+    pub(crate) worker_ids: Vec<Uuid>,
+    pub(crate) workers: Vec<ReportedWorker>,
+}
+
+impl ReportedExport {
+    // Days the worker was reported for, from the report date on
+    pub(crate) fn days(&self, id: Uuid) -> Vec<NaiveDate> {
+        if !self.worker_ids.contains(&id) {
+            return vec![];
+        }
+        let count = self
+            .workers
+            .iter()
+            .filter(|w| w.worker_id == id)
+            .map(|w| w.days)
+            .max()
+            .unwrap_or(1);
+        (0..count.max(1))
+            .filter_map(|i| self.report_date.checked_add_days(Days::new(i as u64)))
+            .collect()
+    }
+}
+
+// Every export, older ones are taken from the audit log by their export time
+pub(crate) fn reported_exports() -> Result<Vec<ReportedExport>, String> {
+    let records = list_exports()?;
+    let paths = records
+        .iter()
+        .map(|r| r.path.as_str())
+        .collect::<HashSet<&str>>();
+    let mut res = read_audit_log(None, None, None)?
+        .into_iter()
+        .filter(|e| e.action == AuditAction::Export && !paths.contains(e.note.as_str()))
+        .map(|e| ReportedExport {
+            id: None,
+            timestamp: e.timestamp,
+            report_date: e.timestamp.date_naive(),
+            operator: e.operator,
+            file: e.note,
+            worker_ids: e.worker_ids,
+            workers: vec![],
+        })
+        .collect::<Vec<ReportedExport>>();
+    for r in records {
+        res.push(ReportedExport {
+            id: Some(r.id),
+            timestamp: r.timestamp,
+            report_date: parse_date(&r.report_date)?,
+            operator: r.operator,
+            file: r.path,
+            worker_ids: r.worker_ids,
+            workers: r.workers,
+        });
+    }
+    res.sort_by_key(|e| e.timestamp);
+    Ok(res)
+}
+
+// Distinct reported days of every worker
+pub(crate) fn reported_days() -> Result<HashMap<Uuid, BTreeSet<NaiveDate>>, String> {
+    let mut res: HashMap<Uuid, BTreeSet<NaiveDate>> = HashMap::new();
+    for export in reported_exports()? {
+        for id in &export.worker_ids {
+            res.entry(*id).or_default().extend(export.days(*id));
+        }
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+    use crate::{api::audit::AuditEntry, test_util::export};
+
+    #[test]
+    fn records_are_stored_with_the_xml() {
+        let now = Local::now();
+        let old = export(
+            vec![],
+            "2024-03-01",
+            "/tmp/x/old.xml",
+            now - Duration::days(1),
+        );
+        let new = export(vec![], "2024-03-02", "/tmp/x/new.xml", now);
+        let loaded = ExportRecord::load(new.id).unwrap();
+        assert_eq!(loaded.file_name, "new.xml");
+        assert_eq!(loaded.report_date, "2024-03-02");
+        assert_eq!(loaded.sha256, sha256_hex(b"<nyomtatvanyok/>"));
+        assert_eq!(loaded.xml().unwrap(), "<nyomtatvanyok/>");
+        assert_eq!(
+            list_exports()
+                .unwrap()
+                .iter()
+                .map(|r| r.id)
+                .collect::<Vec<Uuid>>(),
+            vec![new.id, old.id]
+        );
+        assert!(ExportRecord::load(Uuid::new_v4()).is_err());
+    }
+
+    #[test]
+    fn changed_or_missing_xml_is_not_returned() {
+        let record = export(vec![], "2024-03-01", "/tmp/x/a.xml", Local::now());
+        let path = ExportRecord::xml_path(record.id).unwrap();
+        std::fs::write(
+            &path,
+            storage::seal(b"<nyomtatvanyok></nyomtatvanyok>").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            record.xml(),
+            Err("Exported XML does not match its hash".to_string())
+        );
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(record.xml(), Err("Exported XML is missing".to_string()));
+    }
+
+    #[test]
+    fn resave_keeps_the_file_name() {
+        let record = export(vec![], "2024-03-01", "/elsewhere/a.xml", Local::now());
+        let to = std::env::temp_dir().join(format!("resave-{}", Uuid::new_v4().as_simple()));
+        std::fs::create_dir_all(&to).unwrap();
+        let path = record.resave(&to.to_string_lossy()).unwrap();
+        assert_eq!(PathBuf::from(&path), to.join("a.xml"));
+        assert_eq!(std::fs::read_to_string(path).unwrap(), "<nyomtatvanyok/>");
+    }
+
+    #[test]
+    fn reported_days_come_from_records_and_older_audit_entries() {
+        let (anna, bela) = (Uuid::new_v4(), Uuid::new_v4());
+        // Logged for every export, only counted when there is no record of it
+        AuditEntry::new("op", AuditAction::Export, vec![anna])
+            .with_note("/tmp/x/old.xml")
+            .append()
+            .unwrap();
+        AuditEntry::new("op", AuditAction::Export, vec![anna, bela])
+            .with_note("/tmp/x/a.xml")
+            .append()
+            .unwrap();
+        export(vec![anna, bela], "2024-03-01", "/tmp/x/a.xml", Local::now());
+        export(vec![anna], "2024-03-01", "/tmp/x/b.xml", Local::now());
+        let exports = reported_exports().unwrap();
+        assert_eq!(exports.len(), 3);
+        assert_eq!(exports.iter().filter(|e| e.id.is_none()).count(), 1);
+        let days = reported_days().unwrap();
+        let date = |s: &str| parse_date(s).unwrap();
+        assert_eq!(
+            days[&anna].iter().copied().collect::<Vec<NaiveDate>>(),
+            vec![date("2024-03-01"), Local::now().date_naive()]
+        );
+        assert_eq!(
+            days[&bela].iter().copied().collect::<Vec<NaiveDate>>(),
+            vec![date("2024-03-01")]
+        );
+    }
+}
@@ -0,0 +1,277 @@
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Duration, Local};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{settings::Settings, worker::get_data_dir};
+
+// Exists while an instance is exporting, never part of a backup
+pub(crate) const EXPORT_LOCK_FILE: &str = "export.lock";
+// One file per worker being saved, never part of a backup
+pub(crate) const WORKER_LOCKS_DIR: &str = "locks";
+
+// A crashed instance leaves its lock behind
+const STALE_AFTER_MINUTES: i64 = 10;
+// A save takes milliseconds
+const WORKER_STALE_AFTER_SECONDS: i64 = 30;
+const WORKER_LOCK_ATTEMPTS: u32 = 50;
+const WORKER_LOCK_WAIT_MILLISECONDS: u64 = 20;
+
+fn get_lock_path() -> Result<PathBuf, String> {
+    Ok(get_data_dir()?.join(EXPORT_LOCK_FILE))
+}
+
+fn get_worker_lock_path(id: Uuid) -> Result<PathBuf, String> {
+    let dir = get_data_dir()?.join(WORKER_LOCKS_DIR);
+    if !dir.exists() {
+        std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    }
+    Ok(dir.join(format!("{}.lock", id.as_simple())))
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct LockOwner {
+    // Tells apart two instances taking over the same stale lock
+    id: Uuid,
+    operator: String,
+    pid: u32,
+    created_at: DateTime<Local>,
+}
+
+impl LockOwner {
+    // None if there is no lock, one being written or left broken counts from
+    // its modification time
+    fn read(path: &Path) -> Result<Option<Self>, String> {
+        let data = match std::fs::read_to_string(path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.to_string()),
+        };
+        if let Ok(owner) = serde_json::from_str(&data) {
+            return Ok(Some(owner));
+        }
+        let modified = std::fs::metadata(path)
+            .and_then(|m| m.modified())
+            .map_err(|e| e.to_string())?;
+        Ok(Some(Self {
+            id: Uuid::nil(),
+            operator: String::new(),
+            pid: 0,
+            created_at: modified.into(),
+        }))
+    }
+}
+
+// Creates the lock file, returns the owner if someone else holds it.
+// The content is written next to it first, so it is never seen half written.
+fn create_lock_file(path: &Path, stale_after: Duration) -> Result<Option<LockOwner>, String> {
+    let owner = LockOwner {
+        id: Uuid::new_v4(),
+        operator: Settings::load()?.operator,
+        pid: std::process::id(),
+        created_at: Local::now(),
+    };
+    let tmp_path = path.with_extension(format!("{}.tmp", owner.id.as_simple()));
+    let data = serde_json::to_string(&owner).map_err(|e| e.to_string())?;
+    std::fs::write(&tmp_path, data).map_err(|e| e.to_string())?;
+    let res = place_lock_file(path, &tmp_path, &owner, stale_after);
+    let _ = std::fs::remove_file(&tmp_path);
+    res
+}
+
+fn place_lock_file(
+    path: &Path,
+    tmp_path: &Path,
+    owner: &LockOwner,
+    stale_after: Duration,
+) -> Result<Option<LockOwner>, String> {
+    for _ in 0..2 {
+        // Fails if the lock exists, unlike a rename
+        match std::fs::hard_link(tmp_path, path) {
+            Ok(()) => return Ok(None),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(e.to_string()),
+        }
+        match LockOwner::read(path)? {
+            // Released meanwhile
+            None => continue,
+            Some(current) if Local::now() - current.created_at < stale_after => {
+                return Ok(Some(current));
+            }
+            // Stale, take it over, then make sure no other instance did the
+            // same after us
+            Some(_) => {
+                std::fs::rename(tmp_path, path).map_err(|e| e.to_string())?;
+                return match LockOwner::read(path)? {
+                    Some(current) if current.id == owner.id => Ok(None),
+                    Some(current) => Ok(Some(current)),
+                    None => Err("Could not acquire the lock".into()),
+                };
+            }
+        }
+    }
+    Err("Could not acquire the lock".into())
+}
+
+// Held for the duration of an export, released on drop
+#[derive(Debug)]
+pub(crate) struct ExportLock {
+    path: PathBuf,
+}
+
+impl ExportLock {
+    pub(crate) fn acquire() -> Result<Self, String> {
+        let path = get_lock_path()?;
+        match create_lock_file(&path, Duration::minutes(STALE_AFTER_MINUTES))? {
+            None => Ok(Self { path }),
+            Some(current) => Err(format!(
+                "Export is already running by {} since {}",
+                current.operator,
+                current.created_at.format("%H:%M")
+            )),
+        }
+    }
+}
+
+impl Drop for ExportLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+// Held from reading the revision of a worker until its file is written,
+// so two instances cannot both save over the same revision
+#[derive(Debug)]
+pub(crate) struct WorkerLock {
+    path: PathBuf,
+}
+
+impl WorkerLock {
+    // Waits for the other instance to finish its save
+    pub(crate) fn acquire(id: Uuid) -> Result<Self, String> {
+        let path = get_worker_lock_path(id)?;
+        let stale_after = Duration::seconds(WORKER_STALE_AFTER_SECONDS);
+        let mut owner = None;
+        for _ in 0..WORKER_LOCK_ATTEMPTS {
+            owner = create_lock_file(&path, stale_after)?;
+            match owner {
+                None => return Ok(Self { path }),
+                Some(_) => std::thread::sleep(std::time::Duration::from_millis(
+                    WORKER_LOCK_WAIT_MILLISECONDS,
+                )),
+            }
+        }
+        Err(format!(
+            "Worker is being saved by {}",
+            owner.map(|o| o.operator).unwrap_or_default()
+        ))
+    }
+}
+
+impl Drop for WorkerLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::worker::{Db, UpdateError, Worker};
+
+    #[test]
+    fn one_export_at_a_time() {
+        let lock = ExportLock::acquire().unwrap();
+        let err = ExportLock::acquire().unwrap_err();
+        assert!(err.starts_with("Export is already running by"), "{}", err);
+        drop(lock);
+        ExportLock::acquire().unwrap();
+    }
+
+    #[test]
+    fn stale_locks_are_taken_over() {
+        let owner = LockOwner {
+            id: Uuid::new_v4(),
+            operator: "other".to_string(),
+            pid: 0,
+            created_at: Local::now() - Duration::minutes(STALE_AFTER_MINUTES + 1),
+        };
+        std::fs::write(
+            get_lock_path().unwrap(),
+            serde_json::to_string(&owner).unwrap(),
+        )
+        .unwrap();
+        let _lock = ExportLock::acquire().unwrap();
+        let owner = LockOwner::read(&get_lock_path().unwrap()).unwrap().unwrap();
+        assert_eq!(owner.pid, std::process::id());
+    }
+
+    #[test]
+    fn unreadable_locks_are_held_until_stale() {
+        let id = Uuid::new_v4();
+        let path = get_worker_lock_path(id).unwrap();
+        // Another instance is still writing it
+        std::fs::write(&path, "").unwrap();
+        assert!(WorkerLock::acquire(id).is_err());
+        let old = std::time::SystemTime::now()
+            - std::time::Duration::from_secs(WORKER_STALE_AFTER_SECONDS as u64 + 1);
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(old)
+            .unwrap();
+        let lock = WorkerLock::acquire(id).unwrap();
+        assert!(LockOwner::read(&path)
+            .unwrap()
+            .is_some_and(|o| o.pid == std::process::id()));
+        drop(lock);
+        // No temporary files are left behind
+        assert_eq!(
+            std::fs::read_dir(path.parent().unwrap())
+                .unwrap()
+                .flatten()
+                .filter(|e| e
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with(&id.as_simple().to_string()))
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn worker_lock_is_per_worker() {
+        let id = Uuid::new_v4();
+        let lock = WorkerLock::acquire(id).unwrap();
+        WorkerLock::acquire(Uuid::new_v4()).unwrap();
+        assert!(WorkerLock::acquire(id).is_err());
+        drop(lock);
+        WorkerLock::acquire(id).unwrap();
+    }
+
+    #[test]
+    fn update_waits_for_the_worker_lock() {
+        let mut db = Db::open().unwrap();
+        let anna = Worker {
+            name: "Kiss Anna".to_string(),
+            ..Worker::default()
+        };
+        db.add_new_worker(anna.clone()).unwrap();
+        let saved = db.get_by_id(anna.id).unwrap().clone();
+        let changed = Worker {
+            city: "Pécs".to_string(),
+            ..saved
+        };
+        // Another instance saving it
+        let lock = WorkerLock::acquire(anna.id).unwrap();
+        assert!(matches!(
+            db.update_worker(changed.clone()),
+            Err(UpdateError::Failed(_))
+        ));
+        drop(lock);
+        db.update_worker(changed).unwrap();
+        assert!(!get_worker_lock_path(anna.id).unwrap().exists());
+    }
+}
@@ -0,0 +1,270 @@
+use chrono::{DateTime, Local};
+use uuid::Uuid;
+
+use super::worker::Worker;
+
+// Max number of operations kept in memory
+const HISTORY_LIMIT: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HistoryAction {
+    Add,
+    Update,
+    Remove,
+    Select,
+}
+
+#[derive(Debug, Clone)]
+pub struct HistoryItem {
+    pub id: Uuid,
+    pub action: HistoryAction,
+    pub description: String,
+    pub worker_ids: Vec<Uuid>,
+    pub created_at: DateTime<Local>,
+}
+
+// Worker state before and after an operation.
+// None means the worker did not exist.
+#[derive(Debug, Clone)]
+pub(crate) struct Change {
+    pub(crate) id: Uuid,
+    pub(crate) before: Option<Worker>,
+    pub(crate) after: Option<Worker>,
+}
+
+impl Change {
+    pub(crate) fn name(&self) -> &str {
+        self.after
+            .as_ref()
+            .or(self.before.as_ref())
+            .map(|w| w.name.as_str())
+            .unwrap_or_default()
+    }
+    pub(crate) fn action(&self) -> HistoryAction {
+        match (&self.before, &self.after) {
+            (None, _) => HistoryAction::Add,
+            (_, None) => HistoryAction::Remove,
+            // Only the checkbox changed
+            (Some(before), Some(after))
+                if Worker {
+                    revision: after.revision,
+                    ..before.set_selected(after.is_selected)
+                } == *after =>
+            {
+                HistoryAction::Select
+            }
+            _ => HistoryAction::Update,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Command {
+    pub(crate) item: HistoryItem,
+    pub(crate) changes: Vec<Change>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct History {
+    undo: Vec<Command>,
+    redo: Vec<Command>,
+}
+
+impl History {
+    pub(crate) fn record(
+        &mut self,
+        action: HistoryAction,
+        description: String,
+        changes: Vec<Change>,
+    ) {
+        if changes.is_empty() {
+            return;
+        }
+        let item = HistoryItem {
+            id: Uuid::new_v4(),
+            action,
+            description,
+            worker_ids: changes.iter().map(|c| c.id).collect(),
+            created_at: Local::now(),
+        };
+        self.undo.push(Command { item, changes });
+        if self.undo.len() > HISTORY_LIMIT {
+            self.undo.remove(0);
+        }
+        // New operation invalidates the redo stack
+        self.redo.clear();
+    }
+    pub(crate) fn pop_undo(&mut self) -> Option<Command> {
+        self.undo.pop()
+    }
+    pub(crate) fn pop_redo(&mut self) -> Option<Command> {
+        self.redo.pop()
+    }
+    pub(crate) fn push_undo(&mut self, command: Command) {
+        self.undo.push(command);
+    }
+    pub(crate) fn push_redo(&mut self, command: Command) {
+        self.redo.push(command);
+    }
+    pub(crate) fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+    pub(crate) fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+    // Drop every operation touching the given workers
+    pub(crate) fn forget(&mut self, ids: &[Uuid]) {
+        let touches = |c: &Command| c.changes.iter().any(|change| ids.contains(&change.id));
+        self.undo.retain(|c| !touches(c));
+        self.redo.retain(|c| !touches(c));
+    }
+    pub(crate) fn forget_selection(&mut self) {
+        self.undo.retain(|c| c.item.action != HistoryAction::Select);
+        self.redo.retain(|c| c.item.action != HistoryAction::Select);
+    }
+    // Most recent first
+    pub(crate) fn items(&self, limit: usize) -> Vec<HistoryItem> {
+        self.undo
+            .iter()
+            .rev()
+            .take(limit)
+            .map(|c| c.item.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn worker(name: &str) -> Worker {
+        Worker {
+            name: name.to_string(),
+            ..Worker::default()
+        }
+    }
+
+    fn change(before: Option<&Worker>, after: Option<&Worker>) -> Change {
+        Change {
+            id: before.or(after).unwrap().id,
+            before: before.cloned(),
+            after: after.cloned(),
+        }
+    }
+
+    fn record(history: &mut History, worker: &Worker) {
+        history.record(
+            HistoryAction::Add,
+            format!("Add {}", worker.name),
+            vec![change(None, Some(worker))],
+        );
+    }
+
+    #[test]
+    fn change_action_follows_the_states() {
+        let before = worker("Kiss Anna");
+        let renamed = Worker {
+            name: "Kiss Anna Mária".to_string(),
+            ..before.clone()
+        };
+        assert_eq!(change(None, Some(&before)).action(), HistoryAction::Add);
+        assert_eq!(change(Some(&before), None).action(), HistoryAction::Remove);
+        assert_eq!(
+            change(Some(&before), Some(&renamed)).action(),
+            HistoryAction::Update
+        );
+        // A new revision alone does not make a selection an update
+        let selected = Worker {
+            revision: before.revision + 1,
+            ..before.set_selected(true)
+        };
+        assert_eq!(
+            change(Some(&before), Some(&selected)).action(),
+            HistoryAction::Select
+        );
+    }
+
+    #[test]
+    fn undo_and_redo_move_between_the_stacks() {
+        let mut history = History::default();
+        assert!(!history.can_undo() && !history.can_redo());
+        record(&mut history, &worker("Kiss Anna"));
+        let command = history.pop_undo().unwrap();
+        assert_eq!(command.item.description, "Add Kiss Anna");
+        history.push_redo(command);
+        assert!(!history.can_undo() && history.can_redo());
+        let command = history.pop_redo().unwrap();
+        history.push_undo(command);
+        assert!(history.can_undo() && !history.can_redo());
+    }
+
+    #[test]
+    fn new_operation_clears_redo() {
+        let mut history = History::default();
+        record(&mut history, &worker("Kiss Anna"));
+        let command = history.pop_undo().unwrap();
+        history.push_redo(command);
+        record(&mut history, &worker("Nagy Béla"));
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn empty_operation_is_not_recorded() {
+        let mut history = History::default();
+        history.record(HistoryAction::Select, "Select none".to_string(), vec![]);
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn forget_drops_operations_of_the_workers() {
+        let mut history = History::default();
+        let anna = worker("Kiss Anna");
+        let bela = worker("Nagy Béla");
+        record(&mut history, &anna);
+        record(&mut history, &bela);
+        history.record(
+            HistoryAction::Select,
+            "Select all".to_string(),
+            vec![
+                change(Some(&anna), Some(&anna.set_selected(true))),
+                change(Some(&bela), Some(&bela.set_selected(true))),
+            ],
+        );
+        history.forget(&[anna.id]);
+        let items = history.items(10);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].worker_ids, vec![bela.id]);
+    }
+
+    #[test]
+    fn forget_selection_keeps_edits() {
+        let mut history = History::default();
+        let anna = worker("Kiss Anna");
+        record(&mut history, &anna);
+        history.record(
+            HistoryAction::Select,
+            "Select Kiss Anna".to_string(),
+            vec![change(Some(&anna), Some(&anna.set_selected(true)))],
+        );
+        history.forget_selection();
+        let items = history.items(10);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].action, HistoryAction::Add);
+    }
+
+    #[test]
+    fn keeps_the_last_operations_only() {
+        let mut history = History::default();
+        for i in 0..HISTORY_LIMIT + 5 {
+            record(&mut history, &worker(&format!("Worker {}", i)));
+        }
+        let items = history.items(usize::MAX);
+        assert_eq!(items.len(), HISTORY_LIMIT);
+        assert_eq!(
+            items[0].description,
+            format!("Add Worker {}", HISTORY_LIMIT + 4)
+        );
+        assert_eq!(items[HISTORY_LIMIT - 1].description, "Add Worker 5");
+        assert_eq!(history.items(3).len(), 3);
+    }
+}
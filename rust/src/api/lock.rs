@@ -0,0 +1,303 @@
+use std::{
+    path::PathBuf,
+    sync::RwLock,
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::crypto::{hash_password, verify_password};
+
+use super::{settings::Settings, worker::get_data_dir};
+
+// Kept outside of snapshots and backup files, like the encryption info
+pub(crate) const PIN_FILE: &str = "pin.json";
+
+// Wrong PINs allowed before every further try has to wait
+const FREE_ATTEMPTS: u32 = 3;
+const FIRST_DELAY_SECONDS: i64 = 30;
+const MAX_DELAY_SECONDS: i64 = 15 * 60;
+
+struct LockState {
+    locked: bool,
+    last_activity: Option<Instant>,
+    auto_lock: Option<Duration>,
+}
+
+impl LockState {
+    const fn new() -> Self {
+        LockState {
+            locked: false,
+            last_activity: None,
+            auto_lock: None,
+        }
+    }
+    fn expired(&self, now: Instant) -> bool {
+        match (self.last_activity, self.auto_lock) {
+            (Some(last), Some(auto_lock)) => now.duration_since(last) >= auto_lock,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(not(test))]
+static STATE: RwLock<LockState> = RwLock::new(LockState::new());
+
+#[cfg(not(test))]
+fn with_state<R>(f: impl FnOnce(&mut LockState) -> R) -> R {
+    f(&mut STATE.write().unwrap())
+}
+
+// Tests run in parallel, each with its own lock
+#[cfg(test)]
+thread_local! {
+    static STATE: RwLock<LockState> = const { RwLock::new(LockState::new()) };
+}
+
+#[cfg(test)]
+fn with_state<R>(f: impl FnOnce(&mut LockState) -> R) -> R {
+    STATE.with(|s| f(&mut s.write().unwrap()))
+}
+
+fn get_pin_path() -> Result<PathBuf, String> {
+    Ok(get_data_dir()?.join(PIN_FILE))
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct PinConfig {
+    // Argon2 PHC string
+    hash: String,
+    // Kept in the file, so restarting the app does not reset the wait
+    #[serde(default)]
+    failed_attempts: u32,
+    #[serde(default)]
+    retry_after: Option<DateTime<Local>>,
+}
+
+impl PinConfig {
+    fn new(pin: &str) -> Result<Self, String> {
+        Ok(PinConfig {
+            hash: hash_password(pin)?,
+            failed_attempts: 0,
+            retry_after: None,
+        })
+    }
+    fn load() -> Result<Option<Self>, String> {
+        let path = get_pin_path()?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        let buffer = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        serde_json::from_str(&buffer)
+            .map(Some)
+            .map_err(|e| e.to_string())
+    }
+    fn save(&self) -> Result<(), String> {
+        let buffer = serde_json::to_string(&self).map_err(|e| e.to_string())?;
+        std::fs::write(get_pin_path()?, buffer).map_err(|e| e.to_string())
+    }
+    // Every wrong PIN past the free ones doubles the wait
+    fn verify(&mut self, pin: &str) -> Result<(), String> {
+        let now = Local::now();
+        if let Some(retry_after) = self.retry_after.filter(|t| *t > now) {
+            let seconds = (retry_after - now).num_seconds() + 1;
+            return Err(format!(
+                "Too many wrong PINs, try again in {seconds} seconds"
+            ));
+        }
+        if verify_password(pin, &self.hash) {
+            if self.failed_attempts > 0 {
+                self.failed_attempts = 0;
+                self.retry_after = None;
+                self.save()?;
+            }
+            return Ok(());
+        }
+        self.failed_attempts += 1;
+        if self.failed_attempts > FREE_ATTEMPTS {
+            let exponent = (self.failed_attempts - FREE_ATTEMPTS - 1).min(16);
+            let seconds = (FIRST_DELAY_SECONDS << exponent).min(MAX_DELAY_SECONDS);
+            self.retry_after = Some(now + chrono::Duration::seconds(seconds));
+        }
+        self.save()?;
+        Err("Wrong PIN".into())
+    }
+}
+
+// Called on startup, the app starts locked when a PIN is set
+pub(crate) fn init() -> Result<(), String> {
+    let locked = has_pin()?;
+    let auto_lock = auto_lock_duration(Settings::load()?.auto_lock_minutes);
+    with_state(|state| {
+        state.locked = locked;
+        state.auto_lock = auto_lock;
+    });
+    Ok(())
+}
+
+pub(crate) fn has_pin() -> Result<bool, String> {
+    Ok(get_pin_path()?.exists())
+}
+
+// Guard for every call touching worker data
+pub(crate) fn check() -> Result<(), String> {
+    let has_pin = has_pin()?;
+    with_state(|state| {
+        let now = Instant::now();
+        if has_pin && state.expired(now) {
+            state.locked = true;
+        }
+        if state.locked {
+            return Err("Locked".into());
+        }
+        state.last_activity = Some(now);
+        Ok(())
+    })
+}
+
+// Only looks, polling it does not count as activity
+pub(crate) fn is_locked() -> bool {
+    let has_pin = has_pin().unwrap_or(false);
+    with_state(|state| state.locked || (has_pin && state.expired(Instant::now())))
+}
+
+pub(crate) fn lock() -> Result<(), String> {
+    if !has_pin()? {
+        return Err("PIN is not set".into());
+    }
+    with_state(|state| state.locked = true);
+    Ok(())
+}
+
+pub(crate) fn unlock(pin: &str) -> Result<(), String> {
+    if let Some(mut config) = PinConfig::load()? {
+        config.verify(pin)?;
+    }
+    with_state(|state| {
+        state.locked = false;
+        state.last_activity = Some(Instant::now());
+    });
+    Ok(())
+}
+
+// The current PIN is required to change an existing one
+pub(crate) fn set_pin(current_pin: Option<String>, new_pin: &str) -> Result<(), String> {
+    if let Some(mut config) = PinConfig::load()? {
+        config.verify(&current_pin.unwrap_or_default())?;
+    }
+    if new_pin.len() < 4 {
+        return Err("PIN must be at least 4 characters".into());
+    }
+    PinConfig::new(new_pin)?.save()
+}
+
+pub(crate) fn remove_pin(pin: &str) -> Result<(), String> {
+    if let Some(mut config) = PinConfig::load()? {
+        config.verify(pin)?;
+        std::fs::remove_file(get_pin_path()?).map_err(|e| e.to_string())?;
+    }
+    with_state(|state| state.locked = false);
+    Ok(())
+}
+
+pub(crate) fn set_auto_lock(minutes: u32) {
+    with_state(|state| state.auto_lock = auto_lock_duration(minutes));
+}
+
+// 0 turns auto lock off
+fn auto_lock_duration(minutes: u32) -> Option<Duration> {
+    match minutes {
+        0 => None,
+        m => Some(Duration::from_secs(m as u64 * 60)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup() {
+        std::fs::create_dir_all(get_data_dir().unwrap()).unwrap();
+        set_pin(None, "1234").unwrap();
+        init().unwrap();
+    }
+
+    #[test]
+    fn starts_locked_with_a_pin() {
+        setup();
+        assert!(is_locked());
+        assert!(check().is_err());
+        assert!(unlock("0000").is_err());
+        unlock("1234").unwrap();
+        assert!(!is_locked());
+        check().unwrap();
+    }
+
+    #[test]
+    fn polling_does_not_keep_it_unlocked() {
+        setup();
+        unlock("1234").unwrap();
+        with_state(|state| {
+            state.auto_lock = Some(Duration::from_millis(50));
+        });
+        for _ in 0..5 {
+            std::thread::sleep(Duration::from_millis(20));
+            is_locked();
+        }
+        assert!(is_locked());
+        assert!(check().is_err());
+    }
+
+    #[test]
+    fn activity_keeps_it_unlocked() {
+        setup();
+        unlock("1234").unwrap();
+        set_auto_lock(1);
+        check().unwrap();
+        assert!(!is_locked());
+        set_auto_lock(0);
+        with_state(|state| state.last_activity = Some(Instant::now() - Duration::from_secs(3600)));
+        check().unwrap();
+    }
+
+    #[test]
+    fn wrong_pins_have_to_wait() {
+        setup();
+        for _ in 0..FREE_ATTEMPTS {
+            assert_eq!(unlock("0000"), Err("Wrong PIN".into()));
+        }
+        assert_eq!(unlock("0000"), Err("Wrong PIN".into()));
+        // Even the right PIN waits, and so do the PIN changes
+        assert!(unlock("1234").unwrap_err().starts_with("Too many"));
+        assert!(set_pin(Some("1234".into()), "5678")
+            .unwrap_err()
+            .starts_with("Too many"));
+        assert!(remove_pin("1234").unwrap_err().starts_with("Too many"));
+        let config = PinConfig::load().unwrap().unwrap();
+        assert_eq!(config.failed_attempts, FREE_ATTEMPTS + 1);
+
+        let mut config = config;
+        config.retry_after = Some(Local::now() - chrono::Duration::seconds(1));
+        config.save().unwrap();
+        unlock("1234").unwrap();
+        let config = PinConfig::load().unwrap().unwrap();
+        assert_eq!(config.failed_attempts, 0);
+        assert!(config.retry_after.is_none());
+    }
+
+    #[test]
+    fn wait_grows_with_every_wrong_pin() {
+        setup();
+        let mut config = PinConfig::load().unwrap().unwrap();
+        let mut waits = vec![];
+        for _ in 0..FREE_ATTEMPTS + 3 {
+            config.retry_after = None;
+            // Hashing may take a while, so it is measured from before the try
+            let before = Local::now();
+            assert!(config.verify("0000").is_err());
+            waits.push(config.retry_after.map(|t| (t - before).num_seconds()));
+        }
+        assert_eq!(waits, vec![None, None, None, Some(30), Some(60), Some(120)]);
+    }
+}
@@ -2,5 +2,23 @@
 // Do not put code in `mod.rs`, but put in e.g. `simple.rs`.
 //
 
+pub mod audit;
+pub mod backup;
+pub mod crew;
+pub mod draft;
+pub mod events;
+pub mod export_history;
+pub mod export_lock;
+pub mod history;
+pub mod lock;
+pub mod privacy;
+pub mod query;
+pub mod roster_export;
+pub mod session;
+pub mod settings;
 pub mod simple;
+pub mod spreadsheet_import;
+pub mod storage;
+pub mod watcher;
 pub mod worker;
+pub mod xml_import;
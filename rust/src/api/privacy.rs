@@ -0,0 +1,337 @@
+use std::path::Path;
+
+use chrono::{DateTime, Local, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::text::collation_key;
+
+use super::{
+    audit::{read_audit_log, AuditAction, AuditEntry},
+    export_history::{reported_exports, ReportedExport},
+    worker::{format_date, Worker},
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ExportAppearance {
+    // Export history entry, None for exports older than the history
+    pub export_id: Option<Uuid>,
+    pub timestamp: DateTime<Local>,
+    // As %Y-%m-%d
+    pub report_date: String,
+    pub operator: String,
+    pub file: String,
+}
+
+// Everything stored about a single worker
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SubjectReport {
+    pub generated_at: DateTime<Local>,
+    pub worker: Worker,
+    // As %Y-%m-%d, in order
+    pub reported_days: Vec<String>,
+    pub exports: Vec<ExportAppearance>,
+    pub audit_entries: Vec<AuditEntry>,
+}
+
+impl SubjectReport {
+    pub(crate) fn new(worker: &Worker) -> Result<Self, String> {
+        let audit_entries = read_audit_log(Some(worker.id), None, None)?;
+        let exported = reported_exports()?
+            .into_iter()
+            .filter(|e| e.worker_ids.contains(&worker.id))
+            .collect::<Vec<ReportedExport>>();
+        let mut reported_days = exported
+            .iter()
+            .flat_map(|e| e.days(worker.id))
+            .collect::<Vec<NaiveDate>>();
+        reported_days.sort();
+        reported_days.dedup();
+        let reported_days = reported_days.into_iter().map(format_date).collect();
+        let exports = exported
+            .into_iter()
+            .map(|e| ExportAppearance {
+                export_id: e.id,
+                timestamp: e.timestamp,
+                report_date: format_date(e.report_date),
+                operator: e.operator,
+                file: e.file,
+            })
+            .collect::<Vec<ExportAppearance>>();
+        Ok(Self {
+            generated_at: Local::now(),
+            worker: worker.clone(),
+            reported_days,
+            exports,
+            audit_entries,
+        })
+    }
+    // Human readable form, given to the worker
+    pub(crate) fn to_text(&self) -> String {
+        let w = &self.worker;
+        let mut res = vec![
+            "Tájékoztató a nyilvántartott személyes adatokról".to_string(),
+            format!("Készült: {}", self.generated_at.format("%Y-%m-%d %H:%M")),
+            String::new(),
+            "Személyes adatok".to_string(),
+            format!("  Név: {}", w.name),
+            format!("  TAJ szám: {}", w.taj),
+            format!("  Adóazonosító jel: {}", w.taxnumber),
+            format!("  Anyja neve: {}", w.mothersname),
+            format!("  Születési idő: {}", w.birthdate),
+            format!("  Születési hely: {}", w.birthplace),
+            format!("  Lakcím: {} {}, {}", w.zip, w.city, w.street),
+            String::new(),
+            format!("Bejelentett munkanapok ({})", self.reported_days.len()),
+        ];
+        res.extend(self.reported_days.iter().map(|d| format!("  {}", d)));
+        res.push(String::new());
+        res.push(format!("Bejelentések ({})", self.exports.len()));
+        res.extend(self.exports.iter().map(|e| {
+            format!(
+                "  {} - {} ({})",
+                e.timestamp.format("%Y-%m-%d %H:%M"),
+                e.file,
+                e.operator
+            )
+        }));
+        res.push(String::new());
+        res.push(format!("Adatkezelési napló ({})", self.audit_entries.len()));
+        for entry in &self.audit_entries {
+            res.push(format!(
+                "  {} {:?} ({})",
+                entry.timestamp.format("%Y-%m-%d %H:%M"),
+                entry.action,
+                entry.operator
+            ));
+            res.extend(
+                entry
+                    .changes
+                    .iter()
+                    .map(|c| format!("    {}: {} -> {}", c.field, c.before, c.after)),
+            );
+        }
+        res.join("\n")
+    }
+    // JSON and text files into the given folder
+    pub(crate) fn write(&self, to: &str) -> Result<Vec<String>, String> {
+        let base = format!(
+            "adatkiadas_{}_{}",
+            self.worker.id.as_simple(),
+            self.generated_at.format("%Y%m%d")
+        );
+        let json_path = Path::new(to).join(format!("{}.json", base));
+        let text_path = Path::new(to).join(format!("{}.txt", base));
+        let json = serde_json::to_string_pretty(&self).map_err(|e| e.to_string())?;
+        std::fs::write(&json_path, json).map_err(|e| e.to_string())?;
+        std::fs::write(&text_path, self.to_text()).map_err(|e| e.to_string())?;
+        Ok(vec![
+            json_path.to_string_lossy().to_string(),
+            text_path.to_string_lossy().to_string(),
+        ])
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RetentionCandidate {
+    pub id: Uuid,
+    pub name: String,
+    pub last_activity: Option<DateTime<Local>>,
+    pub reported_days: u32,
+    // Stored export XMLs with the worker's data, anonymization clears their rows
+    pub kept_exports: u32,
+}
+
+// Workers without activity in the last given years
+pub(crate) fn retention_candidates<'a>(
+    workers: impl Iterator<Item = &'a Worker>,
+    years: u32,
+) -> Result<Vec<RetentionCandidate>, String> {
+    if years == 0 {
+        return Ok(vec![]);
+    }
+    let limit = Local::now()
+        .checked_sub_months(Months::new(years * 12))
+        .ok_or("Invalid retention period")?;
+    let audit = read_audit_log(None, None, None)?;
+    let exports = reported_exports()?;
+    let mut res = vec![];
+    for worker in workers.filter(|w| w.anonymized_at.is_none()) {
+        let exported = exports
+            .iter()
+            .filter(|e| e.worker_ids.contains(&worker.id))
+            .collect::<Vec<&ReportedExport>>();
+        // Selection alone is not an activity. Workers without any recorded
+        // activity are never candidates, nothing tells how old their data is.
+        let last_activity = audit
+            .iter()
+            .filter(|e| {
+                e.worker_ids.contains(&worker.id)
+                    && matches!(
+                        e.action,
+                        AuditAction::Add | AuditAction::Update | AuditAction::Export
+                    )
+            })
+            .map(|e| e.timestamp)
+            .chain(exported.iter().map(|e| e.timestamp))
+            .max();
+        match last_activity {
+            Some(last) if last < limit => {}
+            _ => continue,
+        }
+        let mut reported_days = exported
+            .iter()
+            .flat_map(|e| e.days(worker.id))
+            .collect::<Vec<NaiveDate>>();
+        reported_days.sort();
+        reported_days.dedup();
+        res.push(RetentionCandidate {
+            id: worker.id,
+            name: worker.name.to_owned(),
+            last_activity,
+            reported_days: reported_days.len() as u32,
+            kept_exports: exported.iter().filter(|e| e.id.is_some()).count() as u32,
+        });
+    }
+    res.sort_by_cached_key(|c| collation_key(&c.name));
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::export_history::{one_day_each, ExportRecord},
+        test_util::employer,
+    };
+
+    fn worker() -> Worker {
+        Worker {
+            name: "Kiss Anna".to_string(),
+            taj: "123456788".to_string(),
+            ..Worker::default()
+        }
+    }
+
+    fn export(ids: Vec<Uuid>, path: &str) {
+        AuditEntry::new("op", AuditAction::Export, ids)
+            .with_note(path)
+            .append()
+            .unwrap();
+    }
+
+    #[test]
+    fn report_has_only_the_workers_data() {
+        let anna = worker();
+        let bela = Worker::default();
+        AuditEntry::new("op", AuditAction::Add, vec![anna.id])
+            .with_changes(None, Some(&anna))
+            .append()
+            .unwrap();
+        AuditEntry::new("op", AuditAction::Add, vec![bela.id])
+            .append()
+            .unwrap();
+        export(vec![anna.id, bela.id], "/tmp/a.xml");
+        export(vec![anna.id], "/tmp/b.xml");
+        export(vec![bela.id], "/tmp/c.xml");
+        let report = SubjectReport::new(&anna).unwrap();
+        assert_eq!(report.worker.id, anna.id);
+        assert_eq!(report.exports.len(), 2);
+        assert_eq!(report.exports[0].file, "/tmp/a.xml");
+        assert_eq!(
+            report.reported_days,
+            vec![format_date(Local::now().date_naive())]
+        );
+        assert_eq!(report.audit_entries.len(), 3);
+        assert_eq!(report.audit_entries[0].action, AuditAction::Add);
+    }
+
+    #[test]
+    fn text_and_json_are_written() {
+        let anna = worker();
+        export(vec![anna.id], "/tmp/a.xml");
+        let report = SubjectReport::new(&anna).unwrap();
+        let text = report.to_text();
+        assert!(text.contains("Név: Kiss Anna"));
+        assert!(text.contains("TAJ szám: 123456788"));
+        assert!(text.contains("Bejelentések (1)"));
+        let to = std::env::temp_dir().join(format!("subject-{}", Uuid::new_v4().as_simple()));
+        std::fs::create_dir_all(&to).unwrap();
+        let files = report.write(&to.to_string_lossy()).unwrap();
+        assert_eq!(files.len(), 2);
+        let json = std::fs::read_to_string(&files[0]).unwrap();
+        let read: SubjectReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(read.worker.taj, "123456788");
+        assert_eq!(read.reported_days, report.reported_days);
+        assert_eq!(std::fs::read_to_string(&files[1]).unwrap(), text);
+        std::fs::remove_dir_all(to).unwrap();
+    }
+
+    fn log(worker: &Worker, action: AuditAction, days_ago: i64) {
+        let mut entry = AuditEntry::new("op", action, vec![worker.id]);
+        entry.timestamp = Local::now() - chrono::Duration::days(days_ago);
+        entry.append().unwrap();
+    }
+
+    #[test]
+    fn retention_goes_by_the_last_activity() {
+        let old = worker();
+        let recent = Worker {
+            name: "Nagy Béla".to_string(),
+            ..Worker::default()
+        };
+        let selected_only = Worker {
+            name: "Tóth Géza".to_string(),
+            ..Worker::default()
+        };
+        log(&old, AuditAction::Add, 3 * 365 + 10);
+        log(&old, AuditAction::Select, 10);
+        log(&recent, AuditAction::Add, 3 * 365 + 10);
+        log(&recent, AuditAction::Update, 10);
+        log(&selected_only, AuditAction::Select, 3 * 365 + 10);
+        let workers = [old.clone(), recent, selected_only];
+        let candidates = retention_candidates(workers.iter(), 2).unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].id, old.id);
+        assert!(retention_candidates(workers.iter(), 0).unwrap().is_empty());
+        assert!(retention_candidates(workers.iter(), 5).unwrap().is_empty());
+    }
+
+    #[test]
+    fn recent_exports_keep_the_worker() {
+        let anna = worker();
+        log(&anna, AuditAction::Add, 3 * 365 + 10);
+        assert_eq!(
+            retention_candidates([&anna].into_iter(), 2).unwrap().len(),
+            1
+        );
+        export(vec![anna.id], "/tmp/a.xml");
+        assert!(retention_candidates([&anna].into_iter(), 2)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn kept_exports_are_counted() {
+        let anna = worker();
+        let days_ago = Local::now() - chrono::Duration::days(3 * 365 + 10);
+        let date = days_ago.date_naive();
+        for path in ["/tmp/a.xml", "/tmp/b.xml"] {
+            ExportRecord::create(
+                "op",
+                &employer(),
+                date,
+                one_day_each(vec![anna.id]),
+                path,
+                "<xml/>",
+                days_ago,
+            )
+            .unwrap();
+        }
+        log(&anna, AuditAction::Add, 3 * 365 + 20);
+        let candidates = retention_candidates([&anna].into_iter(), 2).unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].reported_days, 1);
+        assert_eq!(candidates[0].kept_exports, 2);
+    }
+}
@@ -0,0 +1,450 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::text::{collate, fold, matches_all};
+
+use super::{
+    export_history::reported_days,
+    worker::{parse_date, Worker},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WorkerSortKey {
+    Name,
+    City,
+    BirthDate,
+    Selected,
+    LastWorked,
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkerSort {
+    pub key: WorkerSortKey,
+    pub descending: bool,
+}
+
+// Empty fields do not filter
+#[derive(Debug, Clone, Default)]
+pub struct WorkerQuery {
+    // Accent insensitive, every word has to match name, city, TAJ or tax ID
+    pub text: Option<String>,
+    pub selected: Option<bool>,
+    pub city: Option<String>,
+    pub has_validation_errors: Option<bool>,
+    // Workers having all of these tags
+    pub tags: Vec<String>,
+    // Last reported day, as %Y-%m-%d
+    pub last_worked_from: Option<String>,
+    pub last_worked_to: Option<String>,
+    // By name if empty
+    pub sort: Vec<WorkerSort>,
+}
+
+impl WorkerQuery {
+    fn uses_last_worked(&self) -> bool {
+        self.last_worked_from.is_some()
+            || self.last_worked_to.is_some()
+            || self.sort.iter().any(|s| s.key == WorkerSortKey::LastWorked)
+    }
+    fn last_worked_range(&self) -> Result<(Option<NaiveDate>, Option<NaiveDate>), String> {
+        Ok((
+            self.last_worked_from
+                .as_deref()
+                .map(parse_date)
+                .transpose()?,
+            self.last_worked_to.as_deref().map(parse_date).transpose()?,
+        ))
+    }
+    fn matches(
+        &self,
+        worker: &Worker,
+        last_worked: Option<NaiveDate>,
+        (from, to): (Option<NaiveDate>, Option<NaiveDate>),
+    ) -> bool {
+        if let Some(text) = &self.text {
+            let fields = [
+                worker.name.as_str(),
+                &worker.city,
+                &worker.taj,
+                &worker.taxnumber,
+            ];
+            if !matches_all(text, &fields) {
+                return false;
+            }
+        }
+        if self.selected.is_some_and(|s| s != worker.is_selected) {
+            return false;
+        }
+        if let Some(city) = &self.city {
+            if fold(city.trim()) != fold(worker.city.trim()) {
+                return false;
+            }
+        }
+        if let Some(has_errors) = self.has_validation_errors {
+            if worker.validation_errors().is_empty() == has_errors {
+                return false;
+            }
+        }
+        if !self.tags.iter().all(|t| worker.tags.contains(t)) {
+            return false;
+        }
+        if from.is_some() || to.is_some() {
+            match last_worked {
+                Some(day) => {
+                    if from.is_some_and(|from| day < from) || to.is_some_and(|to| day > to) {
+                        return false;
+                    }
+                }
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+// Last reported day of every worker
+pub(crate) fn last_worked_days() -> Result<HashMap<Uuid, NaiveDate>, String> {
+    Ok(reported_days()?
+        .into_iter()
+        .filter_map(|(id, days)| days.last().map(|day| (id, *day)))
+        .collect())
+}
+
+fn compare(
+    sort: &WorkerSort,
+    a: &Worker,
+    b: &Worker,
+    last_worked: &HashMap<Uuid, NaiveDate>,
+) -> Ordering {
+    let res = match sort.key {
+        WorkerSortKey::Name => collate(&a.name, &b.name),
+        WorkerSortKey::City => collate(&a.city, &b.city),
+        WorkerSortKey::BirthDate => a.birthdate.cmp(&b.birthdate),
+        WorkerSortKey::Selected => a.is_selected.cmp(&b.is_selected),
+        WorkerSortKey::LastWorked => last_worked.get(&a.id).cmp(&last_worked.get(&b.id)),
+    };
+    if sort.descending {
+        res.reverse()
+    } else {
+        res
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkerPage {
+    pub workers: Vec<Worker>,
+    // Number of all matching workers
+    pub total: u32,
+    pub offset: u32,
+}
+
+// Matching workers in order, without cloning
+fn matching<'a>(
+    workers: impl Iterator<Item = &'a Worker>,
+    query: &WorkerQuery,
+) -> Result<Vec<&'a Worker>, String> {
+    // The export history is only read when needed
+    let last_worked = if query.uses_last_worked() {
+        last_worked_days()?
+    } else {
+        HashMap::new()
+    };
+    let range = query.last_worked_range()?;
+    let mut res = workers
+        .filter(|w| query.matches(w, last_worked.get(&w.id).copied(), range))
+        .collect::<Vec<&Worker>>();
+    let by_name = [WorkerSort {
+        key: WorkerSortKey::Name,
+        descending: false,
+    }];
+    let sort = if query.sort.is_empty() {
+        &by_name[..]
+    } else {
+        &query.sort[..]
+    };
+    res.sort_by(|a, b| {
+        sort.iter()
+            .map(|s| compare(s, a, b, &last_worked))
+            .find(|o| o.is_ne())
+            // Stable order for paging
+            .unwrap_or_else(|| a.id.cmp(&b.id))
+    });
+    Ok(res)
+}
+
+pub(crate) fn query_workers<'a>(
+    workers: impl Iterator<Item = &'a Worker>,
+    query: &WorkerQuery,
+) -> Result<Vec<Worker>, String> {
+    Ok(matching(workers, query)?.into_iter().cloned().collect())
+}
+
+pub(crate) fn query_page<'a>(
+    workers: impl Iterator<Item = &'a Worker>,
+    query: &WorkerQuery,
+    offset: u32,
+    limit: u32,
+) -> Result<WorkerPage, String> {
+    let res = matching(workers, query)?;
+    Ok(WorkerPage {
+        total: res.len() as u32,
+        workers: res
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .cloned()
+            .collect(),
+        offset,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::audit::{AuditAction, AuditEntry};
+    use chrono::{Local, TimeZone};
+
+    fn worker(name: &str, city: &str) -> Worker {
+        Worker {
+            name: name.to_string(),
+            city: city.to_string(),
+            ..Worker::default()
+        }
+    }
+
+    fn names(workers: &[Worker]) -> Vec<&str> {
+        workers.iter().map(|w| w.name.as_str()).collect()
+    }
+
+    fn run(workers: &[Worker], query: WorkerQuery) -> Vec<Worker> {
+        query_workers(workers.iter(), &query).unwrap()
+    }
+
+    fn roster() -> Vec<Worker> {
+        vec![
+            Worker {
+                taj: "123456788".to_string(),
+                tags: vec!["kertész".to_string()],
+                ..worker("Zoltán Ödön", "Szeged")
+            },
+            Worker {
+                is_selected: true,
+                tags: vec!["kertész".to_string(), "sofőr".to_string()],
+                ..worker("Ádám Éva", "Pécs")
+            },
+            Worker {
+                taxnumber: "8123456789".to_string(),
+                ..worker("Nagy Béla", "szeged ")
+            },
+        ]
+    }
+
+    #[test]
+    fn sorted_by_name_by_default() {
+        assert_eq!(
+            names(&run(&roster(), WorkerQuery::default())),
+            vec!["Ádám Éva", "Nagy Béla", "Zoltán Ödön"]
+        );
+    }
+
+    #[test]
+    fn text_is_accent_insensitive_and_needs_every_word() {
+        let query = |text: &str| WorkerQuery {
+            text: Some(text.to_string()),
+            ..WorkerQuery::default()
+        };
+        assert_eq!(names(&run(&roster(), query("adam"))), vec!["Ádám Éva"]);
+        assert_eq!(
+            names(&run(&roster(), query("ÖDÖN szeged"))),
+            vec!["Zoltán Ödön"]
+        );
+        assert_eq!(names(&run(&roster(), query("1234567"))).len(), 2);
+        assert!(run(&roster(), query("ödön pécs")).is_empty());
+    }
+
+    #[test]
+    fn filters() {
+        let selected = WorkerQuery {
+            selected: Some(true),
+            ..WorkerQuery::default()
+        };
+        assert_eq!(names(&run(&roster(), selected)), vec!["Ádám Éva"]);
+        let city = WorkerQuery {
+            city: Some("Szeged".to_string()),
+            ..WorkerQuery::default()
+        };
+        assert_eq!(
+            names(&run(&roster(), city)),
+            vec!["Nagy Béla", "Zoltán Ödön"]
+        );
+        let tags = WorkerQuery {
+            tags: vec!["kertész".to_string(), "sofőr".to_string()],
+            ..WorkerQuery::default()
+        };
+        assert_eq!(names(&run(&roster(), tags)), vec!["Ádám Éva"]);
+        let complete = Worker {
+            birthplace: "Pécs".to_string(),
+            mothersname: "Kiss Mária".to_string(),
+            street: "Fő utca 1.".to_string(),
+            zip: "7621".to_string(),
+            birthdate: "1990-01-01".to_string(),
+            taj: "123456788".to_string(),
+            taxnumber: "12345678-1-02".to_string(),
+            ..worker("Tóth Géza", "Pécs")
+        };
+        let mut workers = roster();
+        workers.push(complete);
+        let valid = WorkerQuery {
+            has_validation_errors: Some(false),
+            ..WorkerQuery::default()
+        };
+        assert_eq!(names(&run(&workers, valid)), vec!["Tóth Géza"]);
+        let invalid = WorkerQuery {
+            has_validation_errors: Some(true),
+            ..WorkerQuery::default()
+        };
+        assert_eq!(run(&workers, invalid).len(), 3);
+    }
+
+    #[test]
+    fn sort_keys_in_order() {
+        let mut workers = roster();
+        workers[2].city = "Szeged".to_string();
+        let query = WorkerQuery {
+            sort: vec![
+                WorkerSort {
+                    key: WorkerSortKey::City,
+                    descending: true,
+                },
+                WorkerSort {
+                    key: WorkerSortKey::Name,
+                    descending: true,
+                },
+            ],
+            ..WorkerQuery::default()
+        };
+        assert_eq!(
+            names(&run(&workers, query)),
+            vec!["Zoltán Ödön", "Nagy Béla", "Ádám Éva"]
+        );
+        let query = WorkerQuery {
+            sort: vec![WorkerSort {
+                key: WorkerSortKey::Selected,
+                descending: true,
+            }],
+            ..WorkerQuery::default()
+        };
+        assert_eq!(run(&workers, query)[0].name, "Ádám Éva");
+    }
+
+    #[test]
+    fn last_worked_filter_and_sort() {
+        let workers = roster();
+        for (i, day) in [(0, "2024-03-01"), (0, "2024-05-01"), (1, "2024-04-01")] {
+            let day = parse_date(day).unwrap().and_hms_opt(12, 0, 0).unwrap();
+            AuditEntry {
+                timestamp: Local.from_local_datetime(&day).unwrap(),
+                ..AuditEntry::new("op", AuditAction::Export, vec![workers[i].id])
+            }
+            .append()
+            .unwrap();
+        }
+        let range = |from: Option<&str>, to: Option<&str>| WorkerQuery {
+            last_worked_from: from.map(str::to_string),
+            last_worked_to: to.map(str::to_string),
+            ..WorkerQuery::default()
+        };
+        // The last day counts, never worked ones are left out
+        assert_eq!(
+            names(&run(&workers, range(Some("2024-04-15"), None))),
+            vec!["Zoltán Ödön"]
+        );
+        assert_eq!(
+            names(&run(&workers, range(None, Some("2024-04-15")))),
+            vec!["Ádám Éva"]
+        );
+        assert!(query_workers(workers.iter(), &range(Some("április"), None)).is_err());
+        let query = WorkerQuery {
+            sort: vec![WorkerSort {
+                key: WorkerSortKey::LastWorked,
+                descending: true,
+            }],
+            ..WorkerQuery::default()
+        };
+        assert_eq!(
+            names(&run(&workers, query)),
+            vec!["Zoltán Ödön", "Ádám Éva", "Nagy Béla"]
+        );
+    }
+
+    #[test]
+    fn pages_slice_the_matching_workers() {
+        let workers = (0..25)
+            .map(|i| {
+                worker(
+                    &format!("Munkás {:02}", i),
+                    if i % 5 == 0 { "Pécs" } else { "Szeged" },
+                )
+            })
+            .collect::<Vec<Worker>>();
+        let page = |query: &WorkerQuery, offset, limit| {
+            query_page(workers.iter(), query, offset, limit).unwrap()
+        };
+        let all = WorkerQuery::default();
+        let first = page(&all, 0, 10);
+        assert_eq!((first.total, first.offset), (25, 0));
+        assert_eq!(first.workers.first().unwrap().name, "Munkás 00");
+        assert_eq!(first.workers.len(), 10);
+        let last = page(&all, 20, 10);
+        assert_eq!(last.total, 25);
+        assert_eq!(
+            names(&last.workers),
+            vec![
+                "Munkás 20",
+                "Munkás 21",
+                "Munkás 22",
+                "Munkás 23",
+                "Munkás 24"
+            ]
+        );
+        let past = page(&all, 30, 10);
+        assert_eq!((past.total, past.offset), (25, 30));
+        assert!(past.workers.is_empty());
+        // The total is counted after filtering
+        let pecs = WorkerQuery {
+            city: Some("Pécs".to_string()),
+            ..WorkerQuery::default()
+        };
+        let filtered = page(&pecs, 2, 10);
+        assert_eq!(filtered.total, 5);
+        assert_eq!(
+            names(&filtered.workers),
+            vec!["Munkás 10", "Munkás 15", "Munkás 20"]
+        );
+    }
+
+    #[test]
+    fn pages_do_not_overlap_on_equal_keys() {
+        let workers = (0..20)
+            .map(|_| worker("Kiss Anna", "Pécs"))
+            .collect::<Vec<Worker>>();
+        let query = WorkerQuery::default();
+        let mut seen = (0..4)
+            .flat_map(|i| {
+                query_page(workers.iter().rev(), &query, i * 5, 5)
+                    .unwrap()
+                    .workers
+            })
+            .map(|w| w.id)
+            .collect::<Vec<Uuid>>();
+        let first = query_page(workers.iter(), &query, 0, 20).unwrap();
+        assert_eq!(
+            first.workers.iter().map(|w| w.id).collect::<Vec<Uuid>>(),
+            seen
+        );
+        seen.dedup();
+        assert_eq!(seen.len(), 20);
+    }
+}
@@ -0,0 +1,319 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::Path,
+};
+
+use chrono::{Local, NaiveDate};
+use rust_xlsxwriter::{Format, Workbook};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{export_history::reported_days, worker::Worker};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum RosterColumn {
+    Name,
+    // Zip, city and street in one column
+    Address,
+    Taj,
+    Taxnumber,
+    Mothersname,
+    Birthdate,
+    Birthplace,
+    LastWorked,
+    // Number of distinct reported days
+    ReportedDays,
+}
+
+impl RosterColumn {
+    fn title(&self) -> &'static str {
+        match self {
+            RosterColumn::Name => "Név",
+            RosterColumn::Address => "Lakcím",
+            RosterColumn::Taj => "TAJ szám",
+            RosterColumn::Taxnumber => "Adóazonosító jel",
+            RosterColumn::Mothersname => "Anyja neve",
+            RosterColumn::Birthdate => "Születési idő",
+            RosterColumn::Birthplace => "Születési hely",
+            RosterColumn::LastWorked => "Utolsó munkanap",
+            RosterColumn::ReportedDays => "Bejelentett napok",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum RosterFormat {
+    Csv,
+    Xlsx,
+}
+
+enum Cell {
+    Text(String),
+    Date(Option<NaiveDate>),
+    Number(u32),
+}
+
+impl Cell {
+    fn text(&self) -> String {
+        match self {
+            Cell::Text(s) => s.to_owned(),
+            Cell::Date(d) => d
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or_default(),
+            Cell::Number(n) => n.to_string(),
+        }
+    }
+    // Excel runs a cell starting like a formula, so those are kept as text
+    fn csv_text(&self) -> String {
+        let text = self.text();
+        match text.chars().next() {
+            Some('=' | '+' | '-' | '@' | '\t' | '\r') => format!("'{}", text),
+            _ => text,
+        }
+    }
+}
+
+fn cell(column: RosterColumn, worker: &Worker, days: &HashMap<Uuid, BTreeSet<NaiveDate>>) -> Cell {
+    let worked = days.get(&worker.id);
+    match column {
+        RosterColumn::Name => Cell::Text(worker.name.to_owned()),
+        RosterColumn::Address => {
+            Cell::Text(format!("{} {}, {}", worker.zip, worker.city, worker.street))
+        }
+        RosterColumn::Taj => Cell::Text(worker.taj.to_owned()),
+        RosterColumn::Taxnumber => Cell::Text(worker.taxnumber.to_owned()),
+        RosterColumn::Mothersname => Cell::Text(worker.mothersname.to_owned()),
+        // Kept as typed if it is not a valid date
+        RosterColumn::Birthdate => match NaiveDate::parse_from_str(&worker.birthdate, "%Y-%m-%d") {
+            Ok(date) => Cell::Date(Some(date)),
+            Err(_) => Cell::Text(worker.birthdate.to_owned()),
+        },
+        RosterColumn::Birthplace => Cell::Text(worker.birthplace.to_owned()),
+        RosterColumn::LastWorked => Cell::Date(worked.and_then(|d| d.last().copied())),
+        RosterColumn::ReportedDays => Cell::Number(worked.map_or(0, |d| d.len() as u32)),
+    }
+}
+
+// Semicolon separated with BOM, so Excel opens it right in Hungarian locale
+fn write_csv(path: &Path, columns: &[RosterColumn], rows: &[Vec<Cell>]) -> Result<(), String> {
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(b';')
+        .from_writer(vec![0xef, 0xbb, 0xbf]);
+    writer
+        .write_record(columns.iter().map(|c| c.title()))
+        .map_err(|e| e.to_string())?;
+    for row in rows {
+        writer
+            .write_record(row.iter().map(Cell::csv_text))
+            .map_err(|e| e.to_string())?;
+    }
+    let buffer = writer.into_inner().map_err(|e| e.to_string())?;
+    std::fs::write(path, buffer).map_err(|e| e.to_string())
+}
+
+fn write_xlsx(path: &Path, columns: &[RosterColumn], rows: &[Vec<Cell>]) -> Result<(), String> {
+    let mut workbook = Workbook::new();
+    let sheet = workbook.add_worksheet();
+    let bold = Format::new().set_bold();
+    let date = Format::new().set_num_format("yyyy-mm-dd");
+    for (col, column) in columns.iter().enumerate() {
+        sheet
+            .write_string_with_format(0, col as u16, column.title(), &bold)
+            .map_err(|e| e.to_string())?;
+    }
+    for (i, row) in rows.iter().enumerate() {
+        let row_num = i as u32 + 1;
+        for (col, cell) in row.iter().enumerate() {
+            let col = col as u16;
+            match cell {
+                Cell::Text(s) => sheet.write_string(row_num, col, s).map(|_| ()),
+                Cell::Date(Some(d)) => sheet
+                    .write_date_with_format(row_num, col, d, &date)
+                    .map(|_| ()),
+                Cell::Date(None) => Ok(()),
+                Cell::Number(n) => sheet.write_number(row_num, col, *n).map(|_| ()),
+            }
+            .map_err(|e| e.to_string())?;
+        }
+    }
+    sheet.set_freeze_panes(1, 0).map_err(|e| e.to_string())?;
+    sheet.autofit();
+    workbook.save(path).map_err(|e| e.to_string())
+}
+
+// Workers in the given order, returns the path of the written file
+pub(crate) fn write_roster(
+    workers: &[Worker],
+    columns: &[RosterColumn],
+    format: RosterFormat,
+    to: &str,
+) -> Result<String, String> {
+    if columns.is_empty() {
+        return Err("No columns selected".into());
+    }
+    let days = if columns
+        .iter()
+        .any(|c| matches!(c, RosterColumn::LastWorked | RosterColumn::ReportedDays))
+    {
+        reported_days()?
+    } else {
+        HashMap::new()
+    };
+    let rows = workers
+        .iter()
+        .map(|w| columns.iter().map(|c| cell(*c, w, &days)).collect())
+        .collect::<Vec<Vec<Cell>>>();
+    let extension = match format {
+        RosterFormat::Csv => "csv",
+        RosterFormat::Xlsx => "xlsx",
+    };
+    let path = Path::new(to).join(format!(
+        "dolgozok_{}.{}",
+        Local::now().format("%Y%m%d_%H%M%S"),
+        extension
+    ));
+    match format {
+        RosterFormat::Csv => write_csv(&path, columns, &rows)?,
+        RosterFormat::Xlsx => write_xlsx(&path, columns, &rows)?,
+    }
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use calamine::{open_workbook_auto, Data, Reader};
+    use chrono::Local;
+
+    use super::*;
+    use crate::test_util::export;
+
+    fn temp_dir() -> String {
+        let dir = std::env::temp_dir().join(format!("roster-{}", Uuid::new_v4().as_simple()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.to_string_lossy().to_string()
+    }
+
+    fn workers() -> Vec<Worker> {
+        vec![
+            Worker {
+                name: "Kiss Anna".to_string(),
+                zip: "7621".to_string(),
+                city: "Pécs".to_string(),
+                street: "Fő utca 1.".to_string(),
+                birthdate: "1990-05-04".to_string(),
+                taj: "012345678".to_string(),
+                ..Worker::default()
+            },
+            Worker {
+                name: "Nagy Béla".to_string(),
+                birthdate: "1990. május".to_string(),
+                ..Worker::default()
+            },
+        ]
+    }
+
+    const COLUMNS: [RosterColumn; 5] = [
+        RosterColumn::Name,
+        RosterColumn::Address,
+        RosterColumn::Taj,
+        RosterColumn::Birthdate,
+        RosterColumn::LastWorked,
+    ];
+
+    #[test]
+    fn csv_opens_in_hungarian_excel() {
+        let workers = workers();
+        export(
+            vec![workers[0].id],
+            "2024-03-01",
+            "/tmp/a.xml",
+            Local::now(),
+        );
+        export(
+            vec![workers[0].id],
+            "2024-03-05",
+            "/tmp/a.xml",
+            Local::now(),
+        );
+        let path = write_roster(&workers, &COLUMNS, RosterFormat::Csv, &temp_dir()).unwrap();
+        assert!(path.ends_with(".csv"));
+        let content = std::fs::read_to_string(path).unwrap();
+        assert_eq!(
+            content,
+            "\u{feff}Név;Lakcím;TAJ szám;Születési idő;Utolsó munkanap\n\
+             Kiss Anna;7621 Pécs, Fő utca 1.;012345678;1990-05-04;2024-03-05\n\
+             Nagy Béla; , ;;1990. május;\n"
+        );
+    }
+
+    #[test]
+    fn csv_cells_are_not_formulas() {
+        let workers = [
+            Worker {
+                name: "=HYPERLINK(\"http://x\")".to_string(),
+                street: "-1+1".to_string(),
+                taj: "+36123".to_string(),
+                birthdate: "@SUM(A1)".to_string(),
+                ..Worker::default()
+            },
+            Worker {
+                name: "Kiss-Nagy Anna".to_string(),
+                ..Worker::default()
+            },
+        ];
+        let path = write_roster(&workers, &COLUMNS, RosterFormat::Csv, &temp_dir()).unwrap();
+        let content = std::fs::read_to_string(path).unwrap();
+        let lines = content.lines().collect::<Vec<&str>>();
+        assert_eq!(
+            lines[1],
+            "\"'=HYPERLINK(\"\"http://x\"\")\"; , -1+1;'+36123;'@SUM(A1);"
+        );
+        assert_eq!(lines[2], "Kiss-Nagy Anna; , ;;;");
+    }
+
+    #[test]
+    fn xlsx_has_typed_cells() {
+        let workers = workers();
+        export(
+            vec![workers[0].id, workers[1].id],
+            "2024-03-01",
+            "/tmp/a.xml",
+            Local::now(),
+        );
+        export(
+            vec![workers[0].id],
+            "2024-03-05",
+            "/tmp/a.xml",
+            Local::now(),
+        );
+        export(
+            vec![workers[0].id],
+            "2024-03-05",
+            "/tmp/a.xml",
+            Local::now(),
+        );
+        let columns = [
+            RosterColumn::Name,
+            RosterColumn::Taj,
+            RosterColumn::Birthdate,
+            RosterColumn::ReportedDays,
+        ];
+        let path = write_roster(&workers, &columns, RosterFormat::Xlsx, &temp_dir()).unwrap();
+        let mut workbook = open_workbook_auto(&path).unwrap();
+        let range = workbook.worksheet_range_at(0).unwrap().unwrap();
+        let rows = range.rows().collect::<Vec<&[Data]>>();
+        assert_eq!(rows[0][0], Data::String("Név".to_string()));
+        // The TAJ stays text, so the leading zero is kept
+        assert_eq!(rows[1][1], Data::String("012345678".to_string()));
+        assert!(matches!(rows[1][2], Data::DateTime(_)));
+        assert_eq!(rows[2][2], Data::String("1990. május".to_string()));
+        // Distinct days
+        assert_eq!(rows[1][3], Data::Float(2.0));
+        assert_eq!(rows[2][3], Data::Float(1.0));
+    }
+
+    #[test]
+    fn columns_are_required() {
+        assert!(write_roster(&workers(), &[], RosterFormat::Csv, &temp_dir()).is_err());
+    }
+}
@@ -0,0 +1,71 @@
+use std::path::PathBuf;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::worker::{get_data_dir, parse_date};
+
+pub(crate) const SELECTIONS_DIR: &str = "selections";
+
+// Workers selected for an employer on a given day
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SelectionSession {
+    // Tax number of the employer
+    pub employer: String,
+    // As %Y-%m-%d
+    pub date: String,
+    pub worker_ids: Vec<Uuid>,
+}
+
+impl SelectionSession {
+    fn path(employer: &str, date: NaiveDate) -> Result<PathBuf, String> {
+        let dir = get_data_dir()?.join(SELECTIONS_DIR);
+        if !dir.exists() {
+            std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        }
+        let employer = employer
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect::<String>();
+        Ok(dir.join(format!("{}_{}.json", employer, date.format("%Y%m%d"))))
+    }
+    // None if nothing was selected for it yet
+    pub(crate) fn load(employer: &str, date: NaiveDate) -> Result<Option<Self>, String> {
+        let path = Self::path(employer, date)?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        let buffer = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        serde_json::from_str(&buffer)
+            .map(Some)
+            .map_err(|e| e.to_string())
+    }
+    pub(crate) fn save(&self) -> Result<(), String> {
+        let buffer = serde_json::to_string(&self).map_err(|e| e.to_string())?;
+        std::fs::write(Self::path(&self.employer, parse_date(&self.date)?)?, buffer)
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn date_keeps_its_stored_format() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        let session = SelectionSession {
+            employer: "12345678".to_string(),
+            date: "2024-03-05".to_string(),
+            worker_ids: vec![Uuid::new_v4()],
+        };
+        session.save().unwrap();
+        assert_eq!(
+            serde_json::to_value(&session).unwrap()["date"],
+            serde_json::to_value(date).unwrap()
+        );
+        assert_eq!(SelectionSession::load("12345678", date), Ok(Some(session)));
+        assert!(parse_date("2024.03.05").is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Read, Write},
+    path::PathBuf,
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::worker::get_data_dir;
+
+pub(crate) const SETTINGS_FILE: &str = "settings.json";
+
+fn get_settings_path() -> Result<PathBuf, String> {
+    Ok(get_data_dir()?.join(SETTINGS_FILE))
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Employer {
+    pub tax_number: String,
+    pub name: String,
+    pub phone: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Settings {
+    // Name recorded in the audit log
+    #[serde(default = "default_operator")]
+    pub operator: String,
+    // Number of snapshots kept in the backups folder
+    #[serde(default = "default_backup_keep")]
+    pub backup_keep: u32,
+    // Lock after this many minutes of inactivity, 0 means never
+    #[serde(default = "default_auto_lock_minutes")]
+    pub auto_lock_minutes: u32,
+    // Anonymize workers inactive for this many years, 0 means never
+    #[serde(default)]
+    pub retention_years: u32,
+    // Reports are made for these, the first one is used by default
+    #[serde(default = "default_employers")]
+    pub employers: Vec<Employer>,
+}
+
+fn default_employers() -> Vec<Employer> {
+    vec![Employer {
+        tax_number: "23127182215".to_string(),
+        name: "Mezei Istvánné".to_string(),
+        phone: "06305236153".to_string(),
+    }]
+}
+
+fn default_auto_lock_minutes() -> u32 {
+    10
+}
+
+fn default_backup_keep() -> u32 {
+    30
+}
+
+fn default_operator() -> String {
+    std::env::var("USERNAME")
+        .or_else(|_| std::env::var("USER"))
+        .unwrap_or_default()
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            operator: default_operator(),
+            backup_keep: default_backup_keep(),
+            auto_lock_minutes: default_auto_lock_minutes(),
+            retention_years: 0,
+            employers: default_employers(),
+        }
+    }
+}
+
+impl Settings {
+    pub(crate) fn load() -> Result<Self, String> {
+        let path = get_settings_path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let mut file = File::open(&path).map_err(|e| e.to_string())?;
+        let mut buffer = String::new();
+        file.read_to_string(&mut buffer)
+            .map_err(|e| e.to_string())?;
+        serde_json::from_str(&buffer).map_err(|e| e.to_string())
+    }
+    pub(crate) fn default_employer(&self) -> String {
+        self.employers
+            .first()
+            .map(|e| e.tax_number.to_owned())
+            .unwrap_or_default()
+    }
+    pub(crate) fn save(&self) -> Result<&Self, String> {
+        let mut buffer =
+            BufWriter::new(File::create(get_settings_path()?).map_err(|e| e.to_string())?);
+        buffer
+            .write_all(
+                serde_json::to_string(&self)
+                    .map_err(|e| e.to_string())?
+                    .as_bytes(),
+            )
+            .map_err(|e| e.to_string())?;
+        buffer.flush().map_err(|e| e.to_string())?;
+        Ok(self)
+    }
+}
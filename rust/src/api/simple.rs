@@ -1,70 +1,424 @@
 use std::{
-    fs::File,
-    io::Write,
+    collections::HashSet,
+    fs::OpenOptions,
+    io::{ErrorKind, Write},
     path::Path,
     sync::{OnceLock, RwLock},
 };
 
-use chrono::Local;
+use chrono::{Local, NaiveDate};
+use uuid::Uuid;
 
-use crate::xml::render_xml;
+use crate::{
+    frb_generated::StreamSink,
+    text::collation_key,
+    xml::{render_xml, validate_xml, RenderContext, ReportRow},
+};
 
-use super::worker::{Db, Worker};
+use super::{
+    audit::{merge_audit_log, read_audit_log, AuditAction, AuditEntry},
+    backup::{self, ImportMode, ImportSummary, Snapshot, SnapshotReason},
+    crew::{self, Crew},
+    draft::{self, DraftIssue, ReportDraft},
+    events::{self, DbEvent},
+    export_history::{self, ExportRecord, ReportedWorker},
+    export_lock::ExportLock,
+    history::{HistoryAction, HistoryItem},
+    lock,
+    privacy::{retention_candidates, RetentionCandidate, SubjectReport},
+    query::{query_page, query_workers, WorkerPage, WorkerQuery},
+    roster_export::{self, RosterColumn, RosterFormat},
+    session::SelectionSession,
+    settings::Settings,
+    spreadsheet_import::{self, ImportProfile, SpreadsheetImport},
+    storage, watcher,
+    worker::{get_workers_dir, parse_date, Db, UpdateError, Worker},
+    xml_import::{self, XmlImportSummary},
+};
 
 static COUNTER: OnceLock<RwLock<i32>> = OnceLock::new();
 static DB: OnceLock<RwLock<crate::api::worker::Db>> = OnceLock::new();
 
-fn _export_xml(to: String) -> Result<(), String> {
+// Returns the path of the written file
+fn _export_report(
+    context: &RenderContext,
+    rows: &[ReportRow],
+    worker_ids: Vec<Uuid>,
+    to: &str,
+) -> Result<String, String> {
+    let _lock = ExportLock::acquire()?;
+
+    backup::create_snapshot(SnapshotReason::Export)?;
+
+    let xml = render_xml(context, rows);
+
+    let issues = validate_xml(&xml);
+
+    if !issues.is_empty() {
+        return Err(format!(
+            "Invalid report: {}",
+            issues
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<String>>()
+                .join("; ")
+        ));
+    }
+
+    let mut attempt = 0;
+
+    let (path, mut file) = loop {
+        let path = Path::new(to).join(context.file_name(attempt));
+
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => break (path, file),
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => attempt += 1,
+            Err(e) => return Err(e.to_string()),
+        }
+    };
+
+    file.write_all(xml.as_bytes()).map_err(|e| e.to_string())?;
+
+    file.flush().map_err(|e| e.to_string())?;
+
+    let path = path.to_string_lossy().to_string();
+
+    let db = DB.get().unwrap().read().unwrap();
+
+    ExportRecord::create(
+        &db.operator,
+        &context.employer,
+        context.report_date,
+        worker_ids
+            .iter()
+            .zip(rows)
+            .map(|(id, row)| ReportedWorker::new(*id, row))
+            .collect(),
+        &path,
+        &xml,
+        context.generated_at,
+    )?;
+
+    db.log_event(AuditAction::Export, worker_ids, &path)?;
+
+    Ok(path)
+}
+
+// For the employer of the current selection session, the session day may be
+// stale when the app stays open, so the report day comes from the caller
+fn _export_workers(workers: Vec<Worker>, to: &str, date: NaiveDate) -> Result<(), String> {
+    let session = DB.get().unwrap().read().unwrap().session();
+    let employer = Settings::load()?
+        .employers
+        .into_iter()
+        .find(|e| e.tax_number == session.employer)
+        .ok_or("Unknown employer")?;
+    let rows = workers
+        .iter()
+        .map(ReportRow::from)
+        .collect::<Vec<ReportRow>>();
+    let worker_ids = workers.iter().map(|w| w.id).collect::<Vec<Uuid>>();
+    let context = RenderContext::new(employer, date);
+    _export_report(&context, &rows, worker_ids, to)?;
+    Ok(())
+}
+
+fn _export_xml(to: String, date: NaiveDate) -> Result<(), String> {
     let selected_workers = _get_workers()
         .into_iter()
         .filter(|w| w.is_selected)
         .collect::<Vec<Worker>>();
 
-    let xml = render_xml(selected_workers);
+    _export_workers(selected_workers, &to, date)
+}
 
-    let file_name = format!("{}.xml", Local::now().naive_local().to_string());
+// The day of the report as %Y-%m-%d
+#[flutter_rust_bridge::frb(sync)]
+pub fn export_xml_api(to: String, date: String) -> Result<(), String> {
+    lock::check()?;
+    _export_xml(to, parse_date(&date)?)
+}
 
-    let path = Path::new(&to).join(&file_name);
+// Most recent first
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_export_history() -> Result<Vec<ExportRecord>, String> {
+    lock::check()?;
+    export_history::list_exports()
+}
 
-    let mut file = File::create(path).map_err(|e| e.to_string())?;
+// The XML as it was submitted, except the rows of anonymized workers
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_export_xml(id: Uuid) -> Result<String, String> {
+    lock::check()?;
+    ExportRecord::load(id)?.xml()
+}
 
-    file.write_all(xml.as_bytes()).map_err(|e| e.to_string())?;
+// Returns the path of the XML file
+#[flutter_rust_bridge::frb(sync)]
+pub fn resave_export(id: Uuid, to: String) -> Result<String, String> {
+    lock::check()?;
+    ExportRecord::load(id)?.resave(&to)
+}
 
-    file.flush().map_err(|e| e.to_string())?;
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_drafts() -> Result<Vec<ReportDraft>, String> {
+    lock::check()?;
+    draft::list_drafts()
+}
 
-    Ok(())
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_draft(id: Uuid) -> Result<ReportDraft, String> {
+    lock::check()?;
+    ReportDraft::load(id)
+}
+
+// With the current selection as rows
+#[flutter_rust_bridge::frb(sync)]
+pub fn create_draft(date: String, employer: String) -> Result<ReportDraft, String> {
+    lock::check()?;
+    let date = parse_date(&date)?;
+    let ids = _get_selected_workers().iter().map(|w| w.id).collect();
+    let mut draft = ReportDraft::new(date, employer, ids);
+    draft.save()?;
+    Ok(draft)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn save_draft(mut draft: ReportDraft) -> Result<ReportDraft, String> {
+    lock::check()?;
+    ReportDraft::load(draft.id)?;
+    draft.save()?;
+    Ok(draft)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn delete_draft(id: Uuid) -> Result<(), String> {
+    lock::check()?;
+    ReportDraft::delete(id)
+}
+
+fn _validate_draft(id: Uuid) -> Result<Vec<DraftIssue>, String> {
+    let draft = ReportDraft::load(id)?;
+    Ok(draft.validate(&DB.get().unwrap().read().unwrap()))
+}
+
+// Empty if the draft can be exported
+#[flutter_rust_bridge::frb(sync)]
+pub fn validate_draft(id: Uuid) -> Result<Vec<DraftIssue>, String> {
+    lock::check()?;
+    _validate_draft(id)
+}
+
+fn _export_draft_xml(id: Uuid, to: String) -> Result<String, String> {
+    let mut draft = ReportDraft::load(id)?;
+    let rows = {
+        let db = DB.get().unwrap().read().unwrap();
+        if let Some(issue) = draft.validate(&db).first() {
+            return Err(issue.message.to_owned());
+        }
+        draft.report_rows(&db)?
+    };
+    let worker_ids = draft.rows.iter().map(|r| r.worker_id).collect();
+    let context = RenderContext::new(draft.employer()?, parse_date(&draft.date)?);
+    let path = _export_report(&context, &rows, worker_ids, &to)?;
+    draft.exported_at = Some(Local::now());
+    draft.save()?;
+    Ok(path)
+}
+
+// Returns the path of the XML file
+#[flutter_rust_bridge::frb(sync)]
+pub fn export_draft_xml(id: Uuid, to: String) -> Result<String, String> {
+    lock::check()?;
+    _export_draft_xml(id, to)
+}
+
+fn _crew_workers(id: Uuid) -> Result<Vec<Worker>, String> {
+    let crew = crew::get_crew(id)?;
+    // Removed workers are skipped
+    Ok(_get_workers()
+        .into_iter()
+        .filter(|w| crew.worker_ids.contains(&w.id))
+        .collect())
+}
+
+// The selection stays as it is
+#[flutter_rust_bridge::frb(sync)]
+pub fn export_crew_xml(id: Uuid, to: String, date: String) -> Result<(), String> {
+    lock::check()?;
+    _export_workers(_crew_workers(id)?, &to, parse_date(&date)?)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_crews() -> Result<Vec<Crew>, String> {
+    lock::check()?;
+    crew::load_crews()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_crew_workers(id: Uuid) -> Result<Vec<Worker>, String> {
+    lock::check()?;
+    _crew_workers(id)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn create_crew(name: String, worker_ids: Vec<Uuid>) -> Result<Crew, String> {
+    lock::check()?;
+    crew::save_crew(Crew::new(name, worker_ids))
+}
+
+// From the current selection
+#[flutter_rust_bridge::frb(sync)]
+pub fn create_crew_from_selection(name: String) -> Result<Crew, String> {
+    lock::check()?;
+    let ids = _get_selected_workers().iter().map(|w| w.id).collect();
+    crew::save_crew(Crew::new(name, ids))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn update_crew(crew: Crew) -> Result<Crew, String> {
+    lock::check()?;
+    crew::get_crew(crew.id)?;
+    crew::save_crew(crew)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn delete_crew(id: Uuid) -> Result<(), String> {
+    lock::check()?;
+    crew::delete_crew(id)
 }
 
+fn _apply_crew(id: Uuid, replace: bool) -> Result<u32, String> {
+    let crew = crew::get_crew(id)?;
+    _set_selection(&format!("Select crew {}", crew.name), |w| {
+        crew.worker_ids.contains(&w.id) || (!replace && w.is_selected)
+    })
+}
+
+// Select the crew, replace clears the rest of the selection
 #[flutter_rust_bridge::frb(sync)]
-pub fn export_xml_api(to: String) -> Result<(), String> {
-    _export_xml(to)
+pub fn apply_crew(id: Uuid, replace: bool) -> Result<u32, String> {
+    lock::check()?;
+    _apply_crew(id, replace)
 }
 
 fn _get_workers() -> Vec<Worker> {
-    let mut db = DB.get().unwrap().read().unwrap().workers.to_owned();
-    db.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    let mut db = DB
+        .get()
+        .unwrap()
+        .read()
+        .unwrap()
+        .workers()
+        .cloned()
+        .collect::<Vec<Worker>>();
+    db.sort_by_cached_key(|w| collation_key(&w.name));
     db
 }
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn get_workers() -> Vec<Worker> {
-    _get_workers()
+pub fn get_workers() -> Result<Vec<Worker>, String> {
+    lock::check()?;
+    Ok(_get_workers())
+}
+
+fn _query_workers(query: WorkerQuery) -> Result<Vec<Worker>, String> {
+    query_workers(DB.get().unwrap().read().unwrap().workers(), &query)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn query_workers_api(query: WorkerQuery) -> Result<Vec<Worker>, String> {
+    lock::check()?;
+    _query_workers(query)
+}
+
+fn _export_roster(
+    query: WorkerQuery,
+    columns: Vec<RosterColumn>,
+    format: RosterFormat,
+    to: String,
+) -> Result<String, String> {
+    let workers = _query_workers(query)?;
+    let path = roster_export::write_roster(&workers, &columns, format, &to)?;
+    DB.get().unwrap().read().unwrap().log_event(
+        AuditAction::RosterExport,
+        workers.iter().map(|w| w.id).collect(),
+        &path,
+    )?;
+    Ok(path)
+}
+
+// Workers matching the query in its order, returns the path of the file
+#[flutter_rust_bridge::frb(sync)]
+pub fn export_roster(
+    query: WorkerQuery,
+    columns: Vec<RosterColumn>,
+    format: RosterFormat,
+    to: String,
+) -> Result<String, String> {
+    lock::check()?;
+    _export_roster(query, columns, format, to)
 }
 
-fn _add_worker(worker: Worker) {
+fn _find_duplicate_workers(worker: Worker) -> Vec<Worker> {
     DB.get()
         .unwrap()
-        .write()
+        .read()
         .unwrap()
-        .add_new_worker(worker)
-        .unwrap();
+        .find_duplicates(&worker)
+        .into_iter()
+        .cloned()
+        .collect()
+}
+
+// Other workers with the same TAJ or tax ID, shown as a warning after saving
+#[flutter_rust_bridge::frb(sync)]
+pub fn find_duplicate_workers(worker: Worker) -> Result<Vec<Worker>, String> {
+    lock::check()?;
+    Ok(_find_duplicate_workers(worker))
+}
+
+fn _get_workers_page(query: WorkerQuery, offset: u32, limit: u32) -> Result<WorkerPage, String> {
+    query_page(
+        DB.get().unwrap().read().unwrap().workers(),
+        &query,
+        offset,
+        limit,
+    )
+}
+
+// Only the rows shown by the table, use fetch_workers_page off the UI thread
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_workers_page(query: WorkerQuery, offset: u32, limit: u32) -> Result<WorkerPage, String> {
+    lock::check()?;
+    _get_workers_page(query, offset, limit)
+}
+
+pub fn fetch_workers_page(
+    query: WorkerQuery,
+    offset: u32,
+    limit: u32,
+) -> Result<WorkerPage, String> {
+    lock::check()?;
+    _get_workers_page(query, offset, limit)
+}
+
+fn _add_worker(worker: Worker) -> Result<(), String> {
+    DB.get().unwrap().write().unwrap().add_new_worker(worker)
 }
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn add_worker(worker: Worker) {
+pub fn add_worker(worker: Worker) -> Result<(), String> {
+    lock::check()?;
     _add_worker(worker)
 }
 
+// Emits an event whenever the worker data changes
+pub fn subscribe_events(sink: StreamSink<DbEvent>) -> Result<(), String> {
+    lock::check()?;
+    events::subscribe(sink);
+    Ok(())
+}
+
 #[flutter_rust_bridge::frb(sync)]
 pub fn get_empty_worker() -> Worker {
     let w = Worker::default();
@@ -72,37 +426,627 @@ pub fn get_empty_worker() -> Worker {
     w
 }
 
-fn _update_worker(worker: Worker) {
+fn _update_worker(worker: Worker) -> Result<(), UpdateError> {
+    DB.get().unwrap().write().unwrap().update_worker(worker)?;
+    Ok(())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn update_worker(worker: Worker) -> Result<(), UpdateError> {
+    lock::check()?;
+    _update_worker(worker)
+}
+
+fn _remove_worker(worker: Worker) -> Result<(), UpdateError> {
+    DB.get().unwrap().write().unwrap().remove_worker(worker)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn remove_worker_api(worker: Worker) -> Result<(), UpdateError> {
+    lock::check()?;
+    _remove_worker(worker)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_selection_session() -> Result<SelectionSession, String> {
+    lock::check()?;
+    Ok(DB.get().unwrap().read().unwrap().session())
+}
+
+fn _open_selection_session(employer: String, date: NaiveDate) -> Result<(), String> {
     DB.get()
         .unwrap()
         .write()
         .unwrap()
-        .update_worker(worker)
-        .unwrap();
+        .open_session(employer, date)?;
+    events::emit(DbEvent::Reloaded);
+    Ok(())
 }
 
+// Selection is kept separately for every employer and day
 #[flutter_rust_bridge::frb(sync)]
-pub fn update_worker(worker: Worker) {
-    _update_worker(worker)
+pub fn open_selection_session(employer: String, date: String) -> Result<(), String> {
+    lock::check()?;
+    _open_selection_session(employer, parse_date(&date)?)
+}
+
+fn _get_selected_workers() -> Vec<Worker> {
+    let mut res = DB
+        .get()
+        .unwrap()
+        .read()
+        .unwrap()
+        .get_workers_selected()
+        .into_iter()
+        .cloned()
+        .collect::<Vec<Worker>>();
+    res.sort_by_cached_key(|w| collation_key(&w.name));
+    res
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_selected_workers() -> Result<Vec<Worker>, String> {
+    lock::check()?;
+    Ok(_get_selected_workers())
 }
 
-fn _remove_worker(worker: Worker) {
+fn _set_worker_selected(id: Uuid, selected: bool) -> Result<(), String> {
     DB.get()
         .unwrap()
         .write()
         .unwrap()
-        .remove_worker(worker)
-        .unwrap();
+        .set_worker_selected_by_id(id, selected)
+        .ok_or("Worker not found by ID")?;
+    Ok(())
 }
 
 #[flutter_rust_bridge::frb(sync)]
-pub fn remove_worker_api(worker: Worker) {
-    _remove_worker(worker)
+pub fn set_worker_selected(id: Uuid, selected: bool) -> Result<(), String> {
+    lock::check()?;
+    _set_worker_selected(id, selected)
+}
+
+// The bulk selection functions return the number of changed workers,
+// and can be undone in one step
+fn _set_selection(description: &str, selected: impl Fn(&Worker) -> bool) -> Result<u32, String> {
+    DB.get()
+        .unwrap()
+        .write()
+        .unwrap()
+        .set_selection(description, selected)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn select_all_workers() -> Result<u32, String> {
+    lock::check()?;
+    _set_selection("Select all", |_| true)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn clear_selection() -> Result<u32, String> {
+    lock::check()?;
+    _set_selection("Clear selection", |_| false)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn invert_selection() -> Result<u32, String> {
+    lock::check()?;
+    _set_selection("Invert selection", |w| !w.is_selected)
+}
+
+fn select_ids(db: &mut Db, ids: HashSet<Uuid>, selected: bool) -> Result<u32, String> {
+    db.set_selection(&format!("Select {} workers", ids.len()), |w| {
+        if ids.contains(&w.id) {
+            selected
+        } else {
+            w.is_selected
+        }
+    })
+}
+
+fn _select_workers_by_ids(ids: Vec<Uuid>, selected: bool) -> Result<u32, String> {
+    select_ids(
+        &mut DB.get().unwrap().write().unwrap(),
+        ids.into_iter().collect(),
+        selected,
+    )
+}
+
+// Only the given workers change
+#[flutter_rust_bridge::frb(sync)]
+pub fn select_workers_by_ids(ids: Vec<Uuid>, selected: bool) -> Result<u32, String> {
+    lock::check()?;
+    _select_workers_by_ids(ids, selected)
+}
+
+fn _select_workers_by_query(query: WorkerQuery, selected: bool) -> Result<u32, String> {
+    // One guard, so the workers can not change between the query and the selection
+    let mut db = DB.get().unwrap().write().unwrap();
+    let ids = query_workers(db.workers(), &query)?
+        .iter()
+        .map(|w| w.id)
+        .collect::<HashSet<Uuid>>();
+    select_ids(&mut db, ids, selected)
+}
+
+// Workers matching the query change, the others keep their selection
+#[flutter_rust_bridge::frb(sync)]
+pub fn select_workers_by_query(query: WorkerQuery, selected: bool) -> Result<u32, String> {
+    lock::check()?;
+    _select_workers_by_query(query, selected)
+}
+
+fn _undo() -> Result<Option<HistoryItem>, UpdateError> {
+    DB.get().unwrap().write().unwrap().undo()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn undo() -> Result<Option<HistoryItem>, UpdateError> {
+    lock::check()?;
+    _undo()
+}
+
+fn _redo() -> Result<Option<HistoryItem>, UpdateError> {
+    DB.get().unwrap().write().unwrap().redo()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn redo() -> Result<Option<HistoryItem>, UpdateError> {
+    lock::check()?;
+    _redo()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn can_undo() -> bool {
+    DB.get().unwrap().read().unwrap().history.can_undo()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn can_redo() -> bool {
+    DB.get().unwrap().read().unwrap().history.can_redo()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_history(limit: usize) -> Result<Vec<HistoryItem>, String> {
+    lock::check()?;
+    Ok(DB.get().unwrap().read().unwrap().history.items(limit))
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_audit_log(
+    worker_id: Option<Uuid>,
+    from: Option<String>,
+    to: Option<String>,
+) -> Result<Vec<AuditEntry>, String> {
+    lock::check()?;
+    read_audit_log(
+        worker_id,
+        from.as_deref().map(parse_date).transpose()?,
+        to.as_deref().map(parse_date).transpose()?,
+    )
+}
+
+fn _get_worker_data_report(id: Uuid) -> Result<SubjectReport, String> {
+    let db = DB.get().unwrap().read().unwrap();
+    let worker = db.get_by_id(id).ok_or("Worker not found by ID")?;
+    SubjectReport::new(worker)
+}
+
+// Everything stored about a worker, for data subject requests
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_worker_data_report(id: Uuid) -> Result<SubjectReport, String> {
+    lock::check()?;
+    _get_worker_data_report(id)
+}
+
+fn _export_worker_data(id: Uuid, to: String) -> Result<Vec<String>, String> {
+    let files = _get_worker_data_report(id)?.write(&to)?;
+    DB.get()
+        .unwrap()
+        .read()
+        .unwrap()
+        .log_event(AuditAction::SubjectAccess, vec![id], &to)?;
+    Ok(files)
+}
+
+// Writes a JSON and a text file into the given folder
+#[flutter_rust_bridge::frb(sync)]
+pub fn export_worker_data(id: Uuid, to: String) -> Result<Vec<String>, String> {
+    lock::check()?;
+    _export_worker_data(id, to)
+}
+
+fn _preview_retention() -> Result<Vec<RetentionCandidate>, String> {
+    let years = Settings::load()?.retention_years;
+    retention_candidates(DB.get().unwrap().read().unwrap().workers(), years)
+}
+
+// Workers that would be anonymized by run_retention
+#[flutter_rust_bridge::frb(sync)]
+pub fn preview_retention() -> Result<Vec<RetentionCandidate>, String> {
+    lock::check()?;
+    _preview_retention()
+}
+
+fn _run_retention() -> Result<u32, String> {
+    let years = Settings::load()?.retention_years;
+    let mut db = DB.get().unwrap().write().unwrap();
+    let ids = retention_candidates(db.workers(), years)?
+        .iter()
+        .map(|c| c.id)
+        .collect::<Vec<Uuid>>();
+    if !ids.is_empty() {
+        db.anonymize_workers(&ids)?;
+    }
+    Ok(ids.len() as u32)
+}
+
+// Never run on its own, anonymizing can not be undone, so the user starts it
+// after looking at preview_retention
+#[flutter_rust_bridge::frb(sync)]
+pub fn run_retention() -> Result<u32, String> {
+    lock::check()?;
+    _run_retention()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_settings() -> Result<Settings, String> {
+    lock::check()?;
+    Settings::load()
+}
+
+fn _set_settings(settings: Settings) -> Result<(), String> {
+    settings.save()?;
+    lock::set_auto_lock(settings.auto_lock_minutes);
+    DB.get().unwrap().write().unwrap().operator = settings.operator;
+    Ok(())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_settings(settings: Settings) -> Result<(), String> {
+    lock::check()?;
+    _set_settings(settings)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn list_snapshots() -> Result<Vec<Snapshot>, String> {
+    lock::check()?;
+    backup::list_snapshots()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn create_snapshot() -> Result<Snapshot, String> {
+    lock::check()?;
+    backup::create_snapshot(SnapshotReason::Manual)
+}
+
+fn _restore_snapshot(id: String) -> Result<(), String> {
+    let mut db = DB.get().unwrap().write().unwrap();
+    backup::restore_snapshot(&id)?;
+    *db = db.reopen()?;
+    db.log_event(AuditAction::Restore, vec![], &id)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn restore_snapshot(id: String) -> Result<(), String> {
+    lock::check()?;
+    _restore_snapshot(id)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn export_backup_file(path: String, password: String) -> Result<(), String> {
+    lock::check()?;
+    backup::write_backup_file(&path, &password)
+}
+
+fn _import_backup_file(
+    path: String,
+    password: String,
+    mode: ImportMode,
+    dry_run: bool,
+) -> Result<ImportSummary, String> {
+    let backup = backup::read_backup_file(&path, &password)?;
+    let mut db = DB.get().unwrap().write().unwrap();
+    let summary = backup.summary(&db, mode);
+    if dry_run {
+        return Ok(summary);
+    }
+    match mode {
+        ImportMode::Merge => {
+            let changes = backup.merge_changes(&db);
+            db.apply_changes(
+                HistoryAction::Update,
+                format!("Import {} workers", changes.len()),
+                changes,
+                "Import",
+            )?;
+        }
+        ImportMode::Replace => {
+            backup::replace_data_dir(&backup.archive, &storage::seal_file)?;
+            *db = db.reopen()?;
+        }
+    }
+    merge_audit_log(&backup.audit_log)?;
+    db.log_event(AuditAction::Import, vec![], &path)?;
+    Ok(summary)
+}
+
+// Summary of what an import would change, without changing anything
+#[flutter_rust_bridge::frb(sync)]
+pub fn preview_backup_import(
+    path: String,
+    password: String,
+    mode: ImportMode,
+) -> Result<ImportSummary, String> {
+    lock::check()?;
+    _import_backup_file(path, password, mode, true)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn import_backup_file(
+    path: String,
+    password: String,
+    mode: ImportMode,
+) -> Result<ImportSummary, String> {
+    lock::check()?;
+    _import_backup_file(path, password, mode, false)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_import_profiles() -> Result<Vec<ImportProfile>, String> {
+    lock::check()?;
+    spreadsheet_import::load_profiles()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn save_import_profile(profile: ImportProfile) -> Result<ImportProfile, String> {
+    lock::check()?;
+    spreadsheet_import::save_profile(profile)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn delete_import_profile(name: String) -> Result<(), String> {
+    lock::check()?;
+    spreadsheet_import::delete_profile(&name)
+}
+
+// Column headers of a CSV or Excel file
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_spreadsheet_headers(path: String) -> Result<Vec<String>, String> {
+    lock::check()?;
+    spreadsheet_import::read_headers(&path)
+}
+
+fn _import_spreadsheet(
+    path: String,
+    profile: ImportProfile,
+    dry_run: bool,
+) -> Result<SpreadsheetImport, String> {
+    if dry_run {
+        let db = DB.get().unwrap().read().unwrap();
+        return spreadsheet_import::read_spreadsheet(&path, &profile, &db);
+    }
+    let mut db = DB.get().unwrap().write().unwrap();
+    let import = spreadsheet_import::read_spreadsheet(&path, &profile, &db)?;
+    if import.valid == 0 {
+        return Ok(import);
+    }
+    let workers = import.valid_workers();
+    let ids = workers.iter().map(|w| w.id).collect::<Vec<Uuid>>();
+    // All or nothing, a failed write rolls back the ones before it
+    db.apply_changes(
+        HistoryAction::Add,
+        format!("Import {} workers", workers.len()),
+        workers.into_iter().map(|w| (w.id, Some(w))).collect(),
+        &path,
+    )?;
+    // Imported already, a failed log entry does not make it fail
+    let _ = db.log_event(AuditAction::Import, ids, &path);
+    Ok(import)
+}
+
+// Rows with their errors, without changing anything
+#[flutter_rust_bridge::frb(sync)]
+pub fn preview_spreadsheet_import(
+    path: String,
+    profile: ImportProfile,
+) -> Result<SpreadsheetImport, String> {
+    lock::check()?;
+    _import_spreadsheet(path, profile, true)
+}
+
+// Rows without errors are added as one undoable step
+#[flutter_rust_bridge::frb(sync)]
+pub fn import_spreadsheet(
+    path: String,
+    profile: ImportProfile,
+) -> Result<SpreadsheetImport, String> {
+    lock::check()?;
+    _import_spreadsheet(path, profile, false)
+}
+
+fn _import_xml_files(paths: Vec<String>, dry_run: bool) -> Result<XmlImportSummary, String> {
+    if dry_run {
+        let db = DB.get().unwrap().read().unwrap();
+        return Ok(xml_import::read_xml_files(&paths, &db)?.summary);
+    }
+    let mut db = DB.get().unwrap().write().unwrap();
+    let import = xml_import::read_xml_files(&paths, &db)?;
+    let note = format!("Import {} XML files", paths.len());
+    // Nothing is kept of a failed import, so its files are not skipped next time
+    let remove = |records: &[ExportRecord]| {
+        for record in records {
+            let _ = record.delete();
+        }
+    };
+    let mut records = vec![];
+    let mut ids = HashSet::new();
+    for report in import.reports {
+        ids.extend(report.workers.iter().map(|w| w.worker_id));
+        match ExportRecord::create(
+            &db.operator,
+            &report.employer,
+            report.report_date,
+            report.workers,
+            &report.path,
+            &report.xml,
+            report.timestamp,
+        ) {
+            Ok(record) => records.push(record),
+            Err(e) => {
+                remove(&records);
+                return Err(e);
+            }
+        }
+    }
+    if !import.workers.is_empty() {
+        let res = db.apply_changes(
+            HistoryAction::Add,
+            format!("Import {} workers", import.workers.len()),
+            import
+                .workers
+                .into_iter()
+                .map(|w| (w.id, Some(w)))
+                .collect(),
+            &note,
+        );
+        if let Err(e) = res {
+            remove(&records);
+            return Err(e.into());
+        }
+    }
+    // Imported already, a failed log entry does not make it fail
+    let _ = db.log_event(AuditAction::Import, ids.into_iter().collect(), &note);
+    Ok(import.summary)
+}
+
+// What importing the T1042E files would do, without changing anything
+#[flutter_rust_bridge::frb(sync)]
+pub fn preview_xml_import(paths: Vec<String>) -> Result<XmlImportSummary, String> {
+    lock::check()?;
+    _import_xml_files(paths, true)
+}
+
+// Workers and reported days from earlier T1042E files, files already imported are skipped
+#[flutter_rust_bridge::frb(sync)]
+pub fn import_xml_files(paths: Vec<String>) -> Result<XmlImportSummary, String> {
+    lock::check()?;
+    _import_xml_files(paths, false)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_storage_encrypted() -> Result<bool, String> {
+    storage::is_enabled()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_storage_unlocked() -> bool {
+    storage::is_unlocked()
+}
+
+// Needs to be called after init_app when the storage is encrypted
+fn _unlock_storage(passphrase: String) -> Result<(), String> {
+    storage::unlock(&passphrase)?;
+    *DB.get().unwrap().write().unwrap() = Db::open()?;
+    events::emit(DbEvent::Reloaded);
+    Ok(())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn unlock_storage(passphrase: String) -> Result<(), String> {
+    _unlock_storage(passphrase)
+}
+
+fn _enable_storage_encryption(passphrase: String) -> Result<(), String> {
+    // Block writes while files are rewritten
+    let _db = DB.get().unwrap().write().unwrap();
+    storage::enable(&passphrase)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn enable_storage_encryption(passphrase: String) -> Result<(), String> {
+    lock::check()?;
+    _enable_storage_encryption(passphrase)
+}
+
+fn _change_storage_passphrase(
+    old_passphrase: String,
+    new_passphrase: String,
+) -> Result<(), String> {
+    let _db = DB.get().unwrap().write().unwrap();
+    storage::change_passphrase(&old_passphrase, &new_passphrase)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn change_storage_passphrase(
+    old_passphrase: String,
+    new_passphrase: String,
+) -> Result<(), String> {
+    lock::check()?;
+    _change_storage_passphrase(old_passphrase, new_passphrase)
+}
+
+fn _disable_storage_encryption(passphrase: String) -> Result<(), String> {
+    let _db = DB.get().unwrap().write().unwrap();
+    storage::disable(&passphrase)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn disable_storage_encryption(passphrase: String) -> Result<(), String> {
+    lock::check()?;
+    _disable_storage_encryption(passphrase)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn has_pin() -> Result<bool, String> {
+    lock::has_pin()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_locked() -> bool {
+    lock::is_locked()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn lock_app() -> Result<(), String> {
+    lock::lock()
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn unlock_app(pin: String) -> Result<(), String> {
+    lock::unlock(&pin)?;
+    // Changes made while locked were sent as ids only
+    events::emit(DbEvent::Reloaded);
+    Ok(())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_pin(current_pin: Option<String>, new_pin: String) -> Result<(), String> {
+    lock::set_pin(current_pin, &new_pin)
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn remove_pin(pin: String) -> Result<(), String> {
+    lock::remove_pin(&pin)
 }
 
 fn _init() {
     COUNTER.set(RwLock::new(0)).unwrap();
-    DB.set(RwLock::new(Db::open().unwrap())).unwrap();
+    let _ = lock::init();
+    let _ = backup::ensure_daily_snapshot();
+    // Encrypted storage is loaded by unlock_storage
+    let db = if storage::is_unlocked() {
+        Db::open().unwrap()
+    } else {
+        Db::empty()
+    };
+    DB.set(RwLock::new(db)).unwrap();
+    // Other instances may share the data folder
+    if let Ok(dir) = get_workers_dir() {
+        let _ = watcher::start(dir, |path| {
+            let _ = DB.get().unwrap().write().unwrap().reload_worker_file(path);
+        });
+    }
 }
 
 #[flutter_rust_bridge::frb(init)]
@@ -0,0 +1,494 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use calamine::{open_workbook_auto, Data, Reader};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::text::{collation_key, fold};
+
+use super::worker::{get_data_dir, index_key, Db, Worker};
+
+pub(crate) const IMPORT_PROFILES_FILE: &str = "import_profiles.json";
+
+fn get_profiles_path() -> Result<PathBuf, String> {
+    Ok(get_data_dir()?.join(IMPORT_PROFILES_FILE))
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum WorkerField {
+    Name,
+    Taj,
+    Taxnumber,
+    Mothersname,
+    Birthdate,
+    Birthplace,
+    Zip,
+    City,
+    Street,
+    // Comma separated
+    Tags,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ColumnMapping {
+    // Header of the column in the first row, accent and case insensitive
+    pub header: String,
+    pub field: WorkerField,
+}
+
+// Column mapping of a spreadsheet layout, saved for later imports
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImportProfile {
+    pub name: String,
+    pub columns: Vec<ColumnMapping>,
+}
+
+// Sorted by name
+pub(crate) fn load_profiles() -> Result<Vec<ImportProfile>, String> {
+    let path = get_profiles_path()?;
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let buffer = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    let mut profiles: Vec<ImportProfile> =
+        serde_json::from_str(&buffer).map_err(|e| e.to_string())?;
+    profiles.sort_by_cached_key(|p| collation_key(&p.name));
+    Ok(profiles)
+}
+
+fn save_profiles(profiles: &[ImportProfile]) -> Result<(), String> {
+    let buffer = serde_json::to_string(profiles).map_err(|e| e.to_string())?;
+    std::fs::write(get_profiles_path()?, buffer).map_err(|e| e.to_string())
+}
+
+// Replaces the profile with the same name
+pub(crate) fn save_profile(mut profile: ImportProfile) -> Result<ImportProfile, String> {
+    profile.name = profile.name.trim().to_string();
+    if profile.name.is_empty() {
+        return Err("Profile name is required".into());
+    }
+    let mut profiles = load_profiles()?;
+    profiles.retain(|p| p.name != profile.name);
+    profiles.push(profile.clone());
+    save_profiles(&profiles)?;
+    Ok(profile)
+}
+
+pub(crate) fn delete_profile(name: &str) -> Result<(), String> {
+    let mut profiles = load_profiles()?;
+    profiles.retain(|p| p.name != name);
+    save_profiles(&profiles)
+}
+
+// Numbers without a fraction are written as integers, so TAJ and zip columns
+// stored as numbers come out as typed
+fn cell_text(cell: &Data) -> String {
+    match cell {
+        Data::Float(f) if f.fract() == 0.0 => format!("{}", *f as i64),
+        Data::Int(i) => i.to_string(),
+        Data::DateTime(d) => d
+            .as_datetime()
+            .map(|d| d.date().format("%Y-%m-%d").to_string())
+            .unwrap_or_default(),
+        Data::Empty | Data::Error(_) => String::new(),
+        _ => cell.to_string(),
+    }
+}
+
+// A TAJ stored as a number lost its leading zeros
+fn taj_text(cell: &Data) -> String {
+    match cell {
+        Data::Float(f) if f.fract() == 0.0 && *f >= 0.0 => format!("{:09}", *f as i64),
+        Data::Int(i) if *i >= 0 => format!("{:09}", i),
+        _ => cell_text(cell),
+    }
+}
+
+fn read_csv(path: &str) -> Result<Vec<Vec<Data>>, String> {
+    let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+    let content = String::from_utf8_lossy(&bytes);
+    let content = content.trim_start_matches('\u{feff}');
+    // Excel saves with semicolons in Hungarian locale
+    let first_line = content.lines().next().unwrap_or_default();
+    let delimiter = if first_line.matches(';').count() > first_line.matches(',').count() {
+        b';'
+    } else {
+        b','
+    };
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(content.as_bytes());
+    reader
+        .records()
+        .map(|r| {
+            r.map(|r| r.iter().map(|c| Data::String(c.to_string())).collect())
+                .map_err(|e| e.to_string())
+        })
+        .collect()
+}
+
+fn read_workbook(path: &str) -> Result<Vec<Vec<Data>>, String> {
+    let mut workbook = open_workbook_auto(path).map_err(|e| e.to_string())?;
+    let range = workbook
+        .worksheet_range_at(0)
+        .ok_or("The workbook has no sheets")?
+        .map_err(|e| e.to_string())?;
+    Ok(range.rows().map(|row| row.to_vec()).collect())
+}
+
+// Rows of a CSV file or the first sheet of a workbook, CSV cells are all text
+fn read_table(path: &str) -> Result<Vec<Vec<Data>>, String> {
+    let is_csv = PathBuf::from(path)
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("csv") || e.eq_ignore_ascii_case("txt"));
+    if is_csv {
+        read_csv(path)
+    } else {
+        read_workbook(path)
+    }
+}
+
+// First row of the file, to build a profile from
+pub(crate) fn read_headers(path: &str) -> Result<Vec<String>, String> {
+    Ok(read_table(path)?
+        .into_iter()
+        .next()
+        .unwrap_or_default()
+        .iter()
+        .map(|h| cell_text(h).trim().to_string())
+        .collect())
+}
+
+// Accepts the usual Hungarian forms, like 1990.05.04.
+fn parse_birthdate(value: &str) -> String {
+    let value = value.trim().trim_end_matches('.');
+    ["%Y-%m-%d", "%Y.%m.%d", "%Y. %m. %d", "%Y/%m/%d"]
+        .iter()
+        .find_map(|f| NaiveDate::parse_from_str(value, f).ok())
+        .map_or_else(|| value.to_string(), |d| d.format("%Y-%m-%d").to_string())
+}
+
+fn set_field(worker: &mut Worker, field: WorkerField, value: &str) {
+    let value = value.trim();
+    match field {
+        WorkerField::Name => worker.name = value.to_string(),
+        WorkerField::Taj => worker.taj = value.to_string(),
+        WorkerField::Taxnumber => worker.taxnumber = value.to_string(),
+        WorkerField::Mothersname => worker.mothersname = value.to_string(),
+        WorkerField::Birthdate => worker.birthdate = parse_birthdate(value),
+        WorkerField::Birthplace => worker.birthplace = value.to_string(),
+        WorkerField::Zip => worker.zip = value.to_string(),
+        WorkerField::City => worker.city = value.to_string(),
+        WorkerField::Street => worker.street = value.to_string(),
+        WorkerField::Tags => {
+            worker.tags = value
+                .split(',')
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImportRow {
+    // Line number in the file, the header is line 1
+    pub row: u32,
+    pub worker: Worker,
+    // Imported only if empty
+    pub errors: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SpreadsheetImport {
+    pub rows: Vec<ImportRow>,
+    // Number of rows without errors
+    pub valid: u32,
+}
+
+impl SpreadsheetImport {
+    // Workers of the rows without errors
+    pub(crate) fn valid_workers(&self) -> Vec<Worker> {
+        self.rows
+            .iter()
+            .filter(|r| r.errors.is_empty())
+            .map(|r| r.worker.clone())
+            .collect()
+    }
+}
+
+// Every row mapped and checked against the fields rules, the database and the earlier rows
+pub(crate) fn read_spreadsheet(
+    path: &str,
+    profile: &ImportProfile,
+    db: &Db,
+) -> Result<SpreadsheetImport, String> {
+    let mut table = read_table(path)?.into_iter();
+    let headers = table
+        .next()
+        .ok_or("The file is empty")?
+        .iter()
+        .map(|h| fold(cell_text(h).trim()))
+        .collect::<Vec<String>>();
+    let mut columns = vec![];
+    for mapping in &profile.columns {
+        let index = headers
+            .iter()
+            .position(|h| *h == fold(mapping.header.trim()))
+            .ok_or_else(|| format!("Column not found: {}", mapping.header))?;
+        columns.push((index, mapping.field));
+    }
+    let mut rows = vec![];
+    // TAJ and tax ID keys of the earlier rows, with their line
+    let mut seen_taj: HashMap<String, u32> = HashMap::new();
+    let mut seen_taxnumber: HashMap<String, u32> = HashMap::new();
+    for (i, cells) in table.enumerate() {
+        let row = i as u32 + 2;
+        if cells.iter().all(|c| cell_text(c).trim().is_empty()) {
+            continue;
+        }
+        let mut worker = Worker {
+            id: Uuid::new_v4(),
+            ..Default::default()
+        };
+        for (index, field) in &columns {
+            let value = cells.get(*index).map_or_else(String::new, |c| match field {
+                WorkerField::Taj => taj_text(c),
+                _ => cell_text(c),
+            });
+            set_field(&mut worker, *field, &value);
+        }
+        let mut errors = worker
+            .validation_errors()
+            .iter()
+            .map(|f| format!("Invalid field: {}", f))
+            .collect::<Vec<String>>();
+        if let Some(other) = db.find_duplicates(&worker).first() {
+            errors.push(format!(
+                "A worker with the same TAJ or tax ID already exists: {}",
+                other.name
+            ));
+        }
+        for (seen, value, message) in [
+            (&mut seen_taj, &worker.taj, "Same TAJ as in row"),
+            (
+                &mut seen_taxnumber,
+                &worker.taxnumber,
+                "Same tax ID as in row",
+            ),
+        ] {
+            if let Some(key) = index_key(value) {
+                match seen.get(&key) {
+                    Some(other) => errors.push(format!("{} {}", message, other)),
+                    None => {
+                        seen.insert(key, row);
+                    }
+                }
+            }
+        }
+        rows.push(ImportRow {
+            row,
+            worker,
+            errors,
+        });
+    }
+    Ok(SpreadsheetImport {
+        valid: rows.iter().filter(|r| r.errors.is_empty()).count() as u32,
+        rows,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_xlsxwriter::Workbook;
+
+    use super::*;
+
+    fn temp_file(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("import-{}", Uuid::new_v4().as_simple()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir.join(name)
+    }
+
+    fn write_csv(content: &str) -> String {
+        let path = temp_file("munkasok.csv");
+        std::fs::write(&path, content).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    fn profile(columns: &[(&str, WorkerField)]) -> ImportProfile {
+        ImportProfile {
+            name: "Bérszámfejtés".to_string(),
+            columns: columns
+                .iter()
+                .map(|(header, field)| ColumnMapping {
+                    header: header.to_string(),
+                    field: *field,
+                })
+                .collect(),
+        }
+    }
+
+    fn full_profile() -> ImportProfile {
+        profile(&[
+            ("nev", WorkerField::Name),
+            ("taj szam", WorkerField::Taj),
+            ("adoazonosito", WorkerField::Taxnumber),
+            ("anyja neve", WorkerField::Mothersname),
+            ("szuletesi datum", WorkerField::Birthdate),
+            ("szuletesi hely", WorkerField::Birthplace),
+            ("irsz", WorkerField::Zip),
+            ("varos", WorkerField::City),
+            ("utca", WorkerField::Street),
+            ("cimkek", WorkerField::Tags),
+        ])
+    }
+
+    const HEADER: &str = "\u{feff}Név;TAJ szám;Adóazonosító;Anyja neve;Születési dátum;Születési hely;Irsz;Város;Utca;Címkék\n";
+
+    fn line(name: &str, taj: &str, taxnumber: &str) -> String {
+        format!(
+            "{};{};{};Kiss Mária;1990.05.04.;Pécs;7621;Pécs;Fő utca 1.;kertész, sofőr\n",
+            name, taj, taxnumber
+        )
+    }
+
+    #[test]
+    fn rows_are_mapped_and_checked() {
+        let mut db = Db::open().unwrap();
+        db.add_new_worker(Worker {
+            name: "Régi Rita".to_string(),
+            taj: "111222333".to_string(),
+            ..Worker::default()
+        })
+        .unwrap();
+        let content = [
+            HEADER.to_string(),
+            line("Kiss Anna", "123 456 788", "12345678-1-02"),
+            ";;;;;;;;;\n".to_string(),
+            line("Nagy Béla", "12345", "12345678-1-03"),
+            line("Régi Rita", "111222333", "12345678-1-04"),
+            line("Kiss Anna", "123456788", "12345678-1-05"),
+        ]
+        .concat();
+        let import = read_spreadsheet(&write_csv(&content), &full_profile(), &db).unwrap();
+        assert_eq!(import.valid, 1);
+        let rows = import.rows;
+        assert_eq!(
+            rows.iter().map(|r| r.row).collect::<Vec<u32>>(),
+            vec![2, 4, 5, 6]
+        );
+        let anna = &rows[0].worker;
+        assert_eq!(anna.birthdate, "1990-05-04");
+        assert_eq!(anna.tags, vec!["kertész", "sofőr"]);
+        assert_eq!(rows[1].errors, vec!["Invalid field: taj"]);
+        assert_eq!(
+            rows[2].errors,
+            vec!["A worker with the same TAJ or tax ID already exists: Régi Rita"]
+        );
+        assert_eq!(rows[3].errors, vec!["Same TAJ as in row 2"]);
+        let valid = SpreadsheetImport { rows, valid: 1 }.valid_workers();
+        assert_eq!(valid.len(), 1);
+        assert_eq!(valid[0].name, "Kiss Anna");
+    }
+
+    #[test]
+    fn taj_and_tax_id_are_compared_separately() {
+        let db = Db::open().unwrap();
+        let content = [
+            "Név,TAJ szám,Adóazonosító\n",
+            "Kiss Anna,123456789,\n",
+            "Nagy Béla,,123456789\n",
+            "Tóth Géza,,123-456-789\n",
+        ]
+        .concat();
+        let profile = profile(&[
+            ("Név", WorkerField::Name),
+            ("TAJ szám", WorkerField::Taj),
+            ("Adóazonosító", WorkerField::Taxnumber),
+        ]);
+        let import = read_spreadsheet(&write_csv(&content), &profile, &db).unwrap();
+        let same = |i: usize| {
+            import.rows[i]
+                .errors
+                .iter()
+                .filter(|e| e.starts_with("Same"))
+                .cloned()
+                .collect::<Vec<String>>()
+        };
+        assert!(same(0).is_empty());
+        assert!(same(1).is_empty());
+        assert_eq!(same(2), vec!["Same tax ID as in row 3"]);
+    }
+
+    #[test]
+    fn numeric_taj_cells_keep_the_leading_zeros() {
+        let path = temp_file("munkasok.xlsx");
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet();
+        for (col, header) in ["Név", "TAJ szám", "Irsz"].iter().enumerate() {
+            sheet.write_string(0, col as u16, *header).unwrap();
+        }
+        sheet.write_string(1, 0, "Kiss Anna").unwrap();
+        sheet.write_number(1, 1, 12345678.0).unwrap();
+        sheet.write_number(1, 2, 7621.0).unwrap();
+        sheet.write_string(2, 0, "Nagy Béla").unwrap();
+        sheet.write_string(2, 1, "123 456 789").unwrap();
+        workbook.save(&path).unwrap();
+        let path = path.to_string_lossy().to_string();
+        assert_eq!(
+            read_headers(&path).unwrap(),
+            vec!["Név", "TAJ szám", "Irsz"]
+        );
+        let profile = profile(&[
+            ("Név", WorkerField::Name),
+            ("TAJ szám", WorkerField::Taj),
+            ("Irsz", WorkerField::Zip),
+        ]);
+        let import = read_spreadsheet(&path, &profile, &Db::open().unwrap()).unwrap();
+        assert_eq!(import.rows[0].worker.taj, "012345678");
+        assert_eq!(import.rows[0].worker.zip, "7621");
+        assert_eq!(import.rows[1].worker.taj, "123 456 789");
+    }
+
+    #[test]
+    fn mapped_columns_have_to_exist() {
+        let path = write_csv("Név;Város\nKiss Anna;Pécs\n");
+        let res = read_spreadsheet(&path, &full_profile(), &Db::open().unwrap());
+        assert_eq!(res.err(), Some("Column not found: taj szam".to_string()));
+    }
+
+    #[test]
+    fn profiles_are_replaced_by_name() {
+        save_profile(profile(&[("Név", WorkerField::Name)])).unwrap();
+        save_profile(ImportProfile {
+            name: " Adatlap ".to_string(),
+            ..full_profile()
+        })
+        .unwrap();
+        save_profile(profile(&[
+            ("Név", WorkerField::Name),
+            ("Város", WorkerField::City),
+        ]))
+        .unwrap();
+        assert!(save_profile(ImportProfile {
+            name: "".to_string(),
+            ..full_profile()
+        })
+        .is_err());
+        let profiles = load_profiles().unwrap();
+        assert_eq!(
+            profiles
+                .iter()
+                .map(|p| p.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["Adatlap", "Bérszámfejtés"]
+        );
+        assert_eq!(profiles[1].columns.len(), 2);
+        delete_profile("Adatlap").unwrap();
+        assert_eq!(load_profiles().unwrap().len(), 1);
+    }
+}
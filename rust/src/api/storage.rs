@@ -0,0 +1,495 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::crypto::{decrypt, derive_key, encrypt, random_salt, Key};
+
+use super::{
+    audit::AUDIT_LOG,
+    backup,
+    draft::DRAFTS_DIR,
+    export_history::EXPORTS_DIR,
+    worker::{get_data_dir, WORKERS_DIR},
+};
+
+// Kept outside of snapshots and backup files, as it belongs to the local key
+pub(crate) const ENCRYPTION_FILE: &str = "encryption.json";
+// Exists while the files are rewritten with a new key, replaces ENCRYPTION_FILE when done
+pub(crate) const PENDING_ENCRYPTION_FILE: &str = "encryption.pending.json";
+
+// Header of encrypted storage files
+const MAGIC: &[u8; 4] = b"DWS1";
+// Prefix of encrypted audit log lines
+const LINE_PREFIX: &str = "DWS1:";
+// Encrypted with the key to verify the passphrase
+const CHECK_VALUE: &[u8] = b"dailyworker";
+
+#[cfg(not(test))]
+static KEY: RwLock<Option<Key>> = RwLock::new(None);
+
+// Tests run in parallel, each with its own data folder and key
+#[cfg(test)]
+thread_local! {
+    static KEY: RwLock<Option<Key>> = const { RwLock::new(None) };
+}
+
+#[cfg(not(test))]
+fn key() -> Option<Key> {
+    *KEY.read().unwrap()
+}
+
+#[cfg(not(test))]
+fn set_key(key: Option<Key>) {
+    *KEY.write().unwrap() = key;
+}
+
+#[cfg(test)]
+fn key() -> Option<Key> {
+    KEY.with(|k| *k.read().unwrap())
+}
+
+#[cfg(test)]
+fn set_key(key: Option<Key>) {
+    KEY.with(|k| *k.write().unwrap() = key);
+}
+
+fn get_encryption_path() -> Result<PathBuf, String> {
+    Ok(get_data_dir()?.join(ENCRYPTION_FILE))
+}
+
+fn get_pending_path() -> Result<PathBuf, String> {
+    Ok(get_data_dir()?.join(PENDING_ENCRYPTION_FILE))
+}
+
+// Written next to it first, so a failure never leaves a broken file
+fn write_json(path: &Path, value: &impl Serialize) -> Result<(), String> {
+    let buffer = serde_json::to_string(value).map_err(|e| e.to_string())?;
+    let tmp_path = path.with_extension("tmp");
+    std::fs::write(&tmp_path, buffer).map_err(|e| e.to_string())?;
+    std::fs::rename(&tmp_path, path).map_err(|e| e.to_string())
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<Option<T>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let buffer = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&buffer)
+        .map(Some)
+        .map_err(|e| e.to_string())
+}
+
+fn to_key(data: &[u8]) -> Result<Key, String> {
+    Key::try_from(data).map_err(|_| "Invalid key".to_string())
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct EncryptionConfig {
+    salt: Vec<u8>,
+    check: Vec<u8>,
+}
+
+impl EncryptionConfig {
+    fn new(passphrase: &str) -> Result<(Self, Key), String> {
+        if passphrase.is_empty() {
+            return Err("Passphrase is required".into());
+        }
+        let salt = random_salt();
+        let key = derive_key(passphrase, &salt)?;
+        let check = encrypt(&key, CHECK_VALUE)?;
+        Ok((Self { salt, check }, key))
+    }
+    fn load() -> Result<Option<Self>, String> {
+        read_json(&get_encryption_path()?)
+    }
+    fn save(&self) -> Result<(), String> {
+        write_json(&get_encryption_path()?, &self)
+    }
+    fn key(&self, passphrase: &str) -> Result<Key, String> {
+        let key = derive_key(passphrase, &self.salt)?;
+        match decrypt(&key, &self.check) {
+            Ok(check) if check == CHECK_VALUE => Ok(key),
+            _ => Err("Wrong passphrase".into()),
+        }
+    }
+}
+
+// A key change in progress, kept until every file is rewritten.
+// Each key is stored encrypted with the other one, so either passphrase can finish it.
+#[derive(Serialize, Deserialize, Debug)]
+struct PendingConfig {
+    config: EncryptionConfig,
+    // Previous key encrypted with the new one, None when enabling
+    previous: Option<Vec<u8>>,
+    // New key encrypted with the previous one
+    next: Option<Vec<u8>>,
+}
+
+impl PendingConfig {
+    fn load() -> Result<Option<Self>, String> {
+        read_json(&get_pending_path()?)
+    }
+    fn save(&self) -> Result<(), String> {
+        write_json(&get_pending_path()?, &self)
+    }
+    // Previous and new key from either passphrase
+    fn keys(&self, passphrase: &str) -> Result<(Option<Key>, Key), String> {
+        if let Ok(key) = self.config.key(passphrase) {
+            let previous = match &self.previous {
+                Some(previous) => Some(to_key(&decrypt(&key, previous)?)?),
+                None => None,
+            };
+            return Ok((previous, key));
+        }
+        let previous = EncryptionConfig::load()?
+            .ok_or("Wrong passphrase")?
+            .key(passphrase)?;
+        let next = self.next.as_ref().ok_or("Wrong passphrase")?;
+        Ok((Some(previous), to_key(&decrypt(&previous, next)?)?))
+    }
+}
+
+pub(crate) fn is_enabled() -> Result<bool, String> {
+    Ok(get_encryption_path()?.exists() || get_pending_path()?.exists())
+}
+
+pub(crate) fn is_unlocked() -> bool {
+    key().is_some() || !is_enabled().unwrap_or(false)
+}
+
+// Finishes an interrupted passphrase change first
+pub(crate) fn unlock(passphrase: &str) -> Result<(), String> {
+    if let Some(pending) = PendingConfig::load()? {
+        let (previous, key) = pending.keys(passphrase)?;
+        return finish(&pending.config, previous.as_ref(), key);
+    }
+    let config = EncryptionConfig::load()?.ok_or("Storage is not encrypted")?;
+    set_key(Some(config.key(passphrase)?));
+    Ok(())
+}
+
+pub(crate) fn enable(passphrase: &str) -> Result<(), String> {
+    if is_enabled()? {
+        return Err("Storage is already encrypted".into());
+    }
+    let (config, key) = EncryptionConfig::new(passphrase)?;
+    let pending = PendingConfig {
+        config,
+        previous: None,
+        next: None,
+    };
+    pending.save()?;
+    finish(&pending.config, None, key)
+}
+
+pub(crate) fn change_passphrase(old: &str, new: &str) -> Result<(), String> {
+    let old_key = EncryptionConfig::load()?
+        .ok_or("Storage is not encrypted")?
+        .key(old)?;
+    let (config, key) = EncryptionConfig::new(new)?;
+    let pending = PendingConfig {
+        config,
+        previous: Some(encrypt(&key, &old_key)?),
+        next: Some(encrypt(&old_key, &key)?),
+    };
+    pending.save()?;
+    finish(&pending.config, Some(&old_key), key)
+}
+
+// The new config takes over only when every file is rewritten
+fn finish(config: &EncryptionConfig, previous: Option<&Key>, key: Key) -> Result<(), String> {
+    rewrite_all(previous, Some(&key))?;
+    config.save()?;
+    std::fs::remove_file(get_pending_path()?).map_err(|e| e.to_string())?;
+    set_key(Some(key));
+    Ok(())
+}
+
+// Files already in plain form are kept, so it can be run again after a failure
+pub(crate) fn disable(passphrase: &str) -> Result<(), String> {
+    let key = EncryptionConfig::load()?
+        .ok_or("Storage is not encrypted")?
+        .key(passphrase)?;
+    rewrite_all(Some(&key), None)?;
+    std::fs::remove_file(get_encryption_path()?).map_err(|e| e.to_string())?;
+    set_key(None);
+    Ok(())
+}
+
+// Key used for writing, fails if encrypted but not unlocked yet
+fn current_key() -> Result<Option<Key>, String> {
+    if let Some(key) = key() {
+        return Ok(Some(key));
+    }
+    if is_enabled()? {
+        return Err("Storage is locked".into());
+    }
+    Ok(None)
+}
+
+pub(crate) fn seal(data: &[u8]) -> Result<Vec<u8>, String> {
+    seal_with(current_key()?.as_ref(), data)
+}
+
+pub(crate) fn unseal(data: &[u8]) -> Result<Vec<u8>, String> {
+    unseal_with(key().as_slice(), data)
+}
+
+pub(crate) fn seal_line(line: &str) -> Result<String, String> {
+    seal_line_with(current_key()?.as_ref(), line)
+}
+
+pub(crate) fn unseal_line(line: &str) -> Result<String, String> {
+    unseal_line_with(key().as_slice(), line)
+}
+
+// Data folder file given by its relative path, encrypted with the current key
+pub(crate) fn seal_file(name: &str, data: Vec<u8>) -> Result<Vec<u8>, String> {
+    reseal_file(name, data, &[], current_key()?.as_ref())
+}
+
+// Data folder file given by its relative path, in plain form
+pub(crate) fn unseal_file(name: &str, data: Vec<u8>) -> Result<Vec<u8>, String> {
+    reseal_file(name, data, key().as_slice(), None)
+}
+
+fn seal_with(key: Option<&Key>, data: &[u8]) -> Result<Vec<u8>, String> {
+    match key {
+        Some(key) => Ok([MAGIC.as_slice(), &encrypt(key, data)?].concat()),
+        None => Ok(data.to_vec()),
+    }
+}
+
+// Plain data is returned as it is, encrypted data is opened with the first matching key
+fn unseal_with(keys: &[Key], data: &[u8]) -> Result<Vec<u8>, String> {
+    match data.strip_prefix(MAGIC.as_slice()) {
+        Some(data) => decrypt_with_any(keys, data),
+        None => Ok(data.to_vec()),
+    }
+}
+
+fn decrypt_with_any(keys: &[Key], data: &[u8]) -> Result<Vec<u8>, String> {
+    let mut res = Err("Storage is locked".to_string());
+    for key in keys {
+        res = decrypt(key, data);
+        if res.is_ok() {
+            break;
+        }
+    }
+    res
+}
+
+fn seal_line_with(key: Option<&Key>, line: &str) -> Result<String, String> {
+    match key {
+        Some(key) => Ok(format!(
+            "{}{}",
+            LINE_PREFIX,
+            STANDARD.encode(encrypt(key, line.as_bytes())?)
+        )),
+        None => Ok(line.to_string()),
+    }
+}
+
+fn unseal_line_with(keys: &[Key], line: &str) -> Result<String, String> {
+    match line.strip_prefix(LINE_PREFIX) {
+        Some(data) => {
+            let data = STANDARD.decode(data).map_err(|e| e.to_string())?;
+            let line = decrypt_with_any(keys, &data)?;
+            String::from_utf8(line).map_err(|e| e.to_string())
+        }
+        None => Ok(line.to_string()),
+    }
+}
+
+fn reseal_file(
+    name: &str,
+    data: Vec<u8>,
+    from: &[Key],
+    to: Option<&Key>,
+) -> Result<Vec<u8>, String> {
+    if name == AUDIT_LOG {
+        let content = String::from_utf8(data).map_err(|e| e.to_string())?;
+        let mut res = String::new();
+        for line in content.lines().filter(|l| !l.trim().is_empty()) {
+            res.push_str(&seal_line_with(to, &unseal_line_with(from, line)?)?);
+            res.push('\n');
+        }
+        return Ok(res.into_bytes());
+    }
+    if [WORKERS_DIR, DRAFTS_DIR, EXPORTS_DIR]
+        .iter()
+        .any(|dir| name.starts_with(&format!("{}/", dir)))
+    {
+        return seal_with(to, &unseal_with(from, &data)?);
+    }
+    Ok(data)
+}
+
+// Re-encrypt the data folder and every snapshot.
+// Files already rewritten open with the new key, so it can be run again after a failure.
+fn rewrite_all(from: Option<&Key>, to: Option<&Key>) -> Result<(), String> {
+    let keys = [to, from]
+        .into_iter()
+        .flatten()
+        .copied()
+        .collect::<Vec<Key>>();
+    let transform = |name: &str, data: Vec<u8>| reseal_file(name, data, &keys, to);
+    backup::rewrite_data_dir(&transform)?;
+    backup::rewrite_snapshots(&transform)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{
+        audit::read_audit_log,
+        backup::{create_snapshot, list_snapshots, restore_snapshot, SnapshotReason},
+        worker::{get_worker_path, Db, Worker},
+    };
+
+    fn add_worker(taj: &str) -> Worker {
+        let worker = Worker {
+            name: "Szabó Éva".to_string(),
+            taj: taj.to_string(),
+            ..Worker::default()
+        };
+        Db::open().unwrap().add_new_worker(worker.clone()).unwrap();
+        worker
+    }
+
+    fn raw_worker(worker: &Worker) -> Vec<u8> {
+        std::fs::read(get_worker_path(worker.id).unwrap()).unwrap()
+    }
+
+    fn raw_audit_log() -> String {
+        std::fs::read_to_string(get_data_dir().unwrap().join(AUDIT_LOG)).unwrap()
+    }
+
+    #[test]
+    fn enable_encrypts_the_data_folder() {
+        let worker = add_worker("123456788");
+        enable("jelszo").unwrap();
+        assert!(raw_worker(&worker).starts_with(MAGIC));
+        assert!(!raw_audit_log().contains("123456788"));
+        assert_eq!(Db::open().unwrap().workers().count(), 1);
+        assert_eq!(read_audit_log(None, None, None).unwrap().len(), 1);
+        assert!(enable("masik").is_err());
+    }
+
+    #[test]
+    fn locked_until_the_passphrase_is_given() {
+        add_worker("123456788");
+        enable("jelszo").unwrap();
+        set_key(None);
+        assert!(!is_unlocked());
+        assert!(Db::open().is_err());
+        assert!(unlock("rossz").is_err());
+        unlock("jelszo").unwrap();
+        assert_eq!(Db::open().unwrap().workers().count(), 1);
+    }
+
+    #[test]
+    fn change_passphrase_rewrites_snapshots() {
+        let worker = add_worker("123456788");
+        enable("jelszo").unwrap();
+        let snapshot = create_snapshot(SnapshotReason::Manual).unwrap();
+        assert!(change_passphrase("rossz", "uj").is_err());
+        change_passphrase("jelszo", "uj").unwrap();
+        set_key(None);
+        assert!(unlock("jelszo").is_err());
+        unlock("uj").unwrap();
+        restore_snapshot(&snapshot.id).unwrap();
+        assert!(Db::open().unwrap().get_by_id(worker.id).is_some());
+    }
+
+    #[test]
+    fn disable_leaves_plain_files() {
+        let worker = add_worker("123456788");
+        enable("jelszo").unwrap();
+        create_snapshot(SnapshotReason::Manual).unwrap();
+        assert!(disable("rossz").is_err());
+        disable("jelszo").unwrap();
+        assert!(!is_enabled().unwrap());
+        assert!(!raw_worker(&worker).starts_with(MAGIC));
+        assert!(raw_audit_log().contains("123456788"));
+        assert!(!list_snapshots().unwrap().is_empty());
+        assert_eq!(Db::open().unwrap().workers().count(), 1);
+    }
+
+    // The pending config is written and the first worker file rewritten when the app stops
+    fn interrupted_change(first: &Worker) {
+        let old_key = key().unwrap();
+        let (config, new_key) = EncryptionConfig::new("uj").unwrap();
+        PendingConfig {
+            config,
+            previous: Some(encrypt(&new_key, &old_key).unwrap()),
+            next: Some(encrypt(&old_key, &new_key).unwrap()),
+        }
+        .save()
+        .unwrap();
+        let path = get_worker_path(first.id).unwrap();
+        let name = format!(
+            "{}/{}",
+            WORKERS_DIR,
+            path.file_name().unwrap().to_str().unwrap()
+        );
+        let data = reseal_file(&name, raw_worker(first), &[old_key], Some(&new_key)).unwrap();
+        std::fs::write(&path, data).unwrap();
+        set_key(None);
+    }
+
+    #[test]
+    fn interrupted_change_finishes_with_the_new_passphrase() {
+        let first = add_worker("123456788");
+        add_worker("987654321");
+        enable("jelszo").unwrap();
+        interrupted_change(&first);
+        assert!(is_enabled().unwrap() && !is_unlocked());
+        unlock("uj").unwrap();
+        assert!(!get_pending_path().unwrap().exists());
+        assert_eq!(Db::open().unwrap().workers().count(), 2);
+        set_key(None);
+        assert!(unlock("jelszo").is_err());
+        unlock("uj").unwrap();
+    }
+
+    #[test]
+    fn interrupted_change_finishes_with_the_old_passphrase() {
+        let first = add_worker("123456788");
+        add_worker("987654321");
+        enable("jelszo").unwrap();
+        interrupted_change(&first);
+        unlock("jelszo").unwrap();
+        assert!(!get_pending_path().unwrap().exists());
+        assert_eq!(Db::open().unwrap().workers().count(), 2);
+        assert_eq!(read_audit_log(None, None, None).unwrap().len(), 2);
+        set_key(None);
+        unlock("uj").unwrap();
+    }
+
+    #[test]
+    fn interrupted_enable_is_locked_until_finished() {
+        let first = add_worker("123456788");
+        add_worker("987654321");
+        let (config, key) = EncryptionConfig::new("jelszo").unwrap();
+        PendingConfig {
+            config,
+            previous: None,
+            next: None,
+        }
+        .save()
+        .unwrap();
+        let path = get_worker_path(first.id).unwrap();
+        std::fs::write(&path, seal_with(Some(&key), &raw_worker(&first)).unwrap()).unwrap();
+        assert!(is_enabled().unwrap() && !is_unlocked());
+        assert!(unlock("rossz").is_err());
+        unlock("jelszo").unwrap();
+        assert!(get_encryption_path().unwrap().exists());
+        assert!(!raw_audit_log().contains("987654321"));
+        assert_eq!(Db::open().unwrap().workers().count(), 2);
+    }
+}
@@ -0,0 +1,197 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use uuid::Uuid;
+
+// Content hash of every worker file as we last saw or wrote it,
+// so our own writes are not reported as external changes
+static KNOWN: Mutex<Option<HashMap<Uuid, u64>>> = Mutex::new(None);
+static WATCHER: Mutex<Option<RecommendedWatcher>> = Mutex::new(None);
+
+fn content_hash(data: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    data.hash(&mut hasher);
+    hasher.finish()
+}
+
+pub(crate) fn remember(id: Uuid, data: &[u8]) {
+    KNOWN
+        .lock()
+        .unwrap()
+        .get_or_insert_with(HashMap::new)
+        .insert(id, content_hash(data));
+}
+
+pub(crate) fn forget(id: Uuid) {
+    if let Some(known) = KNOWN.lock().unwrap().as_mut() {
+        known.remove(&id);
+    }
+}
+
+pub(crate) fn is_known(id: Uuid, data: Option<&[u8]>) -> bool {
+    let known = KNOWN.lock().unwrap();
+    let hash = known.as_ref().and_then(|known| known.get(&id));
+    match data {
+        Some(data) => hash == Some(&content_hash(data)),
+        None => hash.is_none(),
+    }
+}
+
+// Worker ID from a worker file path
+pub(crate) fn worker_id(path: &Path) -> Option<Uuid> {
+    if path.extension()? != "json" {
+        return None;
+    }
+    Uuid::parse_str(path.file_stem()?.to_str()?).ok()
+}
+
+// Calls on_change with every added, changed or removed worker file
+pub(crate) fn start(
+    dir: PathBuf,
+    on_change: impl Fn(&Path) + Send + 'static,
+) -> Result<(), String> {
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        if let Ok(event) = res {
+            if matches!(
+                event.kind,
+                EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+            ) {
+                for path in event.paths.iter().filter(|p| worker_id(p).is_some()) {
+                    on_change(path);
+                }
+            }
+        }
+    })
+    .map_err(|e| e.to_string())?;
+    watcher
+        .watch(&dir, RecursiveMode::NonRecursive)
+        .map_err(|e| e.to_string())?;
+    *WATCHER.lock().unwrap() = Some(watcher);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{
+        events::{take_emitted, DbEvent},
+        worker::{get_worker_path, Db, Worker},
+    };
+
+    fn add_worker(db: &mut Db, name: &str) -> Worker {
+        let worker = Worker {
+            name: name.to_string(),
+            ..Worker::default()
+        };
+        db.add_new_worker(worker.clone()).unwrap();
+        take_emitted();
+        db.get_by_id(worker.id).unwrap().clone()
+    }
+
+    // As another instance would write it
+    fn write_elsewhere(worker: &Worker) -> PathBuf {
+        let path = get_worker_path(worker.id).unwrap();
+        std::fs::write(&path, serde_json::to_vec(worker).unwrap()).unwrap();
+        path
+    }
+
+    #[test]
+    fn worker_files_only() {
+        let id = Uuid::new_v4();
+        let path = PathBuf::from("workers").join(format!("{}.json", id.as_simple()));
+        assert_eq!(worker_id(&path), Some(id));
+        assert_eq!(worker_id(&path.with_extension("tmp")), None);
+        assert_eq!(worker_id(Path::new("workers/session.json")), None);
+    }
+
+    #[test]
+    fn own_writes_are_ignored() {
+        let mut db = Db::open().unwrap();
+        let anna = add_worker(&mut db, "Kiss Anna");
+        db.reload_worker_file(&get_worker_path(anna.id).unwrap())
+            .unwrap();
+        assert!(take_emitted().is_empty());
+    }
+
+    #[test]
+    fn external_changes_are_loaded() {
+        let mut db = Db::open().unwrap();
+        let anna = Worker {
+            name: "Kiss Anna".to_string(),
+            ..Worker::default()
+        };
+        db.reload_worker_file(&write_elsewhere(&anna)).unwrap();
+        assert!(db.get_by_id(anna.id).is_some());
+        assert!(matches!(&take_emitted()[..], [DbEvent::WorkerAdded { .. }]));
+        let path = write_elsewhere(&Worker {
+            city: "Pécs".to_string(),
+            ..anna.clone()
+        });
+        db.reload_worker_file(&path).unwrap();
+        assert_eq!(db.get_by_id(anna.id).unwrap().city, "Pécs");
+        let events = take_emitted();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], DbEvent::WorkerUpdated { worker } if worker.city == "Pécs"));
+    }
+
+    #[test]
+    fn local_edits_are_reported_as_conflicts() {
+        let mut db = Db::open().unwrap();
+        let anna = add_worker(&mut db, "Kiss Anna");
+        let local = db
+            .update_worker(Worker {
+                city: "Szeged".to_string(),
+                ..anna.clone()
+            })
+            .unwrap()
+            .clone();
+        take_emitted();
+        let path = write_elsewhere(&Worker {
+            city: "Pécs".to_string(),
+            ..local.clone()
+        });
+        db.reload_worker_file(&path).unwrap();
+        let events = take_emitted();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            &events[0],
+            DbEvent::Conflict { local: l, deleted: false } if l.city == "Szeged"
+        ));
+        assert!(matches!(&events[1], DbEvent::WorkerUpdated { .. }));
+        // Undo would overwrite the other instance's change
+        assert!(!db.history.can_undo());
+    }
+
+    #[test]
+    fn external_removal_is_loaded() {
+        let mut db = Db::open().unwrap();
+        let anna = Worker {
+            name: "Kiss Anna".to_string(),
+            ..Worker::default()
+        };
+        db.reload_worker_file(&write_elsewhere(&anna)).unwrap();
+        let edited = add_worker(&mut db, "Nagy Béla");
+        db.update_worker(Worker {
+            city: "Szeged".to_string(),
+            ..edited.clone()
+        })
+        .unwrap();
+        take_emitted();
+        for worker in [&anna, &edited] {
+            let path = get_worker_path(worker.id).unwrap();
+            std::fs::remove_file(&path).unwrap();
+            db.reload_worker_file(&path).unwrap();
+            assert!(db.get_by_id(worker.id).is_none());
+        }
+        let events = take_emitted();
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], DbEvent::WorkerRemoved { id } if id == anna.id));
+        assert!(matches!(events[1], DbEvent::Conflict { deleted: true, .. }));
+        assert!(matches!(events[2], DbEvent::WorkerRemoved { id } if id == edited.id));
+    }
+}
@@ -1,107 +1,693 @@
 use std::{
+    collections::{HashMap, HashSet},
     fs::File,
     io::{BufWriter, Read, Write},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
 use chrono::prelude::*;
-use dirs::home_dir;
 use flutter_rust_bridge::frb;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-fn get_workers_dir() -> Result<PathBuf, String> {
-    if let Some(dir) = home_dir() {
-        let data_dir = dir.join(".dailyworkerdata");
+use super::{
+    audit::{redact_audit_log, AuditAction, AuditEntry},
+    backup,
+    draft::redact_drafts,
+    events::{self, DbEvent},
+    export_history::redact_exports,
+    export_lock::WorkerLock,
+    history::{Change, History, HistoryAction, HistoryItem},
+    session::SelectionSession,
+    settings::Settings,
+    storage, watcher,
+};
+
+#[cfg(not(test))]
+fn data_dir() -> Option<PathBuf> {
+    Some(dirs::home_dir()?.join(".dailyworkerdata"))
+}
+
+// Tests run in parallel, each in its own data folder
+#[cfg(test)]
+thread_local! {
+    static TEST_DATA_DIR: PathBuf = std::env::temp_dir()
+        .join(format!("dailyworkerdata-test-{}", Uuid::new_v4().as_simple()));
+}
+
+#[cfg(test)]
+fn data_dir() -> Option<PathBuf> {
+    Some(TEST_DATA_DIR.with(PathBuf::clone))
+}
+
+pub(crate) fn get_data_dir() -> Result<PathBuf, String> {
+    if let Some(data_dir) = data_dir() {
         // Check if exist
         if !data_dir.exists() {
             // Create path if needed
             let _ = std::fs::create_dir_all(&data_dir);
         }
-        let workers_dir = data_dir.join("workers");
-        // Check if exist
-        if !workers_dir.exists() {
-            // Create path if needed
-            let _ = std::fs::create_dir_all(&workers_dir);
-        }
-        return Ok(workers_dir);
+        return Ok(data_dir);
     }
     Err("Error while getting context".into())
 }
 
+pub(crate) const WORKERS_DIR: &str = "workers";
+
+pub(crate) fn get_workers_dir() -> Result<PathBuf, String> {
+    let workers_dir = get_data_dir()?.join(WORKERS_DIR);
+    // Check if exist
+    if !workers_dir.exists() {
+        // Create path if needed
+        let _ = std::fs::create_dir_all(&workers_dir);
+    }
+    Ok(workers_dir)
+}
+
+pub(crate) fn get_worker_path(id: Uuid) -> Result<PathBuf, String> {
+    Ok(get_workers_dir()?.join(format!("{}.json", id.as_simple())))
+}
+
+// Dates cross the bridge and are stored in this format, like the birth date
+pub(crate) const DATE_FORMAT: &str = "%Y-%m-%d";
+
+pub(crate) fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value.trim(), DATE_FORMAT)
+        .map_err(|_| format!("Invalid date: {}", value))
+}
+
+pub(crate) fn format_date(date: NaiveDate) -> String {
+    date.format(DATE_FORMAT).to_string()
+}
+
+// Digits only, so formatting does not hide duplicates
+pub(crate) fn index_key(value: &str) -> Option<String> {
+    let key = value
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+    (!key.is_empty()).then_some(key)
+}
+
+// Held while a worker file is written, fails if the stored worker is not at
+// the given revision, None meaning it must not exist yet
+fn lock_revision(local: &Worker, revision: Option<u64>) -> Result<WorkerLock, UpdateError> {
+    let lock = WorkerLock::acquire(local.id)?;
+    let path = get_worker_path(local.id)?;
+    if !path.exists() {
+        return match revision {
+            Some(_) => Err("Worker was removed by someone else".to_string().into()),
+            None => Ok(lock),
+        };
+    }
+    let (remote, _) = Worker::read(&path)?;
+    if Some(remote.revision) != revision {
+        return Err(UpdateError::Conflict {
+            local: Box::new(local.clone()),
+            remote: Box::new(remote),
+        });
+    }
+    Ok(lock)
+}
+
 #[derive(Debug)]
 pub(crate) struct Db {
-    pub(crate) workers: Vec<Worker>,
+    workers: HashMap<Uuid, Worker>,
+    // Secondary indexes, kept up to date by insert and take
+    by_taj: HashMap<String, HashSet<Uuid>>,
+    by_taxnumber: HashMap<String, HashSet<Uuid>>,
+    // Selection of the current session
+    selected: HashSet<Uuid>,
+    employer: String,
+    date: NaiveDate,
+    pub(crate) history: History,
+    pub(crate) operator: String,
+    // Workers changed by this instance
+    pub(crate) edited: HashSet<Uuid>,
 }
 
 impl Db {
     pub(crate) fn open() -> Result<Self, String> {
-        let mut workers: Vec<Worker> = vec![];
+        let settings = Settings::load()?;
+        let mut res = Self {
+            operator: settings.operator.to_owned(),
+            employer: settings.default_employer(),
+            ..Self::empty()
+        };
         let files = std::fs::read_dir(&get_workers_dir()?).map_err(|e| e.to_string())?;
-        for file in files {
-            if let Ok(dir_entry) = file {
-                let mut file = File::open(&dir_entry.path()).map_err(|e| e.to_string())?;
-                let mut buffer = String::new();
-                file.read_to_string(&mut buffer)
-                    .map_err(|e| e.to_string())?;
-                let worker: Worker = serde_json::from_str(&buffer).map_err(|e| e.to_string())?;
-                workers.push(worker);
-            }
-        }
-        workers.sort_by(|a, b| a.name.cmp(&b.name));
-        let res = Self { workers };
+        for dir_entry in files.flatten() {
+            // Leftovers of an interrupted rewrite are skipped
+            if watcher::worker_id(&dir_entry.path()).is_some() {
+                res.insert(Worker::load(&dir_entry.path())?);
+            }
+        }
+        // Selection used to be stored in the worker files
+        let legacy = res.selected.drain().collect::<Vec<Uuid>>();
+        match SelectionSession::load(&res.employer, res.date)? {
+            Some(session) => res.apply_session(&session.worker_ids),
+            None => {
+                res.apply_session(&legacy);
+                if !legacy.is_empty() {
+                    res.save_session()?;
+                }
+            }
+        }
+        // Written once without the flag, with a new revision so other
+        // instances see the change
+        for id in legacy {
+            let mut worker = res.workers[&id].clone();
+            match lock_revision(&worker, Some(worker.revision)) {
+                Ok(_lock) => {
+                    worker.revision += 1;
+                    worker.save()?;
+                    res.insert(worker);
+                }
+                // Migrated by another instance meanwhile
+                Err(UpdateError::Conflict { remote, .. }) => {
+                    res.insert(Worker {
+                        is_selected: worker.is_selected,
+                        ..*remote
+                    });
+                }
+                Err(UpdateError::Failed(e)) => return Err(e),
+            }
+        }
+        Ok(res)
+    }
+    // Load again from disk, staying in the same session
+    pub(crate) fn reopen(&self) -> Result<Self, String> {
+        let mut res = Self::open()?;
+        res.open_session(self.employer.to_owned(), self.date)?;
         Ok(res)
     }
-    pub(crate) fn add_new_worker(&mut self, worker: Worker) -> Result<(), String> {
+    // Used while the storage is locked
+    pub(crate) fn empty() -> Self {
+        Self {
+            workers: HashMap::new(),
+            by_taj: HashMap::new(),
+            by_taxnumber: HashMap::new(),
+            selected: HashSet::new(),
+            employer: String::new(),
+            date: Local::now().date_naive(),
+            history: History::default(),
+            operator: Settings::load().unwrap_or_default().operator,
+            edited: HashSet::new(),
+        }
+    }
+    // Select exactly the given workers, in memory only
+    fn apply_session(&mut self, ids: &[Uuid]) {
+        self.selected.clear();
+        for worker in self.workers.values_mut() {
+            worker.is_selected = ids.contains(&worker.id);
+            if worker.is_selected {
+                self.selected.insert(worker.id);
+            }
+        }
+    }
+    fn save_session(&self) -> Result<(), String> {
+        self.session().save()
+    }
+    pub(crate) fn session(&self) -> SelectionSession {
+        let mut worker_ids = self.selected.iter().copied().collect::<Vec<Uuid>>();
+        worker_ids.sort();
+        SelectionSession {
+            employer: self.employer.to_owned(),
+            date: format_date(self.date),
+            worker_ids,
+        }
+    }
+    // Switch to the selection of another employer or day
+    pub(crate) fn open_session(&mut self, employer: String, date: NaiveDate) -> Result<(), String> {
+        let ids = SelectionSession::load(&employer, date)?
+            .map(|s| s.worker_ids)
+            .unwrap_or_default();
+        self.employer = employer;
+        self.date = date;
+        self.apply_session(&ids);
+        // Selection steps belong to the previous session
+        self.history.forget_selection();
+        Ok(())
+    }
+    // In no particular order
+    pub(crate) fn workers(&self) -> impl Iterator<Item = &Worker> {
+        self.workers.values()
+    }
+    // Every change of the in-memory workers goes through insert and take
+    fn insert(&mut self, worker: Worker) -> Option<Worker> {
+        let before = self.take(worker.id);
+        if let Some(key) = index_key(&worker.taj) {
+            self.by_taj.entry(key).or_default().insert(worker.id);
+        }
+        if let Some(key) = index_key(&worker.taxnumber) {
+            self.by_taxnumber.entry(key).or_default().insert(worker.id);
+        }
+        if worker.is_selected {
+            self.selected.insert(worker.id);
+        }
+        self.workers.insert(worker.id, worker);
+        before
+    }
+    fn take(&mut self, id: Uuid) -> Option<Worker> {
+        let worker = self.workers.remove(&id)?;
+        for (index, value) in [
+            (&mut self.by_taj, &worker.taj),
+            (&mut self.by_taxnumber, &worker.taxnumber),
+        ] {
+            if let Some(key) = index_key(value) {
+                if let Some(ids) = index.get_mut(&key) {
+                    ids.remove(&id);
+                    if ids.is_empty() {
+                        index.remove(&key);
+                    }
+                }
+            }
+        }
+        self.selected.remove(&id);
+        Some(worker)
+    }
+    // Other workers with the same TAJ or tax ID, only a warning as typos
+    // in the stored data have to be fixable one worker at a time
+    pub(crate) fn find_duplicates(&self, worker: &Worker) -> Vec<&Worker> {
+        let mut ids = HashSet::new();
+        for (index, value) in [
+            (&self.by_taj, &worker.taj),
+            (&self.by_taxnumber, &worker.taxnumber),
+        ] {
+            if let Some(found) = index_key(value).and_then(|key| index.get(&key)) {
+                ids.extend(found.iter().filter(|id| **id != worker.id));
+            }
+        }
+        ids.iter().filter_map(|id| self.get_by_id(*id)).collect()
+    }
+    pub(crate) fn add_new_worker(&mut self, mut worker: Worker) -> Result<(), String> {
+        // The first change of the day can be rolled back from a snapshot
+        let _ = backup::ensure_daily_snapshot();
+        self.next_revision(&mut worker);
         worker.save()?;
-        self.workers.push(worker);
+        let change = Change {
+            id: worker.id,
+            before: None,
+            after: Some(worker.clone()),
+        };
+        let selected = worker.is_selected;
+        self.insert(worker);
+        self.history.record(
+            HistoryAction::Add,
+            format!("Add {}", change.name()),
+            vec![change.clone()],
+        );
+        // The worker is saved, so nothing below may report the add as failed
+        if selected {
+            let _ = self.save_session();
+        }
+        let _ = self.audit(HistoryAction::Add, &change, "");
         Ok(())
     }
-    pub(crate) fn remove_worker(&mut self, worker: Worker) -> Result<(), String> {
+    pub(crate) fn remove_worker(&mut self, worker: Worker) -> Result<(), UpdateError> {
+        let _lock = lock_revision(&worker, Some(worker.revision))?;
+        let _ = backup::ensure_daily_snapshot();
         // Remove from storage
         worker.delete()?;
         // Remove from DB
-        self.workers.retain(|w| w.id != worker.id);
+        let before = self.take(worker.id);
+        let selected = before.as_ref().is_some_and(|w| w.is_selected);
+        let change = Change {
+            id: worker.id,
+            before: Some(before.unwrap_or(worker)),
+            after: None,
+        };
+        self.history.record(
+            HistoryAction::Remove,
+            format!("Remove {}", change.name()),
+            vec![change.clone()],
+        );
+        // The file is deleted, so nothing below may report the removal as failed
+        if selected {
+            let _ = self.save_session();
+        }
+        let _ = self.audit(HistoryAction::Remove, &change, "");
         Ok(())
     }
-    pub(crate) fn update_worker(&mut self, new_worker: Worker) -> Result<&Worker, String> {
-        for worker in &mut self.workers {
-            if worker.id == new_worker.id {
-                new_worker.save()?;
-                *worker = new_worker;
-                return Ok(worker);
-            }
+    pub(crate) fn update_worker(&mut self, mut new_worker: Worker) -> Result<&Worker, UpdateError> {
+        let id = new_worker.id;
+        let before = self
+            .get_by_id(id)
+            .cloned()
+            .ok_or_else(|| "Worker not found by ID".to_string())?;
+        let change = Change {
+            id,
+            before: Some(before),
+            after: Some(new_worker.clone()),
+        };
+        let action = change.action();
+        // Only the checkbox changed, the worker file stays as it is
+        if action != HistoryAction::Select {
+            // Another instance may have saved it since it was loaded
+            let _lock = lock_revision(&new_worker, Some(new_worker.revision))?;
+            let _ = backup::ensure_daily_snapshot();
+            self.next_revision(&mut new_worker);
+            new_worker.save()?;
+        }
+        let selection_changed =
+            change.before.as_ref().map(|w| w.is_selected) != Some(new_worker.is_selected);
+        let change = Change {
+            after: Some(new_worker.clone()),
+            ..change
+        };
+        self.insert(new_worker);
+        self.history.record(
+            action,
+            format!("{:?} {}", action, change.name()),
+            vec![change.clone()],
+        );
+        // The worker is saved, so nothing below may report the update as failed
+        if selection_changed {
+            let _ = self.save_session();
         }
-        Err(format!("Worker not found by ID"))
+        let _ = self.audit(action, &change, "");
+        Ok(&self.workers[&id])
     }
     pub(crate) fn set_worker_selected_by_id(
         &mut self,
         id: Uuid,
         selected: bool,
-    ) -> Option<&mut Worker> {
-        for worker in &mut self.workers {
-            if worker.id == id {
-                worker.is_selected = selected;
-                let _ = worker.save();
-                return Some(worker);
-            }
-        }
-        None
+    ) -> Option<&Worker> {
+        let worker = self.get_by_id(id)?.set_selected(selected);
+        let change = Change {
+            id,
+            after: Some(worker.clone()),
+            before: self.insert(worker),
+        };
+        let _ = self.save_session();
+        let _ = self.audit(HistoryAction::Select, &change, "");
+        self.history.record(
+            HistoryAction::Select,
+            format!("Select {}", change.name()),
+            vec![change],
+        );
+        self.get_by_id(id)
     }
     pub(crate) fn get_by_id(&self, id: Uuid) -> Option<&Worker> {
-        self.workers.iter().find(|w| w.id == id)
+        self.workers.get(&id)
     }
     pub(crate) fn get_workers_selected(&self) -> Vec<&Worker> {
-        self.workers
+        self.selected
             .iter()
-            .filter(|w| w.is_selected)
-            .map(|w| w)
+            .filter_map(|id| self.get_by_id(*id))
             .collect::<Vec<&Worker>>()
     }
+    // Selection of many workers as a single operation, returns the number changed
+    pub(crate) fn set_selection(
+        &mut self,
+        description: &str,
+        selected: impl Fn(&Worker) -> bool,
+    ) -> Result<u32, String> {
+        let states = self
+            .workers()
+            .filter(|w| selected(w) != w.is_selected)
+            .map(|w| (w.id, Some(w.set_selected(!w.is_selected))))
+            .collect::<Vec<(Uuid, Option<Worker>)>>();
+        let count = states.len() as u32;
+        if count > 0 {
+            self.apply_changes(HistoryAction::Select, description.to_string(), states, "")?;
+        }
+        Ok(count)
+    }
+    pub(crate) fn undo(&mut self) -> Result<Option<HistoryItem>, UpdateError> {
+        let command = match self.history.pop_undo() {
+            Some(command) => command,
+            None => return Ok(None),
+        };
+        for change in command.changes.iter().rev() {
+            if let Err(e) = self.restore_state(change.id, change.before.clone(), "Undo") {
+                self.history.push_undo(command);
+                return Err(e);
+            }
+        }
+        self.audit_selection(&command.changes, "Undo");
+        let item = command.item.clone();
+        self.history.push_redo(command);
+        self.save_session()?;
+        Ok(Some(item))
+    }
+    pub(crate) fn redo(&mut self) -> Result<Option<HistoryItem>, UpdateError> {
+        let command = match self.history.pop_redo() {
+            Some(command) => command,
+            None => return Ok(None),
+        };
+        for change in &command.changes {
+            if let Err(e) = self.restore_state(change.id, change.after.clone(), "Redo") {
+                self.history.push_redo(command);
+                return Err(e);
+            }
+        }
+        self.audit_selection(&command.changes, "Redo");
+        let item = command.item.clone();
+        self.history.push_undo(command);
+        self.save_session()?;
+        Ok(Some(item))
+    }
+    // Write many workers at once, recorded as a single operation
+    pub(crate) fn apply_changes(
+        &mut self,
+        action: HistoryAction,
+        description: String,
+        states: Vec<(Uuid, Option<Worker>)>,
+        note: &str,
+    ) -> Result<(), UpdateError> {
+        let mut changes = vec![];
+        for (id, state) in states {
+            let before = self.get_by_id(id).cloned();
+            if let Err(e) = self.restore_state(id, state.clone(), note) {
+                self.roll_back(action, description, changes);
+                return Err(e);
+            }
+            changes.push(Change {
+                id,
+                before,
+                after: state,
+            });
+        }
+        self.audit_selection(&changes, note);
+        self.history.record(action, description, changes);
+        Ok(self.save_session()?)
+    }
+    // Put back what a failed apply_changes wrote, the rest stays undoable
+    fn roll_back(&mut self, action: HistoryAction, description: String, mut changes: Vec<Change>) {
+        let mut rolled_back = vec![];
+        while let Some(change) = changes.last() {
+            if self
+                .restore_state(change.id, change.before.clone(), "Rollback")
+                .is_err()
+            {
+                break;
+            }
+            rolled_back.extend(changes.pop());
+        }
+        self.audit_selection(&rolled_back, "Rollback");
+        if !changes.is_empty() {
+            self.history
+                .record(action, format!("{} (partial)", description), changes);
+        }
+        let _ = self.save_session();
+    }
+    // Set a worker to the given state without recording history
+    fn restore_state(
+        &mut self,
+        id: Uuid,
+        state: Option<Worker>,
+        note: &str,
+    ) -> Result<(), UpdateError> {
+        let change = Change {
+            id,
+            before: self.get_by_id(id).cloned(),
+            after: state.clone(),
+        };
+        // Another instance may have saved it since it was loaded
+        let _lock = match change.after.as_ref().or(change.before.as_ref()) {
+            Some(local) if change.action() != HistoryAction::Select => Some(lock_revision(
+                local,
+                change.before.as_ref().map(|w| w.revision),
+            )?),
+            _ => None,
+        };
+        if change.action() != HistoryAction::Select {
+            let _ = backup::ensure_daily_snapshot();
+        }
+        match state {
+            Some(mut worker) => {
+                // Selection lives in the session file, saved by the caller
+                if change.action() != HistoryAction::Select {
+                    self.next_revision(&mut worker);
+                    worker.save()?;
+                }
+                self.insert(worker);
+            }
+            None => {
+                if let Some(worker) = self.get_by_id(id) {
+                    worker.delete()?;
+                }
+                self.take(id);
+            }
+        }
+        // Selections are logged by the caller, once for all the workers
+        if change.action() != HistoryAction::Select {
+            // Applied already, a failed log entry does not make it fail
+            let _ = self.audit(change.action(), &change, note);
+        }
+        Ok(())
+    }
+    // Every save gets a new revision, so stale edits can be detected
+    fn next_revision(&self, worker: &mut Worker) {
+        let current = self
+            .get_by_id(worker.id)
+            .map_or(worker.revision, |w| w.revision.max(worker.revision));
+        worker.revision = current + 1;
+    }
+    // Apply a change made to a worker file by someone else
+    pub(crate) fn reload_worker_file(&mut self, path: &Path) -> Result<(), String> {
+        let id = watcher::worker_id(path).ok_or("Not a worker file")?;
+        let data = std::fs::read(path).ok();
+        if watcher::is_known(id, data.as_deref()) {
+            // Our own write
+            return Ok(());
+        }
+        // Undo would overwrite the external change
+        self.history.forget(&[id]);
+        match data {
+            Some(_) => {
+                let mut remote = Worker::load(path)?;
+                remote.is_selected = self.selected.contains(&id);
+                match self.insert(remote.clone()) {
+                    Some(local) => {
+                        if self.edited.remove(&id) && local != remote {
+                            events::emit(DbEvent::Conflict {
+                                local,
+                                deleted: false,
+                            });
+                        }
+                        events::emit(DbEvent::WorkerUpdated { worker: remote });
+                    }
+                    None => events::emit(DbEvent::WorkerAdded { worker: remote }),
+                }
+            }
+            None => {
+                watcher::forget(id);
+                let local = match self.take(id) {
+                    Some(local) => local,
+                    None => return Ok(()),
+                };
+                if self.edited.remove(&id) {
+                    events::emit(DbEvent::Conflict {
+                        local,
+                        deleted: true,
+                    });
+                }
+                events::emit(DbEvent::WorkerRemoved { id });
+            }
+        }
+        Ok(())
+    }
+    // Personal data is removed from the worker, the audit log, the history, the
+    // drafts, the stored copies of the exported XMLs and the snapshots.
+    pub(crate) fn anonymize_workers(&mut self, ids: &[Uuid]) -> Result<(), String> {
+        let _ = backup::ensure_daily_snapshot();
+        // As they were reported
+        let mut reported = vec![];
+        for id in ids {
+            let current = self
+                .get_by_id(*id)
+                .ok_or_else(|| "Worker not found by ID".to_string())?
+                .clone();
+            let mut worker = current.anonymized();
+            let _lock = lock_revision(&worker, Some(current.revision))?;
+            self.next_revision(&mut worker);
+            worker.save()?;
+            self.insert(worker);
+            reported.push(current);
+        }
+        self.save_session()?;
+        self.history.forget(ids);
+        redact_audit_log(ids)?;
+        redact_drafts(ids)?;
+        redact_exports(&reported)?;
+        backup::redact_snapshots(ids)?;
+        self.log_event(AuditAction::Anonymize, ids.to_vec(), "Retention policy")
+    }
+    pub(crate) fn log_event(
+        &self,
+        action: AuditAction,
+        worker_ids: Vec<Uuid>,
+        note: &str,
+    ) -> Result<(), String> {
+        let res = AuditEntry::new(&self.operator, action, worker_ids.clone())
+            .with_note(note)
+            .append();
+        match action {
+            AuditAction::Export => events::emit(DbEvent::ExportDone {
+                path: note.to_string(),
+                worker_ids,
+            }),
+            AuditAction::Restore | AuditAction::Import | AuditAction::Anonymize => {
+                events::emit(DbEvent::Reloaded)
+            }
+            _ => (),
+        }
+        res
+    }
+    // One audit log entry and one change event for the selection changes of
+    // a bulk operation, with the states as they are now
+    fn audit_selection(&self, changes: &[Change], note: &str) {
+        let (mut selected, mut unselected) = (vec![], vec![]);
+        for change in changes
+            .iter()
+            .filter(|c| c.action() == HistoryAction::Select)
+        {
+            match self.get_by_id(change.id) {
+                Some(worker) if worker.is_selected => selected.push(worker.id),
+                Some(worker) => unselected.push(worker.id),
+                None => (),
+            }
+        }
+        if selected.is_empty() && unselected.is_empty() {
+            return;
+        }
+        let ids = selected.iter().chain(&unselected).copied().collect();
+        // Applied already, a failed log entry does not make it fail
+        let _ = AuditEntry::new(&self.operator, AuditAction::Select, ids)
+            .with_note(note)
+            .append();
+        events::emit(DbEvent::SelectionsChanged {
+            selected,
+            unselected,
+        });
+    }
+    // Audit log entry and change event for a single worker
+    fn audit(&mut self, action: HistoryAction, change: &Change, note: &str) -> Result<(), String> {
+        self.edited.insert(change.id);
+        let res = AuditEntry::new(&self.operator, action.into(), vec![change.id])
+            .with_changes(change.before.as_ref(), change.after.as_ref())
+            .with_note(note)
+            .append();
+        events::emit(match (action, &change.after) {
+            (HistoryAction::Add, Some(worker)) => DbEvent::WorkerAdded {
+                worker: worker.clone(),
+            },
+            (HistoryAction::Select, Some(worker)) => DbEvent::SelectionChanged {
+                id: worker.id,
+                selected: worker.is_selected,
+            },
+            (HistoryAction::Update, Some(worker)) => DbEvent::WorkerUpdated {
+                worker: worker.clone(),
+            },
+            _ => DbEvent::WorkerRemoved { id: change.id },
+        });
+        res
+    }
 }
 
 #[frb(non_final)]
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Worker {
     pub id: Uuid,
     #[frb(non_final)]
@@ -122,8 +708,45 @@ pub struct Worker {
     pub city: String,
     #[frb(non_final)]
     pub street: String,
+    // Selection of the current session, not stored in the worker file
     #[frb(non_final)]
+    #[serde(default, skip_serializing)]
     pub is_selected: bool,
+    // Set when personal data was removed by the retention policy
+    #[serde(default)]
+    pub anonymized_at: Option<DateTime<Local>>,
+    // Increased on every save, updates must be based on the stored revision
+    #[serde(default)]
+    pub revision: u64,
+    #[frb(non_final)]
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum UpdateError {
+    // The worker was saved by someone else since it was loaded
+    Conflict {
+        local: Box<Worker>,
+        remote: Box<Worker>,
+    },
+    Failed(String),
+}
+
+impl From<String> for UpdateError {
+    fn from(e: String) -> Self {
+        Self::Failed(e)
+    }
+}
+
+// For the calls reporting plain messages
+impl From<UpdateError> for String {
+    fn from(e: UpdateError) -> Self {
+        match e {
+            UpdateError::Conflict { .. } => "Worker was changed by someone else".to_string(),
+            UpdateError::Failed(e) => e,
+        }
+    }
 }
 
 impl Default for Worker {
@@ -140,38 +763,14 @@ impl Default for Worker {
             city: Default::default(),
             street: Default::default(),
             is_selected: Default::default(),
+            anonymized_at: Default::default(),
+            revision: Default::default(),
+            tags: Default::default(),
         }
     }
 }
 
 impl Worker {
-    pub(crate) fn new(
-        name: String,
-        taj: String,
-        taxnumber: String,
-        mothersname: String,
-        birthdate: String,
-        birthplace: String,
-        zip: String,
-        city: String,
-        street: String,
-        is_selected: bool,
-    ) -> Self {
-        Worker {
-            id: Uuid::new_v4(),
-            name,
-            taj,
-            taxnumber,
-            mothersname,
-            birthdate,
-            birthplace,
-            zip,
-            city,
-            street,
-            is_selected,
-        }
-    }
-
     #[frb(sync)]
     pub fn cloned(&self) -> Worker {
         self.to_owned()
@@ -186,23 +785,368 @@ impl Worker {
 
     #[frb(sync)]
     pub fn has_valid_birthdate(&self) -> bool {
-        NaiveDate::parse_from_str(&self.birthdate, "%Y-%m-%d").is_ok()
+        parse_date(&self.birthdate).is_ok()
+    }
+
+    // Names of the fields failing the same rules as the edit form
+    #[frb(sync)]
+    pub fn validation_errors(&self) -> Vec<String> {
+        let digits = |s: &str, len: usize| s.len() == len && s.chars().all(|c| c.is_ascii_digit());
+        let mut res = vec![];
+        for (field, value) in [
+            ("name", &self.name),
+            ("birthplace", &self.birthplace),
+            ("mothersname", &self.mothersname),
+            ("city", &self.city),
+            ("street", &self.street),
+        ] {
+            if value.trim().is_empty() {
+                res.push(field.to_string());
+            }
+        }
+        if !self.has_valid_birthdate() {
+            res.push("birthdate".to_string());
+        }
+        if !digits(&self.zip, 4) {
+            res.push("zip".to_string());
+        }
+        if !is_valid_taj(&self.taj) {
+            res.push("taj".to_string());
+        }
+        if !is_valid_taxnumber(&self.taxnumber) {
+            res.push("taxnumber".to_string());
+        }
+        res
     }
     pub(crate) fn save(&self) -> Result<&Self, String> {
-        let file_path = get_workers_dir()?.join(format!("{}.json", self.id.as_simple()));
+        let file_path = get_worker_path(self.id)?;
+        let data = storage::seal(
+            serde_json::to_string(&self)
+                .map_err(|e| e.to_string())?
+                .as_bytes(),
+        )?;
+        watcher::remember(self.id, &data);
         let mut buffer = BufWriter::new(File::create(&file_path).map_err(|e| e.to_string())?);
-        buffer
-            .write_all(
-                serde_json::to_string(&self)
-                    .map_err(|e| e.to_string())?
-                    .as_bytes(),
-            )
-            .map_err(|e| e.to_string())?;
+        buffer.write_all(&data).map_err(|e| e.to_string())?;
         buffer.flush().map_err(|e| e.to_string())?;
         Ok(self)
     }
+    // Keeps the ID only, so statistics by ID remain
+    pub(crate) fn anonymized(&self) -> Worker {
+        Worker {
+            id: self.id,
+            name: format!("Anonimizált {}", &self.id.as_simple().to_string()[..8]),
+            anonymized_at: Some(Local::now()),
+            ..Worker::default()
+        }
+    }
+    // Worker and the raw file content
+    pub(crate) fn read(path: &Path) -> Result<(Worker, Vec<u8>), String> {
+        let mut file = File::open(path).map_err(|e| e.to_string())?;
+        let mut buffer = vec![];
+        file.read_to_end(&mut buffer).map_err(|e| e.to_string())?;
+        let worker: Worker =
+            serde_json::from_slice(&storage::unseal(&buffer)?).map_err(|e| e.to_string())?;
+        Ok((worker, buffer))
+    }
+    // Read into the Db, so the watcher does not report it as a change
+    pub(crate) fn load(path: &Path) -> Result<Worker, String> {
+        let (worker, buffer) = Self::read(path)?;
+        watcher::remember(worker.id, &buffer);
+        Ok(worker)
+    }
     pub(crate) fn delete(&self) -> Result<(), String> {
-        let file_path = get_workers_dir()?.join(format!("{}.json", self.id.as_simple()));
+        let file_path = get_worker_path(self.id)?;
+        watcher::forget(self.id);
         std::fs::remove_file(&file_path).map_err(|e| e.to_string())
     }
 }
+
+// 9 digits, optionally grouped by three
+pub(crate) fn is_valid_taj(taj: &str) -> bool {
+    let digits = |s: &str, len: usize| s.len() == len && s.chars().all(|c| c.is_ascii_digit());
+    let groups = taj.split(' ').collect::<Vec<&str>>();
+    digits(taj, 9) || groups.len() == 3 && groups.iter().all(|g| digits(g, 3))
+}
+
+// XXXXXXXX-X-XX with the VAT code (1-5) and a county code
+pub(crate) fn is_valid_taxnumber(taxnumber: &str) -> bool {
+    let parts = taxnumber.split('-').collect::<Vec<&str>>();
+    let digits = |s: &str, len: usize| s.len() == len && s.chars().all(|c| c.is_ascii_digit());
+    match parts[..] {
+        [base, vat, county] if digits(base, 8) && digits(vat, 1) && digits(county, 2) => {
+            let county = county.parse::<u32>().unwrap_or_default();
+            matches!(vat, "1" | "2" | "3" | "4" | "5") && matches!(county, 2..=20 | 22..=44 | 51)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::{
+            audit::{read_audit_log, AUDIT_LOG},
+            draft::{ReportDraft, RowOverride},
+            export_history::{ExportRecord, ReportedWorker},
+        },
+        xml::{render_xml, RenderContext, ReportRow},
+    };
+
+    fn worker(name: &str, taj: &str) -> Worker {
+        Worker {
+            name: name.to_string(),
+            taj: taj.to_string(),
+            ..Worker::default()
+        }
+    }
+
+    #[test]
+    fn edits_are_logged_and_undoable() {
+        let mut db = Db::open().unwrap();
+        let anna = worker("Kiss Anna", "123456789");
+        db.add_new_worker(anna.clone()).unwrap();
+        let stored = db.get_by_id(anna.id).unwrap().clone();
+        db.update_worker(Worker {
+            city: "Szeged".to_string(),
+            ..stored
+        })
+        .unwrap();
+        let log = read_audit_log(Some(anna.id), None, None).unwrap();
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[1].changes[0].field, "city");
+        db.undo().unwrap();
+        assert_eq!(db.get_by_id(anna.id).unwrap().city, "");
+        db.undo().unwrap();
+        assert!(db.get_by_id(anna.id).is_none());
+        assert!(Db::open().unwrap().get_by_id(anna.id).is_none());
+    }
+
+    #[test]
+    fn failed_audit_does_not_fail_the_change() {
+        let mut db = Db::open().unwrap();
+        // The log can not be appended to
+        std::fs::create_dir_all(get_data_dir().unwrap().join(AUDIT_LOG)).unwrap();
+        let anna = worker("Kiss Anna", "123456789");
+        db.add_new_worker(anna.clone()).unwrap();
+        assert!(db.history.can_undo());
+        let stored = db.get_by_id(anna.id).unwrap().clone();
+        db.remove_worker(stored).unwrap();
+        assert_eq!(db.history.items(10).len(), 2);
+        db.undo().unwrap();
+        assert!(db.get_by_id(anna.id).is_some());
+    }
+
+    #[test]
+    fn undo_does_not_overwrite_changes_of_others() {
+        let mut db = Db::open().unwrap();
+        let anna = worker("Kiss Anna", "123456789");
+        db.add_new_worker(anna.clone()).unwrap();
+        let stored = db.get_by_id(anna.id).unwrap().clone();
+        db.update_worker(Worker {
+            city: "Szeged".to_string(),
+            ..stored
+        })
+        .unwrap();
+        // Saved by another instance, before the watcher reloads it
+        let stored = db.get_by_id(anna.id).unwrap().clone();
+        Worker {
+            city: "Pécs".to_string(),
+            revision: stored.revision + 1,
+            ..stored
+        }
+        .save()
+        .unwrap();
+        assert!(matches!(db.undo(), Err(UpdateError::Conflict { .. })));
+        assert!(db.history.can_undo());
+        assert!(db.anonymize_workers(&[anna.id]).is_err());
+        let path = get_worker_path(anna.id).unwrap();
+        assert_eq!(Worker::load(&path).unwrap().city, "Pécs");
+    }
+
+    #[test]
+    fn anonymizing_clears_drafts_and_exports() {
+        let mut db = Db::open().unwrap();
+        let anna = Worker {
+            taxnumber: "8123456789".to_string(),
+            ..worker("Kiss Anna", "123456789")
+        };
+        let bela = Worker {
+            taxnumber: "8987654321".to_string(),
+            ..worker("Nagy Béla", "987654321")
+        };
+        db.add_new_worker(anna.clone()).unwrap();
+        db.add_new_worker(bela.clone()).unwrap();
+        let employer = Settings::default().employers[0].clone();
+        let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        let rows = [ReportRow::from(&anna), ReportRow::from(&bela)];
+        let xml = render_xml(&RenderContext::new(employer.clone(), date), &rows);
+        let record = ExportRecord::create(
+            "op",
+            &employer,
+            date,
+            rows.iter()
+                .zip([anna.id, bela.id])
+                .map(|(row, id)| ReportedWorker::new(id, row))
+                .collect(),
+            "/tmp/a.xml",
+            &xml,
+            Local::now(),
+        )
+        .unwrap();
+        let mut draft = ReportDraft::new(date, employer.tax_number, vec![anna.id, bela.id]);
+        draft.rows[0].overrides = RowOverride {
+            name: Some("Kiss Anna Mária".to_string()),
+            days: Some(2),
+            ..RowOverride::default()
+        };
+        draft.save().unwrap();
+        db.anonymize_workers(&[anna.id]).unwrap();
+        let overrides = ReportDraft::load(draft.id).unwrap().rows[0]
+            .overrides
+            .clone();
+        assert_eq!(overrides.name, None);
+        assert_eq!(overrides.days, Some(2));
+        // The rest of the report is still there and matches its hash
+        let xml = ExportRecord::load(record.id).unwrap().xml().unwrap();
+        assert!(!xml.contains("Kiss Anna") && !xml.contains("123456789"));
+        assert!(xml.contains("Nagy Béla") && xml.contains("987654321"));
+    }
+
+    #[test]
+    fn duplicates_are_saved_and_found() {
+        let mut db = Db::open().unwrap();
+        let anna = worker("Kiss Anna", "123456789");
+        let copy = Worker {
+            taxnumber: "8123456789".to_string(),
+            ..worker("Kiss Anna", "123 456 789")
+        };
+        let other = Worker {
+            taxnumber: "8123-456789".to_string(),
+            ..worker("Nagy Béla", "")
+        };
+        db.add_new_worker(anna.clone()).unwrap();
+        db.add_new_worker(copy.clone()).unwrap();
+        db.add_new_worker(other.clone()).unwrap();
+        let names = |w: &Worker| {
+            let mut res = db
+                .find_duplicates(w)
+                .iter()
+                .map(|w| w.name.to_owned())
+                .collect::<Vec<String>>();
+            res.sort();
+            res
+        };
+        assert_eq!(names(&anna), vec!["Kiss Anna"]);
+        assert_eq!(names(&copy), vec!["Kiss Anna", "Nagy Béla"]);
+        assert_eq!(names(&worker("Új", "")), Vec::<String>::new());
+        // Saving one of them again is not blocked by the other
+        let stored = db.get_by_id(copy.id).unwrap().clone();
+        db.update_worker(Worker {
+            city: "Pécs".to_string(),
+            ..stored
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn failed_changes_are_rolled_back() {
+        let mut db = Db::open().unwrap();
+        let anna = worker("Kiss Anna", "123456789");
+        let bela = worker("Nagy Béla", "987654321");
+        db.add_new_worker(anna.clone()).unwrap();
+        db.add_new_worker(bela.clone()).unwrap();
+        // Removed by someone else, so deleting it fails
+        std::fs::remove_file(get_worker_path(bela.id).unwrap()).unwrap();
+        let stored = db.get_by_id(anna.id).unwrap().clone();
+        let states = vec![
+            (
+                anna.id,
+                Some(Worker {
+                    city: "Szeged".to_string(),
+                    ..stored
+                }),
+            ),
+            (bela.id, None),
+        ];
+        assert!(db
+            .apply_changes(HistoryAction::Update, "Import".into(), states, "Import")
+            .is_err());
+        assert_eq!(db.get_by_id(anna.id).unwrap().city, "");
+        assert_eq!(
+            Worker::load(&get_worker_path(anna.id).unwrap())
+                .unwrap()
+                .city,
+            ""
+        );
+        assert!(db.get_by_id(bela.id).is_some());
+        // The failed import is not in the history
+        assert_eq!(db.history.items(1)[0].action, HistoryAction::Add);
+    }
+
+    fn write_legacy(worker: &Worker) {
+        let mut value = serde_json::to_value(worker).unwrap();
+        value["is_selected"] = serde_json::Value::Bool(worker.is_selected);
+        let data = storage::seal(value.to_string().as_bytes()).unwrap();
+        std::fs::write(get_worker_path(worker.id).unwrap(), data).unwrap();
+    }
+
+    fn stored_value(id: Uuid) -> serde_json::Value {
+        let data = std::fs::read(get_worker_path(id).unwrap()).unwrap();
+        serde_json::from_slice(&storage::unseal(&data).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn legacy_selection_moves_to_the_session() {
+        let anna = Worker {
+            is_selected: true,
+            ..worker("Kiss Anna", "123456789")
+        };
+        let bela = worker("Nagy Béla", "987654321");
+        write_legacy(&anna);
+        write_legacy(&bela);
+        let db = Db::open().unwrap();
+        assert_eq!(db.session().worker_ids, vec![anna.id]);
+        assert!(db.get_by_id(anna.id).unwrap().is_selected);
+        assert!(!db.get_by_id(bela.id).unwrap().is_selected);
+        // The worker files no longer have it, only the migrated one is saved
+        assert!(stored_value(anna.id).get("is_selected").is_none());
+        assert_eq!(stored_value(anna.id)["revision"], anna.revision + 1);
+        assert_eq!(stored_value(bela.id)["revision"], bela.revision);
+        assert_eq!(db.get_by_id(anna.id).unwrap().revision, anna.revision + 1);
+        // Migrated once
+        Db::open().unwrap();
+        assert_eq!(stored_value(anna.id)["revision"], anna.revision + 1);
+        let session = SelectionSession::load(&db.employer, db.date)
+            .unwrap()
+            .unwrap();
+        assert_eq!(session.worker_ids, vec![anna.id]);
+        // Once there is a session, old flags do not override it
+        write_legacy(&Worker {
+            is_selected: true,
+            ..bela.clone()
+        });
+        let db = Db::open().unwrap();
+        assert_eq!(db.session().worker_ids, vec![anna.id]);
+        assert!(stored_value(bela.id).get("is_selected").is_none());
+    }
+
+    #[test]
+    fn selection_is_per_employer_and_day() {
+        let mut db = Db::open().unwrap();
+        let anna = worker("Kiss Anna", "123456789");
+        db.add_new_worker(anna.clone()).unwrap();
+        let day = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        db.open_session("12345678".to_string(), day).unwrap();
+        db.set_worker_selected_by_id(anna.id, true);
+        db.open_session("87654321".to_string(), day).unwrap();
+        assert!(db.get_workers_selected().is_empty());
+        db.open_session("12345678".to_string(), day.succ_opt().unwrap())
+            .unwrap();
+        assert!(db.get_workers_selected().is_empty());
+        db.open_session("12345678".to_string(), day).unwrap();
+        assert_eq!(db.get_workers_selected()[0].id, anna.id);
+        assert!(stored_value(anna.id).get("is_selected").is_none());
+    }
+}
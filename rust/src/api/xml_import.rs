@@ -0,0 +1,317 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use chrono::{DateTime, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::xml::parse_xml;
+
+use super::{
+    export_history::{list_exports, sha256_hex, ReportedWorker},
+    settings::Employer,
+    worker::{index_key, Db, Worker},
+};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct XmlImportSummary {
+    pub files: u32,
+    // Files already in the export history
+    pub skipped: Vec<String>,
+    // Names of the new workers
+    pub added: Vec<String>,
+    // Rows of workers already known
+    pub matched: u32,
+    // Number of export history entries made
+    pub reports: u32,
+    // File name and problem
+    pub errors: Vec<String>,
+}
+
+// Export history entry to be made for one report day of a file
+pub(crate) struct ImportedReport {
+    pub(crate) path: String,
+    pub(crate) xml: String,
+    pub(crate) timestamp: DateTime<Local>,
+    pub(crate) employer: Employer,
+    pub(crate) report_date: NaiveDate,
+    pub(crate) workers: Vec<ReportedWorker>,
+}
+
+pub(crate) struct XmlImport {
+    pub(crate) summary: XmlImportSummary,
+    pub(crate) workers: Vec<Worker>,
+    pub(crate) reports: Vec<ImportedReport>,
+}
+
+// When the file was written, as the old tool did not record it
+fn file_time(path: &str) -> DateTime<Local> {
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .map(DateTime::<Local>::from)
+        .unwrap_or_else(|_| Local::now())
+}
+
+// Workers are matched by TAJ or tax ID, unknown ones are created with the reported data only.
+// A file with a row that can not be matched is left out as a whole, so it can be imported
+// once the workers are fixed.
+pub(crate) fn read_xml_files(paths: &[String], db: &Db) -> Result<XmlImport, String> {
+    let mut hashes = list_exports()?
+        .into_iter()
+        .map(|r| r.sha256)
+        .collect::<HashSet<String>>();
+    let mut res = XmlImport {
+        summary: XmlImportSummary::default(),
+        workers: vec![],
+        reports: vec![],
+    };
+    // TAJ and tax ID keys of the new workers
+    let mut new_ids: HashMap<String, Uuid> = HashMap::new();
+    for path in paths {
+        res.summary.files += 1;
+        let xml = match std::fs::read_to_string(path) {
+            Ok(xml) => xml,
+            Err(e) => {
+                res.summary.errors.push(format!("{}: {}", path, e));
+                continue;
+            }
+        };
+        let hash = sha256_hex(xml.as_bytes());
+        if hashes.contains(&hash) {
+            res.summary.skipped.push(path.to_owned());
+            continue;
+        }
+        let report = match parse_xml(&xml) {
+            Ok(report) => report,
+            Err(e) => {
+                res.summary.errors.push(format!("{}: {}", path, e));
+                continue;
+            }
+        };
+        let mut errors = vec![];
+        let mut file_ids: HashMap<String, Uuid> = HashMap::new();
+        let mut added = vec![];
+        let mut matched = 0;
+        let mut days: BTreeMap<NaiveDate, Vec<ReportedWorker>> = BTreeMap::new();
+        for (i, parsed) in report.rows.iter().enumerate() {
+            let keys = [&parsed.row.taj, &parsed.row.taxnumber]
+                .into_iter()
+                .filter_map(|v| index_key(v))
+                .collect::<Vec<String>>();
+            if keys.is_empty() {
+                errors.push(format!("{}: row {} has no TAJ or tax ID", path, i + 1));
+                continue;
+            }
+            let worker = Worker {
+                id: Uuid::new_v4(),
+                name: parsed.row.name.to_owned(),
+                taj: parsed.row.taj.to_owned(),
+                taxnumber: parsed.row.taxnumber.to_owned(),
+                ..Default::default()
+            };
+            let mut known = db
+                .find_duplicates(&worker)
+                .iter()
+                .map(|w| w.id)
+                .collect::<HashSet<Uuid>>();
+            known.extend(
+                keys.iter()
+                    .filter_map(|k| new_ids.get(k).or_else(|| file_ids.get(k)).copied()),
+            );
+            if known.len() > 1 {
+                errors.push(format!(
+                    "{}: row {} has the TAJ of one worker and the tax ID of another",
+                    path,
+                    i + 1
+                ));
+                continue;
+            }
+            let id = match known.into_iter().next() {
+                Some(id) => {
+                    matched += 1;
+                    id
+                }
+                None => {
+                    for key in keys {
+                        file_ids.insert(key, worker.id);
+                    }
+                    let id = worker.id;
+                    added.push(worker);
+                    id
+                }
+            };
+            days.entry(parsed.date)
+                .or_default()
+                .push(ReportedWorker::new(id, &parsed.row));
+        }
+        if !errors.is_empty() {
+            res.summary.errors.extend(errors);
+            continue;
+        }
+        hashes.insert(hash);
+        new_ids.extend(file_ids);
+        res.summary.matched += matched;
+        res.summary
+            .added
+            .extend(added.iter().map(|w| w.name.to_owned()));
+        res.workers.extend(added);
+        let timestamp = file_time(path);
+        for (report_date, workers) in days {
+            res.reports.push(ImportedReport {
+                path: path.to_owned(),
+                xml: xml.to_owned(),
+                timestamp,
+                employer: report.employer.clone(),
+                report_date,
+                workers,
+            });
+        }
+    }
+    res.summary.reports = res.reports.len() as u32;
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::{
+        api::export_history::{reported_days, ExportRecord},
+        test_util::setup,
+    };
+
+    fn fixture(name: &str) -> String {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name)
+            .to_string_lossy()
+            .to_string()
+    }
+
+    // What import_xml_files keeps of it
+    fn store(import: XmlImport) {
+        for r in import.reports {
+            ExportRecord::create(
+                "op",
+                &r.employer,
+                r.report_date,
+                r.workers,
+                &r.path,
+                &r.xml,
+                r.timestamp,
+            )
+            .unwrap();
+        }
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn reports_are_made_for_every_day_of_a_file() {
+        let (db, [anna, bela]) = setup();
+        let import = read_xml_files(&[fixture("multi_day.xml")], &db).unwrap();
+        let summary = &import.summary;
+        assert_eq!((summary.files, summary.matched, summary.reports), (1, 3, 2));
+        assert_eq!(summary.added, vec!["Tóth Géza"]);
+        assert!(summary.errors.is_empty());
+        let geza = import.workers[0].id;
+        assert_eq!(import.workers[0].taj, "111222333");
+        let reports = &import.reports;
+        assert_eq!(reports[0].report_date, date("2024-03-04"));
+        assert_eq!(
+            reports[0].workers,
+            vec![
+                ReportedWorker {
+                    worker_id: anna,
+                    category: "03".to_string(),
+                    days: 1
+                },
+                ReportedWorker {
+                    worker_id: bela,
+                    category: "05".to_string(),
+                    days: 2
+                },
+            ]
+        );
+        assert_eq!(reports[1].report_date, date("2024-03-05"));
+        assert_eq!(
+            reports[1]
+                .workers
+                .iter()
+                .map(|w| (w.worker_id, w.days))
+                .collect::<Vec<(Uuid, u32)>>(),
+            vec![(anna, 1), (geza, 3)]
+        );
+        store(import);
+        // Every day the report covers
+        let days = reported_days().unwrap();
+        let list = |id: Uuid| days[&id].iter().copied().collect::<Vec<NaiveDate>>();
+        assert_eq!(list(anna), vec![date("2024-03-04"), date("2024-03-05")]);
+        assert_eq!(list(bela), vec![date("2024-03-04"), date("2024-03-05")]);
+        assert_eq!(
+            list(geza),
+            vec![date("2024-03-05"), date("2024-03-06"), date("2024-03-07")]
+        );
+    }
+
+    #[test]
+    fn unknown_workers_are_added_once() {
+        let (db, _) = setup();
+        let import = read_xml_files(
+            &[fixture("multi_day.xml"), fixture("unknown_worker.xml")],
+            &db,
+        )
+        .unwrap();
+        assert_eq!(import.summary.added, vec!["Tóth Géza", "Szabó Éva"]);
+        assert_eq!(import.summary.matched, 4);
+        let geza = import.workers[0].id;
+        assert_eq!(import.reports[2].workers[0].worker_id, geza);
+    }
+
+    #[test]
+    fn imported_files_are_skipped() {
+        let (db, _) = setup();
+        let path = fixture("multi_day.xml");
+        let import = read_xml_files(&[path.clone(), path.clone()], &db).unwrap();
+        assert_eq!(import.summary.skipped, vec![path.clone()]);
+        assert_eq!(import.reports.len(), 2);
+        store(import);
+        let again = read_xml_files(std::slice::from_ref(&path), &db).unwrap();
+        assert_eq!(again.summary.skipped, vec![path]);
+        assert!(again.reports.is_empty());
+        assert!(again.workers.is_empty());
+    }
+
+    #[test]
+    fn ambiguous_rows_leave_the_file_out() {
+        let (db, _) = setup();
+        let path = fixture("ambiguous.xml");
+        let import = read_xml_files(&[path.clone(), fixture("multi_day.xml")], &db).unwrap();
+        assert_eq!(
+            import.summary.errors,
+            vec![format!(
+                "{}: row 2 has the TAJ of one worker and the tax ID of another",
+                path
+            )]
+        );
+        // Only the other file
+        assert_eq!(import.summary.matched, 3);
+        assert_eq!(import.reports.len(), 2);
+        store(import);
+        // Not recorded, so it is not skipped once the workers are fixed
+        let again = read_xml_files(&[path], &db).unwrap();
+        assert!(again.summary.skipped.is_empty());
+        assert_eq!(again.summary.errors.len(), 1);
+    }
+
+    #[test]
+    fn unreadable_files_are_reported() {
+        let (db, _) = setup();
+        let import = read_xml_files(&[fixture("missing.xml"), fixture("../golden")], &db).unwrap();
+        assert_eq!(import.summary.files, 2);
+        assert_eq!(import.summary.errors.len(), 2);
+        assert!(import.reports.is_empty());
+    }
+}
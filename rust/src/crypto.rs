@@ -0,0 +1,145 @@
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Nonce,
+};
+use argon2::{
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use rand::{rngs::OsRng, RngCore};
+
+// Header of password protected files
+const MAGIC: &[u8; 4] = b"DWE1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+pub type Key = [u8; 32];
+
+pub fn random_salt() -> Vec<u8> {
+    let mut salt = vec![0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    salt
+}
+
+pub fn derive_key(password: &str, salt: &[u8]) -> Result<Key, String> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|e| e.to_string())?;
+    Ok(key)
+}
+
+// nonce + ciphertext
+pub fn encrypt(key: &Key, data: &[u8]) -> Result<Vec<u8>, String> {
+    let cipher = Aes256Gcm::new(key.into());
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), data)
+        .map_err(|e| e.to_string())?;
+    Ok([nonce.as_slice(), &ciphertext].concat())
+}
+
+pub fn decrypt(key: &Key, data: &[u8]) -> Result<Vec<u8>, String> {
+    if data.len() < NONCE_LEN {
+        return Err("Invalid encrypted data".into());
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    Aes256Gcm::new(key.into())
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Wrong password or corrupted data".to_string())
+}
+
+// magic + salt + nonce + ciphertext
+pub fn encrypt_with_password(password: &str, data: &[u8]) -> Result<Vec<u8>, String> {
+    let salt = random_salt();
+    let key = derive_key(password, &salt)?;
+    Ok([MAGIC.as_slice(), &salt, &encrypt(&key, data)?].concat())
+}
+
+pub fn decrypt_with_password(password: &str, data: &[u8]) -> Result<Vec<u8>, String> {
+    if data.len() < MAGIC.len() + SALT_LEN || !data.starts_with(MAGIC) {
+        return Err("Not an encrypted backup file".into());
+    }
+    let (salt, data) = data[MAGIC.len()..].split_at(SALT_LEN);
+    decrypt(&derive_key(password, salt)?, data)
+}
+
+// PHC string with a random salt
+pub fn hash_password(password: &str) -> Result<String, String> {
+    let salt = SaltString::generate(&mut OsRng);
+    Ok(Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| e.to_string())?
+        .to_string())
+}
+
+pub fn verify_password(password: &str, hash: &str) -> bool {
+    match PasswordHash::new(hash) {
+        Ok(hash) => Argon2::default()
+            .verify_password(password.as_bytes(), &hash)
+            .is_ok(),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn password_round_trip() {
+        let data = encrypt_with_password("titok", b"Kiss Anna").unwrap();
+        assert!(data.starts_with(MAGIC));
+        assert_eq!(decrypt_with_password("titok", &data).unwrap(), b"Kiss Anna");
+    }
+
+    #[test]
+    fn wrong_password_fails() {
+        let data = encrypt_with_password("titok", b"Kiss Anna").unwrap();
+        assert!(decrypt_with_password("rossz", &data).is_err());
+    }
+
+    #[test]
+    fn rejects_other_files() {
+        assert!(decrypt_with_password("titok", b"PK\x03\x04").is_err());
+        assert!(decrypt_with_password("titok", b"").is_err());
+    }
+
+    #[test]
+    fn tampered_data_fails() {
+        let mut data = encrypt_with_password("titok", b"Kiss Anna").unwrap();
+        let last = data.len() - 1;
+        data[last] ^= 1;
+        assert!(decrypt_with_password("titok", &data).is_err());
+    }
+
+    #[test]
+    fn same_data_encrypts_differently() {
+        let key = derive_key("titok", &random_salt()).unwrap();
+        let first = encrypt(&key, b"Kiss Anna").unwrap();
+        assert_ne!(first, encrypt(&key, b"Kiss Anna").unwrap());
+        assert_eq!(decrypt(&key, &first).unwrap(), b"Kiss Anna");
+    }
+
+    #[test]
+    fn key_depends_on_the_salt() {
+        let salt = random_salt();
+        assert_eq!(
+            derive_key("titok", &salt).unwrap(),
+            derive_key("titok", &salt).unwrap()
+        );
+        assert_ne!(
+            derive_key("titok", &salt).unwrap(),
+            derive_key("titok", &random_salt()).unwrap()
+        );
+    }
+
+    #[test]
+    fn password_hash_verifies() {
+        let hash = hash_password("1234").unwrap();
+        assert!(verify_password("1234", &hash));
+        assert!(!verify_password("4321", &hash));
+        assert!(!verify_password("1234", "not a hash"));
+    }
+}
@@ -61,20 +61,48 @@ fn wire_add_worker_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_worker = <crate::api::worker::Worker>::sse_decode(&mut deserializer);
             deserializer.end();
+            transform_result_sse((move || crate::api::simple::add_worker(api_worker))())
+        },
+    )
+}
+fn wire_apply_crew_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "apply_crew",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <uuid::Uuid>::sse_decode(&mut deserializer);
+            let api_replace = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
             transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::simple::add_worker(api_worker))
+                crate::api::simple::apply_crew(api_id, api_replace)
             })())
         },
     )
 }
-fn wire_export_xml_api_impl(
+fn wire_can_redo_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "export_xml_api",
+            debug_name: "can_redo",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -88,20 +116,45 @@ fn wire_export_xml_api_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_to = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse((move || crate::api::simple::export_xml_api(api_to))())
+            transform_result_sse((move || Result::<_, ()>::Ok(crate::api::simple::can_redo()))())
         },
     )
 }
-fn wire_get_empty_worker_impl(
+fn wire_can_undo_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_empty_worker",
+            debug_name: "can_undo",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || Result::<_, ()>::Ok(crate::api::simple::can_undo()))())
+        },
+    )
+}
+fn wire_change_storage_passphrase_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "change_storage_passphrase",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -115,21 +168,52 @@ fn wire_get_empty_worker_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_old_passphrase = <String>::sse_decode(&mut deserializer);
+            let api_new_passphrase = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::simple::get_empty_worker())
+                crate::api::simple::change_storage_passphrase(
+                    api_old_passphrase,
+                    api_new_passphrase,
+                )
             })())
         },
     )
 }
-fn wire_get_workers_impl(
+fn wire_clear_selection_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_workers",
+            debug_name: "clear_selection",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::clear_selection())())
+        },
+    )
+}
+fn wire_create_crew_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_crew",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -143,24 +227,25 @@ fn wire_get_workers_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_worker_ids = <Vec<uuid::Uuid>>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::simple::get_workers())
+                crate::api::simple::create_crew(api_name, api_worker_ids)
             })())
         },
     )
 }
-fn wire_init_app_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire_create_crew_from_selection_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "create_crew_from_selection",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -172,23 +257,22 @@ fn wire_init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse(
-                    (move || Result::<_, ()>::Ok(crate::api::simple::init_app()))(),
-                )
-            }
+            transform_result_sse((move || {
+                crate::api::simple::create_crew_from_selection(api_name)
+            })())
         },
     )
 }
-fn wire_remove_worker_api_impl(
+fn wire_create_draft_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_worker_api",
+            debug_name: "create_draft",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -202,22 +286,23 @@ fn wire_remove_worker_api_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_worker = <crate::api::worker::Worker>::sse_decode(&mut deserializer);
+            let api_date = <String>::sse_decode(&mut deserializer);
+            let api_employer = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::simple::remove_worker_api(api_worker))
+                crate::api::simple::create_draft(api_date, api_employer)
             })())
         },
     )
 }
-fn wire_update_worker_impl(
+fn wire_create_snapshot_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_worker",
+            debug_name: "create_snapshot",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -231,22 +316,102 @@ fn wire_update_worker_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_worker = <crate::api::worker::Worker>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::create_snapshot())())
+        },
+    )
+}
+fn wire_delete_crew_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_crew",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <uuid::Uuid>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::delete_crew(api_id))())
+        },
+    )
+}
+fn wire_delete_draft_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_draft",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <uuid::Uuid>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::delete_draft(api_id))())
+        },
+    )
+}
+fn wire_delete_import_profile_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_import_profile",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::simple::update_worker(api_worker))
+                crate::api::simple::delete_import_profile(api_name)
             })())
         },
     )
 }
-fn wire_Worker_cloned_impl(
+fn wire_disable_storage_encryption_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Worker_cloned",
+            debug_name: "disable_storage_encryption",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -260,22 +425,22 @@ fn wire_Worker_cloned_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::worker::Worker>::sse_decode(&mut deserializer);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::worker::Worker::cloned(&api_that))
+                crate::api::simple::disable_storage_encryption(api_passphrase)
             })())
         },
     )
 }
-fn wire_Worker_has_valid_birthdate_impl(
+fn wire_enable_storage_encryption_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Worker_has_valid_birthdate",
+            debug_name: "enable_storage_encryption",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -289,22 +454,22 @@ fn wire_Worker_has_valid_birthdate_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::worker::Worker>::sse_decode(&mut deserializer);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::worker::Worker::has_valid_birthdate(&api_that))
+                crate::api::simple::enable_storage_encryption(api_passphrase)
             })())
         },
     )
 }
-fn wire_Worker_set_selected_impl(
+fn wire_export_backup_file_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "Worker_set_selected",
+            debug_name: "export_backup_file",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -318,215 +483,4731 @@ fn wire_Worker_set_selected_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::worker::Worker>::sse_decode(&mut deserializer);
-            let api_to = <bool>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse((move || {
-                Result::<_, ()>::Ok(crate::api::worker::Worker::set_selected(&api_that, api_to))
+                crate::api::simple::export_backup_file(api_path, api_password)
             })())
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
+fn wire_export_crew_xml_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_crew_xml",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <uuid::Uuid>::sse_decode(&mut deserializer);
+            let api_to = <String>::sse_decode(&mut deserializer);
+            let api_date = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::export_crew_xml(api_id, api_to, api_date)
+            })())
+        },
+    )
 }
-
-impl SseDecode for uuid::Uuid {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return uuid::Uuid::from_slice(&inner).expect("fail to decode uuid");
-    }
+fn wire_export_draft_xml_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_draft_xml",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <uuid::Uuid>::sse_decode(&mut deserializer);
+            let api_to = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::export_draft_xml(api_id, api_to)
+            })())
+        },
+    )
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
+fn wire_export_roster_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_roster",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::query::WorkerQuery>::sse_decode(&mut deserializer);
+            let api_columns =
+                <Vec<crate::api::roster_export::RosterColumn>>::sse_decode(&mut deserializer);
+            let api_format =
+                <crate::api::roster_export::RosterFormat>::sse_decode(&mut deserializer);
+            let api_to = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::export_roster(api_query, api_columns, api_format, api_to)
+            })())
+        },
+    )
+}
+fn wire_export_worker_data_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_worker_data",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <uuid::Uuid>::sse_decode(&mut deserializer);
+            let api_to = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::export_worker_data(api_id, api_to)
+            })())
+        },
+    )
+}
+fn wire_export_xml_api_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_xml_api",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_to = <String>::sse_decode(&mut deserializer);
+            let api_date = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::export_xml_api(api_to, api_date)
+            })())
+        },
+    )
+}
+fn wire_fetch_workers_page_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "fetch_workers_page",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::query::WorkerQuery>::sse_decode(&mut deserializer);
+            let api_offset = <u32>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::simple::fetch_workers_page(api_query, api_offset, api_limit)
+                })())
+            }
+        },
+    )
+}
+fn wire_find_duplicate_workers_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_duplicate_workers",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_worker = <crate::api::worker::Worker>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::find_duplicate_workers(api_worker)
+            })())
+        },
+    )
+}
+fn wire_get_audit_log_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_audit_log",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_worker_id = <Option<uuid::Uuid>>::sse_decode(&mut deserializer);
+            let api_from = <Option<String>>::sse_decode(&mut deserializer);
+            let api_to = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::get_audit_log(api_worker_id, api_from, api_to)
+            })())
+        },
+    )
+}
+fn wire_get_crew_workers_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_crew_workers",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <uuid::Uuid>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::get_crew_workers(api_id))())
+        },
+    )
+}
+fn wire_get_crews_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_crews",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::get_crews())())
+        },
+    )
+}
+fn wire_get_draft_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_draft",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <uuid::Uuid>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::get_draft(api_id))())
+        },
+    )
+}
+fn wire_get_drafts_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_drafts",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::get_drafts())())
+        },
+    )
+}
+fn wire_get_empty_worker_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_empty_worker",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::simple::get_empty_worker())
+            })())
+        },
+    )
+}
+fn wire_get_export_history_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_export_history",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::get_export_history())())
+        },
+    )
+}
+fn wire_get_export_xml_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_export_xml",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <uuid::Uuid>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::get_export_xml(api_id))())
+        },
+    )
+}
+fn wire_get_history_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_history",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::get_history(api_limit))())
+        },
+    )
+}
+fn wire_get_import_profiles_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_import_profiles",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::get_import_profiles())())
+        },
+    )
+}
+fn wire_get_selected_workers_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_selected_workers",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::get_selected_workers())())
+        },
+    )
+}
+fn wire_get_selection_session_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_selection_session",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::get_selection_session())())
+        },
+    )
+}
+fn wire_get_settings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_settings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::get_settings())())
+        },
+    )
+}
+fn wire_get_spreadsheet_headers_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_spreadsheet_headers",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::get_spreadsheet_headers(api_path)
+            })())
+        },
+    )
+}
+fn wire_get_worker_data_report_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_worker_data_report",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <uuid::Uuid>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::get_worker_data_report(api_id))())
+        },
+    )
+}
+fn wire_get_workers_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_workers",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::get_workers())())
+        },
+    )
+}
+fn wire_get_workers_page_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_workers_page",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::query::WorkerQuery>::sse_decode(&mut deserializer);
+            let api_offset = <u32>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::get_workers_page(api_query, api_offset, api_limit)
+            })())
+        },
+    )
+}
+fn wire_has_pin_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "has_pin",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::has_pin())())
+        },
+    )
+}
+fn wire_import_backup_file_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_backup_file",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            let api_mode = <crate::api::backup::ImportMode>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::import_backup_file(api_path, api_password, api_mode)
+            })())
+        },
+    )
+}
+fn wire_import_spreadsheet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_spreadsheet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_profile =
+                <crate::api::spreadsheet_import::ImportProfile>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::import_spreadsheet(api_path, api_profile)
+            })())
+        },
+    )
+}
+fn wire_import_xml_files_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_xml_files",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_paths = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::import_xml_files(api_paths))())
+        },
+    )
+}
+fn wire_init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse(
+                    (move || Result::<_, ()>::Ok(crate::api::simple::init_app()))(),
+                )
+            }
+        },
+    )
+}
+fn wire_invert_selection_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "invert_selection",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::invert_selection())())
+        },
+    )
+}
+fn wire_is_locked_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_locked",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::simple::is_locked())
+            })())
+        },
+    )
+}
+fn wire_is_storage_encrypted_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_storage_encrypted",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::is_storage_encrypted())())
+        },
+    )
+}
+fn wire_is_storage_unlocked_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_storage_unlocked",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::simple::is_storage_unlocked())
+            })())
+        },
+    )
+}
+fn wire_list_snapshots_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_snapshots",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::list_snapshots())())
+        },
+    )
+}
+fn wire_lock_app_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "lock_app",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::lock_app())())
+        },
+    )
+}
+fn wire_open_selection_session_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_selection_session",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_employer = <String>::sse_decode(&mut deserializer);
+            let api_date = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::open_selection_session(api_employer, api_date)
+            })())
+        },
+    )
+}
+fn wire_preview_backup_import_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preview_backup_import",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            let api_mode = <crate::api::backup::ImportMode>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::preview_backup_import(api_path, api_password, api_mode)
+            })())
+        },
+    )
+}
+fn wire_preview_retention_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preview_retention",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::preview_retention())())
+        },
+    )
+}
+fn wire_preview_spreadsheet_import_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preview_spreadsheet_import",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_profile =
+                <crate::api::spreadsheet_import::ImportProfile>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::preview_spreadsheet_import(api_path, api_profile)
+            })())
+        },
+    )
+}
+fn wire_preview_xml_import_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preview_xml_import",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_paths = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::preview_xml_import(api_paths))())
+        },
+    )
+}
+fn wire_query_workers_api_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_workers_api",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::query::WorkerQuery>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::query_workers_api(api_query))())
+        },
+    )
+}
+fn wire_redo_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "redo",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::redo())())
+        },
+    )
+}
+fn wire_remove_pin_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_pin",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pin = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::remove_pin(api_pin))())
+        },
+    )
+}
+fn wire_remove_worker_api_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_worker_api",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_worker = <crate::api::worker::Worker>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::remove_worker_api(api_worker))())
+        },
+    )
+}
+fn wire_resave_export_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resave_export",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <uuid::Uuid>::sse_decode(&mut deserializer);
+            let api_to = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::resave_export(api_id, api_to))())
+        },
+    )
+}
+fn wire_restore_snapshot_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "restore_snapshot",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::restore_snapshot(api_id))())
+        },
+    )
+}
+fn wire_run_retention_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "run_retention",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::run_retention())())
+        },
+    )
+}
+fn wire_save_draft_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_draft",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_draft = <crate::api::draft::ReportDraft>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::save_draft(api_draft))())
+        },
+    )
+}
+fn wire_save_import_profile_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_import_profile",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile =
+                <crate::api::spreadsheet_import::ImportProfile>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::save_import_profile(api_profile)
+            })())
+        },
+    )
+}
+fn wire_select_all_workers_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "select_all_workers",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::select_all_workers())())
+        },
+    )
+}
+fn wire_select_workers_by_ids_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "select_workers_by_ids",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ids = <Vec<uuid::Uuid>>::sse_decode(&mut deserializer);
+            let api_selected = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::select_workers_by_ids(api_ids, api_selected)
+            })())
+        },
+    )
+}
+fn wire_select_workers_by_query_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "select_workers_by_query",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::query::WorkerQuery>::sse_decode(&mut deserializer);
+            let api_selected = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::select_workers_by_query(api_query, api_selected)
+            })())
+        },
+    )
+}
+fn wire_set_pin_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_pin",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_current_pin = <Option<String>>::sse_decode(&mut deserializer);
+            let api_new_pin = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::set_pin(api_current_pin, api_new_pin)
+            })())
+        },
+    )
+}
+fn wire_set_settings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_settings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings = <crate::api::settings::Settings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::set_settings(api_settings))())
+        },
+    )
+}
+fn wire_set_worker_selected_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_worker_selected",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <uuid::Uuid>::sse_decode(&mut deserializer);
+            let api_selected = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                crate::api::simple::set_worker_selected(api_id, api_selected)
+            })())
+        },
+    )
+}
+fn wire_subscribe_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "subscribe_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Stream,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse((move || {
+                    crate::api::simple::subscribe_events(StreamSink::new(
+                        context
+                            .rust2dart_context()
+                            .stream_sink::<_, crate::api::events::DbEvent>(),
+                    ))
+                })())
+            }
+        },
+    )
+}
+fn wire_undo_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "undo",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::undo())())
+        },
+    )
+}
+fn wire_unlock_app_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unlock_app",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pin = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::unlock_app(api_pin))())
+        },
+    )
+}
+fn wire_unlock_storage_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unlock_storage",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_passphrase = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::unlock_storage(api_passphrase))())
+        },
+    )
+}
+fn wire_update_crew_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_crew",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_crew = <crate::api::crew::Crew>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::update_crew(api_crew))())
+        },
+    )
+}
+fn wire_update_worker_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_worker",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_worker = <crate::api::worker::Worker>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::update_worker(api_worker))())
+        },
+    )
+}
+fn wire_validate_draft_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_draft",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <uuid::Uuid>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || crate::api::simple::validate_draft(api_id))())
+        },
+    )
+}
+fn wire_Worker_cloned_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Worker_cloned",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::worker::Worker>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::worker::Worker::cloned(&api_that))
+            })())
+        },
+    )
+}
+fn wire_Worker_has_valid_birthdate_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Worker_has_valid_birthdate",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::worker::Worker>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::worker::Worker::has_valid_birthdate(&api_that))
+            })())
+        },
+    )
+}
+fn wire_Worker_set_selected_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Worker_set_selected",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::worker::Worker>::sse_decode(&mut deserializer);
+            let api_to = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::worker::Worker::set_selected(&api_that, api_to))
+            })())
+        },
+    )
+}
+fn wire_Worker_validation_errors_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "Worker_validation_errors",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::worker::Worker>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse((move || {
+                Result::<_, ()>::Ok(crate::api::worker::Worker::validation_errors(&api_that))
+            })())
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for chrono::DateTime<chrono::Local> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i64>::sse_decode(deserializer);
+        return chrono::DateTime::<chrono::Local>::from(
+            chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(
+                chrono::NaiveDateTime::from_timestamp_micros(inner)
+                    .expect("invalid or out-of-range datetime"),
+                chrono::Utc,
+            ),
+        );
+    }
+}
+
+impl SseDecode for chrono::NaiveDateTime {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i64>::sse_decode(deserializer);
+        return chrono::NaiveDateTime::from_timestamp_micros(inner)
+            .expect("invalid or out-of-range datetime");
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for uuid::Uuid {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return uuid::Uuid::from_slice(&inner).expect("fail to decode uuid");
+    }
+}
+
+impl SseDecode for crate::api::audit::AuditAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::audit::AuditAction::Add,
+            1 => crate::api::audit::AuditAction::Update,
+            2 => crate::api::audit::AuditAction::Remove,
+            3 => crate::api::audit::AuditAction::Select,
+            4 => crate::api::audit::AuditAction::Export,
+            5 => crate::api::audit::AuditAction::Restore,
+            6 => crate::api::audit::AuditAction::Import,
+            7 => crate::api::audit::AuditAction::SubjectAccess,
+            8 => crate::api::audit::AuditAction::Anonymize,
+            9 => crate::api::audit::AuditAction::RosterExport,
+            _ => unreachable!("Invalid variant for AuditAction: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::audit::AuditEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <uuid::Uuid>::sse_decode(deserializer);
+        let mut var_timestamp = <chrono::DateTime<chrono::Local>>::sse_decode(deserializer);
+        let mut var_operator = <String>::sse_decode(deserializer);
+        let mut var_action = <crate::api::audit::AuditAction>::sse_decode(deserializer);
+        let mut var_workerIds = <Vec<uuid::Uuid>>::sse_decode(deserializer);
+        let mut var_changes = <Vec<crate::api::audit::FieldChange>>::sse_decode(deserializer);
+        let mut var_note = <String>::sse_decode(deserializer);
+        return crate::api::audit::AuditEntry {
+            id: var_id,
+            timestamp: var_timestamp,
+            operator: var_operator,
+            action: var_action,
+            worker_ids: var_workerIds,
+            changes: var_changes,
+            note: var_note,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for Box<crate::api::worker::Worker> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <crate::api::worker::Worker>::sse_decode(deserializer);
+        return Box::new(inner);
+    }
+}
+
+impl SseDecode for crate::api::spreadsheet_import::ColumnMapping {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_header = <String>::sse_decode(deserializer);
+        let mut var_field = <crate::api::spreadsheet_import::WorkerField>::sse_decode(deserializer);
+        return crate::api::spreadsheet_import::ColumnMapping {
+            header: var_header,
+            field: var_field,
+        };
+    }
+}
+
+impl SseDecode for crate::api::crew::Crew {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <uuid::Uuid>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_workerIds = <Vec<uuid::Uuid>>::sse_decode(deserializer);
+        let mut var_updatedAt = <chrono::DateTime<chrono::Local>>::sse_decode(deserializer);
+        return crate::api::crew::Crew {
+            id: var_id,
+            name: var_name,
+            worker_ids: var_workerIds,
+            updated_at: var_updatedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::events::DbEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_worker = <crate::api::worker::Worker>::sse_decode(deserializer);
+                return crate::api::events::DbEvent::WorkerAdded { worker: var_worker };
+            }
+            1 => {
+                let mut var_worker = <crate::api::worker::Worker>::sse_decode(deserializer);
+                return crate::api::events::DbEvent::WorkerUpdated { worker: var_worker };
+            }
+            2 => {
+                let mut var_id = <uuid::Uuid>::sse_decode(deserializer);
+                return crate::api::events::DbEvent::WorkerRemoved { id: var_id };
+            }
+            3 => {
+                let mut var_id = <uuid::Uuid>::sse_decode(deserializer);
+                let mut var_selected = <bool>::sse_decode(deserializer);
+                return crate::api::events::DbEvent::SelectionChanged {
+                    id: var_id,
+                    selected: var_selected,
+                };
+            }
+            4 => {
+                let mut var_selected = <Vec<uuid::Uuid>>::sse_decode(deserializer);
+                let mut var_unselected = <Vec<uuid::Uuid>>::sse_decode(deserializer);
+                return crate::api::events::DbEvent::SelectionsChanged {
+                    selected: var_selected,
+                    unselected: var_unselected,
+                };
+            }
+            5 => {
+                let mut var_path = <String>::sse_decode(deserializer);
+                let mut var_workerIds = <Vec<uuid::Uuid>>::sse_decode(deserializer);
+                return crate::api::events::DbEvent::ExportDone {
+                    path: var_path,
+                    worker_ids: var_workerIds,
+                };
+            }
+            6 => {
+                let mut var_local = <crate::api::worker::Worker>::sse_decode(deserializer);
+                let mut var_deleted = <bool>::sse_decode(deserializer);
+                return crate::api::events::DbEvent::Conflict {
+                    local: var_local,
+                    deleted: var_deleted,
+                };
+            }
+            7 => {
+                return crate::api::events::DbEvent::Reloaded;
+            }
+            8 => {
+                let mut var_ids = <Vec<uuid::Uuid>>::sse_decode(deserializer);
+                return crate::api::events::DbEvent::Changed { ids: var_ids };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::draft::DraftIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_workerId = <Option<uuid::Uuid>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::draft::DraftIssue {
+            worker_id: var_workerId,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::draft::DraftRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_workerId = <uuid::Uuid>::sse_decode(deserializer);
+        let mut var_overrides = <crate::api::draft::RowOverride>::sse_decode(deserializer);
+        return crate::api::draft::DraftRow {
+            worker_id: var_workerId,
+            overrides: var_overrides,
+        };
+    }
+}
+
+impl SseDecode for crate::api::settings::Employer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_taxNumber = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_phone = <String>::sse_decode(deserializer);
+        return crate::api::settings::Employer {
+            tax_number: var_taxNumber,
+            name: var_name,
+            phone: var_phone,
+        };
+    }
+}
+
+impl SseDecode for crate::api::privacy::ExportAppearance {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_exportId = <Option<uuid::Uuid>>::sse_decode(deserializer);
+        let mut var_timestamp = <chrono::DateTime<chrono::Local>>::sse_decode(deserializer);
+        let mut var_reportDate = <String>::sse_decode(deserializer);
+        let mut var_operator = <String>::sse_decode(deserializer);
+        let mut var_file = <String>::sse_decode(deserializer);
+        return crate::api::privacy::ExportAppearance {
+            export_id: var_exportId,
+            timestamp: var_timestamp,
+            report_date: var_reportDate,
+            operator: var_operator,
+            file: var_file,
+        };
+    }
+}
+
+impl SseDecode for crate::api::export_history::ExportRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <uuid::Uuid>::sse_decode(deserializer);
+        let mut var_timestamp = <chrono::DateTime<chrono::Local>>::sse_decode(deserializer);
+        let mut var_operator = <String>::sse_decode(deserializer);
+        let mut var_employer = <crate::api::settings::Employer>::sse_decode(deserializer);
+        let mut var_reportDate = <String>::sse_decode(deserializer);
+        let mut var_workerIds = <Vec<uuid::Uuid>>::sse_decode(deserializer);
+        let mut var_workers =
+            <Vec<crate::api::export_history::ReportedWorker>>::sse_decode(deserializer);
+        let mut var_fileName = <String>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_sha256 = <String>::sse_decode(deserializer);
+        return crate::api::export_history::ExportRecord {
+            id: var_id,
+            timestamp: var_timestamp,
+            operator: var_operator,
+            employer: var_employer,
+            report_date: var_reportDate,
+            worker_ids: var_workerIds,
+            workers: var_workers,
+            file_name: var_fileName,
+            path: var_path,
+            sha256: var_sha256,
+        };
+    }
+}
+
+impl SseDecode for crate::api::audit::FieldChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <String>::sse_decode(deserializer);
+        let mut var_before = <String>::sse_decode(deserializer);
+        let mut var_after = <String>::sse_decode(deserializer);
+        return crate::api::audit::FieldChange {
+            field: var_field,
+            before: var_before,
+            after: var_after,
+        };
+    }
+}
+
+impl SseDecode for crate::api::history::HistoryAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::history::HistoryAction::Add,
+            1 => crate::api::history::HistoryAction::Update,
+            2 => crate::api::history::HistoryAction::Remove,
+            3 => crate::api::history::HistoryAction::Select,
+            _ => unreachable!("Invalid variant for HistoryAction: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::history::HistoryItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <uuid::Uuid>::sse_decode(deserializer);
+        let mut var_action = <crate::api::history::HistoryAction>::sse_decode(deserializer);
+        let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_workerIds = <Vec<uuid::Uuid>>::sse_decode(deserializer);
+        let mut var_createdAt = <chrono::DateTime<chrono::Local>>::sse_decode(deserializer);
+        return crate::api::history::HistoryItem {
+            id: var_id,
+            action: var_action,
+            description: var_description,
+            worker_ids: var_workerIds,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::backup::ImportMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::backup::ImportMode::Merge,
+            1 => crate::api::backup::ImportMode::Replace,
+            _ => unreachable!("Invalid variant for ImportMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::spreadsheet_import::ImportProfile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_columns =
+            <Vec<crate::api::spreadsheet_import::ColumnMapping>>::sse_decode(deserializer);
+        return crate::api::spreadsheet_import::ImportProfile {
+            name: var_name,
+            columns: var_columns,
+        };
+    }
+}
+
+impl SseDecode for crate::api::spreadsheet_import::ImportRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_row = <u32>::sse_decode(deserializer);
+        let mut var_worker = <crate::api::worker::Worker>::sse_decode(deserializer);
+        let mut var_errors = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::spreadsheet_import::ImportRow {
+            row: var_row,
+            worker: var_worker,
+            errors: var_errors,
+        };
+    }
+}
+
+impl SseDecode for crate::api::backup::ImportSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_added = <Vec<String>>::sse_decode(deserializer);
+        let mut var_updated = <Vec<String>>::sse_decode(deserializer);
+        let mut var_removed = <Vec<String>>::sse_decode(deserializer);
+        let mut var_unchanged = <u32>::sse_decode(deserializer);
+        let mut var_replacesSettings = <bool>::sse_decode(deserializer);
+        return crate::api::backup::ImportSummary {
+            added: var_added,
+            updated: var_updated,
+            removed: var_removed,
+            unchanged: var_unchanged,
+            replaces_settings: var_replacesSettings,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<uuid::Uuid> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<uuid::Uuid>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::audit::AuditEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::audit::AuditEntry>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::spreadsheet_import::ColumnMapping> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::spreadsheet_import::ColumnMapping>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::crew::Crew> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::crew::Crew>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::draft::DraftIssue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::draft::DraftIssue>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::draft::DraftRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::draft::DraftRow>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::settings::Employer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::settings::Employer>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::privacy::ExportAppearance> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::privacy::ExportAppearance>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::export_history::ExportRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::export_history::ExportRecord>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::audit::FieldChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::audit::FieldChange>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::history::HistoryItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::history::HistoryItem>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::spreadsheet_import::ImportProfile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::spreadsheet_import::ImportProfile>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::spreadsheet_import::ImportRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::spreadsheet_import::ImportRow>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::draft::ReportDraft> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::draft::ReportDraft>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::export_history::ReportedWorker> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::export_history::ReportedWorker>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::privacy::RetentionCandidate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::privacy::RetentionCandidate>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::roster_export::RosterColumn> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::roster_export::RosterColumn>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::backup::Snapshot> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::backup::Snapshot>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::worker::Worker> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::worker::Worker>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::query::WorkerSort> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::query::WorkerSort>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<uuid::Uuid> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<uuid::Uuid>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<chrono::DateTime<chrono::Local>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<chrono::DateTime<chrono::Local>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::history::HistoryItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::history::HistoryItem>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::draft::ReportDraft {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <uuid::Uuid>::sse_decode(deserializer);
+        let mut var_date = <String>::sse_decode(deserializer);
+        let mut var_employer = <String>::sse_decode(deserializer);
+        let mut var_category = <String>::sse_decode(deserializer);
+        let mut var_rows = <Vec<crate::api::draft::DraftRow>>::sse_decode(deserializer);
+        let mut var_updatedAt = <chrono::DateTime<chrono::Local>>::sse_decode(deserializer);
+        let mut var_exportedAt =
+            <Option<chrono::DateTime<chrono::Local>>>::sse_decode(deserializer);
+        return crate::api::draft::ReportDraft {
+            id: var_id,
+            date: var_date,
+            employer: var_employer,
+            category: var_category,
+            rows: var_rows,
+            updated_at: var_updatedAt,
+            exported_at: var_exportedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::export_history::ReportedWorker {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_workerId = <uuid::Uuid>::sse_decode(deserializer);
+        let mut var_category = <String>::sse_decode(deserializer);
+        let mut var_days = <u32>::sse_decode(deserializer);
+        return crate::api::export_history::ReportedWorker {
+            worker_id: var_workerId,
+            category: var_category,
+            days: var_days,
+        };
+    }
+}
+
+impl SseDecode for crate::api::privacy::RetentionCandidate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <uuid::Uuid>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_lastActivity =
+            <Option<chrono::DateTime<chrono::Local>>>::sse_decode(deserializer);
+        let mut var_reportedDays = <u32>::sse_decode(deserializer);
+        let mut var_keptExports = <u32>::sse_decode(deserializer);
+        return crate::api::privacy::RetentionCandidate {
+            id: var_id,
+            name: var_name,
+            last_activity: var_lastActivity,
+            reported_days: var_reportedDays,
+            kept_exports: var_keptExports,
+        };
+    }
+}
+
+impl SseDecode for crate::api::roster_export::RosterColumn {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::roster_export::RosterColumn::Name,
+            1 => crate::api::roster_export::RosterColumn::Address,
+            2 => crate::api::roster_export::RosterColumn::Taj,
+            3 => crate::api::roster_export::RosterColumn::Taxnumber,
+            4 => crate::api::roster_export::RosterColumn::Mothersname,
+            5 => crate::api::roster_export::RosterColumn::Birthdate,
+            6 => crate::api::roster_export::RosterColumn::Birthplace,
+            7 => crate::api::roster_export::RosterColumn::LastWorked,
+            8 => crate::api::roster_export::RosterColumn::ReportedDays,
+            _ => unreachable!("Invalid variant for RosterColumn: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::roster_export::RosterFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::roster_export::RosterFormat::Csv,
+            1 => crate::api::roster_export::RosterFormat::Xlsx,
+            _ => unreachable!("Invalid variant for RosterFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::draft::RowOverride {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_taj = <Option<String>>::sse_decode(deserializer);
+        let mut var_taxnumber = <Option<String>>::sse_decode(deserializer);
+        let mut var_category = <Option<String>>::sse_decode(deserializer);
+        let mut var_days = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::draft::RowOverride {
+            name: var_name,
+            taj: var_taj,
+            taxnumber: var_taxnumber,
+            category: var_category,
+            days: var_days,
+        };
+    }
+}
+
+impl SseDecode for crate::api::session::SelectionSession {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_employer = <String>::sse_decode(deserializer);
+        let mut var_date = <String>::sse_decode(deserializer);
+        let mut var_workerIds = <Vec<uuid::Uuid>>::sse_decode(deserializer);
+        return crate::api::session::SelectionSession {
+            employer: var_employer,
+            date: var_date,
+            worker_ids: var_workerIds,
+        };
+    }
+}
+
+impl SseDecode for crate::api::settings::Settings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_operator = <String>::sse_decode(deserializer);
+        let mut var_backupKeep = <u32>::sse_decode(deserializer);
+        let mut var_autoLockMinutes = <u32>::sse_decode(deserializer);
+        let mut var_retentionYears = <u32>::sse_decode(deserializer);
+        let mut var_employers = <Vec<crate::api::settings::Employer>>::sse_decode(deserializer);
+        return crate::api::settings::Settings {
+            operator: var_operator,
+            backup_keep: var_backupKeep,
+            auto_lock_minutes: var_autoLockMinutes,
+            retention_years: var_retentionYears,
+            employers: var_employers,
+        };
+    }
+}
+
+impl SseDecode for crate::api::backup::Snapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <chrono::NaiveDateTime>::sse_decode(deserializer);
+        let mut var_reason = <crate::api::backup::SnapshotReason>::sse_decode(deserializer);
+        let mut var_size = <u64>::sse_decode(deserializer);
+        return crate::api::backup::Snapshot {
+            id: var_id,
+            created_at: var_createdAt,
+            reason: var_reason,
+            size: var_size,
+        };
+    }
+}
+
+impl SseDecode for crate::api::backup::SnapshotReason {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::backup::SnapshotReason::Daily,
+            1 => crate::api::backup::SnapshotReason::Export,
+            2 => crate::api::backup::SnapshotReason::Manual,
+            3 => crate::api::backup::SnapshotReason::Restore,
+            _ => unreachable!("Invalid variant for SnapshotReason: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::spreadsheet_import::SpreadsheetImport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rows =
+            <Vec<crate::api::spreadsheet_import::ImportRow>>::sse_decode(deserializer);
+        let mut var_valid = <u32>::sse_decode(deserializer);
+        return crate::api::spreadsheet_import::SpreadsheetImport {
+            rows: var_rows,
+            valid: var_valid,
+        };
+    }
+}
+
+impl SseDecode for crate::api::privacy::SubjectReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_generatedAt = <chrono::DateTime<chrono::Local>>::sse_decode(deserializer);
+        let mut var_worker = <crate::api::worker::Worker>::sse_decode(deserializer);
+        let mut var_reportedDays = <Vec<String>>::sse_decode(deserializer);
+        let mut var_exports =
+            <Vec<crate::api::privacy::ExportAppearance>>::sse_decode(deserializer);
+        let mut var_auditEntries = <Vec<crate::api::audit::AuditEntry>>::sse_decode(deserializer);
+        return crate::api::privacy::SubjectReport {
+            generated_at: var_generatedAt,
+            worker: var_worker,
+            reported_days: var_reportedDays,
+            exports: var_exports,
+            audit_entries: var_auditEntries,
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::worker::UpdateError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_local = <Box<crate::api::worker::Worker>>::sse_decode(deserializer);
+                let mut var_remote = <Box<crate::api::worker::Worker>>::sse_decode(deserializer);
+                return crate::api::worker::UpdateError::Conflict {
+                    local: var_local,
+                    remote: var_remote,
+                };
+            }
+            1 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::worker::UpdateError::Failed(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::worker::Worker {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <uuid::Uuid>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_taj = <String>::sse_decode(deserializer);
+        let mut var_taxnumber = <String>::sse_decode(deserializer);
+        let mut var_mothersname = <String>::sse_decode(deserializer);
+        let mut var_birthdate = <String>::sse_decode(deserializer);
+        let mut var_birthplace = <String>::sse_decode(deserializer);
+        let mut var_zip = <String>::sse_decode(deserializer);
+        let mut var_city = <String>::sse_decode(deserializer);
+        let mut var_street = <String>::sse_decode(deserializer);
+        let mut var_isSelected = <bool>::sse_decode(deserializer);
+        let mut var_anonymizedAt =
+            <Option<chrono::DateTime<chrono::Local>>>::sse_decode(deserializer);
+        let mut var_revision = <u64>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::worker::Worker {
+            id: var_id,
+            name: var_name,
+            taj: var_taj,
+            taxnumber: var_taxnumber,
+            mothersname: var_mothersname,
+            birthdate: var_birthdate,
+            birthplace: var_birthplace,
+            zip: var_zip,
+            city: var_city,
+            street: var_street,
+            is_selected: var_isSelected,
+            anonymized_at: var_anonymizedAt,
+            revision: var_revision,
+            tags: var_tags,
+        };
+    }
+}
+
+impl SseDecode for crate::api::spreadsheet_import::WorkerField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::spreadsheet_import::WorkerField::Name,
+            1 => crate::api::spreadsheet_import::WorkerField::Taj,
+            2 => crate::api::spreadsheet_import::WorkerField::Taxnumber,
+            3 => crate::api::spreadsheet_import::WorkerField::Mothersname,
+            4 => crate::api::spreadsheet_import::WorkerField::Birthdate,
+            5 => crate::api::spreadsheet_import::WorkerField::Birthplace,
+            6 => crate::api::spreadsheet_import::WorkerField::Zip,
+            7 => crate::api::spreadsheet_import::WorkerField::City,
+            8 => crate::api::spreadsheet_import::WorkerField::Street,
+            9 => crate::api::spreadsheet_import::WorkerField::Tags,
+            _ => unreachable!("Invalid variant for WorkerField: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::query::WorkerPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_workers = <Vec<crate::api::worker::Worker>>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        let mut var_offset = <u32>::sse_decode(deserializer);
+        return crate::api::query::WorkerPage {
+            workers: var_workers,
+            total: var_total,
+            offset: var_offset,
+        };
+    }
+}
+
+impl SseDecode for crate::api::query::WorkerQuery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <Option<String>>::sse_decode(deserializer);
+        let mut var_selected = <Option<bool>>::sse_decode(deserializer);
+        let mut var_city = <Option<String>>::sse_decode(deserializer);
+        let mut var_hasValidationErrors = <Option<bool>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_lastWorkedFrom = <Option<String>>::sse_decode(deserializer);
+        let mut var_lastWorkedTo = <Option<String>>::sse_decode(deserializer);
+        let mut var_sort = <Vec<crate::api::query::WorkerSort>>::sse_decode(deserializer);
+        return crate::api::query::WorkerQuery {
+            text: var_text,
+            selected: var_selected,
+            city: var_city,
+            has_validation_errors: var_hasValidationErrors,
+            tags: var_tags,
+            last_worked_from: var_lastWorkedFrom,
+            last_worked_to: var_lastWorkedTo,
+            sort: var_sort,
+        };
+    }
+}
+
+impl SseDecode for crate::api::query::WorkerSort {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <crate::api::query::WorkerSortKey>::sse_decode(deserializer);
+        let mut var_descending = <bool>::sse_decode(deserializer);
+        return crate::api::query::WorkerSort {
+            key: var_key,
+            descending: var_descending,
+        };
+    }
+}
+
+impl SseDecode for crate::api::query::WorkerSortKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::query::WorkerSortKey::Name,
+            1 => crate::api::query::WorkerSortKey::City,
+            2 => crate::api::query::WorkerSortKey::BirthDate,
+            3 => crate::api::query::WorkerSortKey::Selected,
+            4 => crate::api::query::WorkerSortKey::LastWorked,
+            _ => unreachable!("Invalid variant for WorkerSortKey: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::xml_import::XmlImportSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_files = <u32>::sse_decode(deserializer);
+        let mut var_skipped = <Vec<String>>::sse_decode(deserializer);
+        let mut var_added = <Vec<String>>::sse_decode(deserializer);
+        let mut var_matched = <u32>::sse_decode(deserializer);
+        let mut var_reports = <u32>::sse_decode(deserializer);
+        let mut var_errors = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::xml_import::XmlImportSummary {
+            files: var_files,
+            skipped: var_skipped,
+            added: var_added,
+            matched: var_matched,
+            reports: var_reports,
+            errors: var_errors,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        25 => wire_fetch_workers_page_impl(port, ptr, rust_vec_len, data_len),
+        78 => wire_init_app_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire_subscribe_events_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        26 => wire_add_worker_impl(ptr, rust_vec_len, data_len),
+        19 => wire_apply_crew_impl(ptr, rust_vec_len, data_len),
+        43 => wire_can_redo_impl(ptr, rust_vec_len, data_len),
+        42 => wire_can_undo_impl(ptr, rust_vec_len, data_len),
+        70 => wire_change_storage_passphrase_impl(ptr, rust_vec_len, data_len),
+        36 => wire_clear_selection_impl(ptr, rust_vec_len, data_len),
+        15 => wire_create_crew_impl(ptr, rust_vec_len, data_len),
+        16 => wire_create_crew_from_selection_impl(ptr, rust_vec_len, data_len),
+        7 => wire_create_draft_impl(ptr, rust_vec_len, data_len),
+        53 => wire_create_snapshot_impl(ptr, rust_vec_len, data_len),
+        18 => wire_delete_crew_impl(ptr, rust_vec_len, data_len),
+        9 => wire_delete_draft_impl(ptr, rust_vec_len, data_len),
+        60 => wire_delete_import_profile_impl(ptr, rust_vec_len, data_len),
+        71 => wire_disable_storage_encryption_impl(ptr, rust_vec_len, data_len),
+        69 => wire_enable_storage_encryption_impl(ptr, rust_vec_len, data_len),
+        55 => wire_export_backup_file_impl(ptr, rust_vec_len, data_len),
+        12 => wire_export_crew_xml_impl(ptr, rust_vec_len, data_len),
+        11 => wire_export_draft_xml_impl(ptr, rust_vec_len, data_len),
+        22 => wire_export_roster_impl(ptr, rust_vec_len, data_len),
+        47 => wire_export_worker_data_impl(ptr, rust_vec_len, data_len),
+        1 => wire_export_xml_api_impl(ptr, rust_vec_len, data_len),
+        23 => wire_find_duplicate_workers_impl(ptr, rust_vec_len, data_len),
+        45 => wire_get_audit_log_impl(ptr, rust_vec_len, data_len),
+        14 => wire_get_crew_workers_impl(ptr, rust_vec_len, data_len),
+        13 => wire_get_crews_impl(ptr, rust_vec_len, data_len),
+        6 => wire_get_draft_impl(ptr, rust_vec_len, data_len),
+        5 => wire_get_drafts_impl(ptr, rust_vec_len, data_len),
+        28 => wire_get_empty_worker_impl(ptr, rust_vec_len, data_len),
+        2 => wire_get_export_history_impl(ptr, rust_vec_len, data_len),
+        3 => wire_get_export_xml_impl(ptr, rust_vec_len, data_len),
+        44 => wire_get_history_impl(ptr, rust_vec_len, data_len),
+        58 => wire_get_import_profiles_impl(ptr, rust_vec_len, data_len),
+        33 => wire_get_selected_workers_impl(ptr, rust_vec_len, data_len),
+        31 => wire_get_selection_session_impl(ptr, rust_vec_len, data_len),
+        50 => wire_get_settings_impl(ptr, rust_vec_len, data_len),
+        61 => wire_get_spreadsheet_headers_impl(ptr, rust_vec_len, data_len),
+        46 => wire_get_worker_data_report_impl(ptr, rust_vec_len, data_len),
+        20 => wire_get_workers_impl(ptr, rust_vec_len, data_len),
+        24 => wire_get_workers_page_impl(ptr, rust_vec_len, data_len),
+        72 => wire_has_pin_impl(ptr, rust_vec_len, data_len),
+        57 => wire_import_backup_file_impl(ptr, rust_vec_len, data_len),
+        63 => wire_import_spreadsheet_impl(ptr, rust_vec_len, data_len),
+        65 => wire_import_xml_files_impl(ptr, rust_vec_len, data_len),
+        37 => wire_invert_selection_impl(ptr, rust_vec_len, data_len),
+        73 => wire_is_locked_impl(ptr, rust_vec_len, data_len),
+        66 => wire_is_storage_encrypted_impl(ptr, rust_vec_len, data_len),
+        67 => wire_is_storage_unlocked_impl(ptr, rust_vec_len, data_len),
+        52 => wire_list_snapshots_impl(ptr, rust_vec_len, data_len),
+        74 => wire_lock_app_impl(ptr, rust_vec_len, data_len),
+        32 => wire_open_selection_session_impl(ptr, rust_vec_len, data_len),
+        56 => wire_preview_backup_import_impl(ptr, rust_vec_len, data_len),
+        48 => wire_preview_retention_impl(ptr, rust_vec_len, data_len),
+        62 => wire_preview_spreadsheet_import_impl(ptr, rust_vec_len, data_len),
+        64 => wire_preview_xml_import_impl(ptr, rust_vec_len, data_len),
+        21 => wire_query_workers_api_impl(ptr, rust_vec_len, data_len),
+        41 => wire_redo_impl(ptr, rust_vec_len, data_len),
+        77 => wire_remove_pin_impl(ptr, rust_vec_len, data_len),
+        30 => wire_remove_worker_api_impl(ptr, rust_vec_len, data_len),
+        4 => wire_resave_export_impl(ptr, rust_vec_len, data_len),
+        54 => wire_restore_snapshot_impl(ptr, rust_vec_len, data_len),
+        49 => wire_run_retention_impl(ptr, rust_vec_len, data_len),
+        8 => wire_save_draft_impl(ptr, rust_vec_len, data_len),
+        59 => wire_save_import_profile_impl(ptr, rust_vec_len, data_len),
+        35 => wire_select_all_workers_impl(ptr, rust_vec_len, data_len),
+        38 => wire_select_workers_by_ids_impl(ptr, rust_vec_len, data_len),
+        39 => wire_select_workers_by_query_impl(ptr, rust_vec_len, data_len),
+        76 => wire_set_pin_impl(ptr, rust_vec_len, data_len),
+        51 => wire_set_settings_impl(ptr, rust_vec_len, data_len),
+        34 => wire_set_worker_selected_impl(ptr, rust_vec_len, data_len),
+        40 => wire_undo_impl(ptr, rust_vec_len, data_len),
+        75 => wire_unlock_app_impl(ptr, rust_vec_len, data_len),
+        68 => wire_unlock_storage_impl(ptr, rust_vec_len, data_len),
+        17 => wire_update_crew_impl(ptr, rust_vec_len, data_len),
+        29 => wire_update_worker_impl(ptr, rust_vec_len, data_len),
+        10 => wire_validate_draft_impl(ptr, rust_vec_len, data_len),
+        79 => wire_Worker_cloned_impl(ptr, rust_vec_len, data_len),
+        81 => wire_Worker_has_valid_birthdate_impl(ptr, rust_vec_len, data_len),
+        80 => wire_Worker_set_selected_impl(ptr, rust_vec_len, data_len),
+        82 => wire_Worker_validation_errors_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::audit::AuditAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Add => 0.into_dart(),
+            Self::Update => 1.into_dart(),
+            Self::Remove => 2.into_dart(),
+            Self::Select => 3.into_dart(),
+            Self::Export => 4.into_dart(),
+            Self::Restore => 5.into_dart(),
+            Self::Import => 6.into_dart(),
+            Self::SubjectAccess => 7.into_dart(),
+            Self::Anonymize => 8.into_dart(),
+            Self::RosterExport => 9.into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audit::AuditAction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audit::AuditAction>
+    for crate::api::audit::AuditAction
+{
+    fn into_into_dart(self) -> crate::api::audit::AuditAction {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::audit::AuditEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.operator.into_into_dart().into_dart(),
+            self.action.into_into_dart().into_dart(),
+            self.worker_ids.into_into_dart().into_dart(),
+            self.changes.into_into_dart().into_dart(),
+            self.note.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::audit::AuditEntry {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audit::AuditEntry>
+    for crate::api::audit::AuditEntry
+{
+    fn into_into_dart(self) -> crate::api::audit::AuditEntry {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::spreadsheet_import::ColumnMapping {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.header.into_into_dart().into_dart(),
+            self.field.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::spreadsheet_import::ColumnMapping
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::spreadsheet_import::ColumnMapping>
+    for crate::api::spreadsheet_import::ColumnMapping
+{
+    fn into_into_dart(self) -> crate::api::spreadsheet_import::ColumnMapping {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::crew::Crew {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.worker_ids.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::crew::Crew {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::crew::Crew> for crate::api::crew::Crew {
+    fn into_into_dart(self) -> crate::api::crew::Crew {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::events::DbEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::events::DbEvent::WorkerAdded { worker } => {
+                [0.into_dart(), worker.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::events::DbEvent::WorkerUpdated { worker } => {
+                [1.into_dart(), worker.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::events::DbEvent::WorkerRemoved { id } => {
+                [2.into_dart(), id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::events::DbEvent::SelectionChanged { id, selected } => [
+                3.into_dart(),
+                id.into_into_dart().into_dart(),
+                selected.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::DbEvent::SelectionsChanged {
+                selected,
+                unselected,
+            } => [
+                4.into_dart(),
+                selected.into_into_dart().into_dart(),
+                unselected.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::DbEvent::ExportDone { path, worker_ids } => [
+                5.into_dart(),
+                path.into_into_dart().into_dart(),
+                worker_ids.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::DbEvent::Conflict { local, deleted } => [
+                6.into_dart(),
+                local.into_into_dart().into_dart(),
+                deleted.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::events::DbEvent::Reloaded => [7.into_dart()].into_dart(),
+            crate::api::events::DbEvent::Changed { ids } => {
+                [8.into_dart(), ids.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::events::DbEvent {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::events::DbEvent>
+    for crate::api::events::DbEvent
+{
+    fn into_into_dart(self) -> crate::api::events::DbEvent {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::draft::DraftIssue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.worker_id.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::draft::DraftIssue {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::draft::DraftIssue>
+    for crate::api::draft::DraftIssue
+{
+    fn into_into_dart(self) -> crate::api::draft::DraftIssue {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::draft::DraftRow {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.worker_id.into_into_dart().into_dart(),
+            self.overrides.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::draft::DraftRow {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::draft::DraftRow>
+    for crate::api::draft::DraftRow
+{
+    fn into_into_dart(self) -> crate::api::draft::DraftRow {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::settings::Employer {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tax_number.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.phone.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::settings::Employer
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::settings::Employer>
+    for crate::api::settings::Employer
+{
+    fn into_into_dart(self) -> crate::api::settings::Employer {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::privacy::ExportAppearance {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.export_id.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.report_date.into_into_dart().into_dart(),
+            self.operator.into_into_dart().into_dart(),
+            self.file.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::privacy::ExportAppearance
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::privacy::ExportAppearance>
+    for crate::api::privacy::ExportAppearance
+{
+    fn into_into_dart(self) -> crate::api::privacy::ExportAppearance {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::export_history::ExportRecord {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.operator.into_into_dart().into_dart(),
+            self.employer.into_into_dart().into_dart(),
+            self.report_date.into_into_dart().into_dart(),
+            self.worker_ids.into_into_dart().into_dart(),
+            self.workers.into_into_dart().into_dart(),
+            self.file_name.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+            self.sha256.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::export_history::ExportRecord
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::export_history::ExportRecord>
+    for crate::api::export_history::ExportRecord
+{
+    fn into_into_dart(self) -> crate::api::export_history::ExportRecord {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::audit::FieldChange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.before.into_into_dart().into_dart(),
+            self.after.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::audit::FieldChange
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audit::FieldChange>
+    for crate::api::audit::FieldChange
+{
+    fn into_into_dart(self) -> crate::api::audit::FieldChange {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::history::HistoryAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Add => 0.into_dart(),
+            Self::Update => 1.into_dart(),
+            Self::Remove => 2.into_dart(),
+            Self::Select => 3.into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::history::HistoryAction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::history::HistoryAction>
+    for crate::api::history::HistoryAction
+{
+    fn into_into_dart(self) -> crate::api::history::HistoryAction {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::history::HistoryItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.action.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.worker_ids.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::history::HistoryItem
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::history::HistoryItem>
+    for crate::api::history::HistoryItem
+{
+    fn into_into_dart(self) -> crate::api::history::HistoryItem {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::backup::ImportMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Merge => 0.into_dart(),
+            Self::Replace => 1.into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::backup::ImportMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::backup::ImportMode>
+    for crate::api::backup::ImportMode
+{
+    fn into_into_dart(self) -> crate::api::backup::ImportMode {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::spreadsheet_import::ImportProfile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.columns.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::spreadsheet_import::ImportProfile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::spreadsheet_import::ImportProfile>
+    for crate::api::spreadsheet_import::ImportProfile
+{
+    fn into_into_dart(self) -> crate::api::spreadsheet_import::ImportProfile {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::spreadsheet_import::ImportRow {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.row.into_into_dart().into_dart(),
+            self.worker.into_into_dart().into_dart(),
+            self.errors.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::spreadsheet_import::ImportRow
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::spreadsheet_import::ImportRow>
+    for crate::api::spreadsheet_import::ImportRow
+{
+    fn into_into_dart(self) -> crate::api::spreadsheet_import::ImportRow {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::backup::ImportSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.added.into_into_dart().into_dart(),
+            self.updated.into_into_dart().into_dart(),
+            self.removed.into_into_dart().into_dart(),
+            self.unchanged.into_into_dart().into_dart(),
+            self.replaces_settings.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::backup::ImportSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::backup::ImportSummary>
+    for crate::api::backup::ImportSummary
+{
+    fn into_into_dart(self) -> crate::api::backup::ImportSummary {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::draft::ReportDraft {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.date.into_into_dart().into_dart(),
+            self.employer.into_into_dart().into_dart(),
+            self.category.into_into_dart().into_dart(),
+            self.rows.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+            self.exported_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::draft::ReportDraft
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::draft::ReportDraft>
+    for crate::api::draft::ReportDraft
+{
+    fn into_into_dart(self) -> crate::api::draft::ReportDraft {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::export_history::ReportedWorker {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.worker_id.into_into_dart().into_dart(),
+            self.category.into_into_dart().into_dart(),
+            self.days.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::export_history::ReportedWorker
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::export_history::ReportedWorker>
+    for crate::api::export_history::ReportedWorker
+{
+    fn into_into_dart(self) -> crate::api::export_history::ReportedWorker {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::privacy::RetentionCandidate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.last_activity.into_into_dart().into_dart(),
+            self.reported_days.into_into_dart().into_dart(),
+            self.kept_exports.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::privacy::RetentionCandidate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::privacy::RetentionCandidate>
+    for crate::api::privacy::RetentionCandidate
+{
+    fn into_into_dart(self) -> crate::api::privacy::RetentionCandidate {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::roster_export::RosterColumn {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Name => 0.into_dart(),
+            Self::Address => 1.into_dart(),
+            Self::Taj => 2.into_dart(),
+            Self::Taxnumber => 3.into_dart(),
+            Self::Mothersname => 4.into_dart(),
+            Self::Birthdate => 5.into_dart(),
+            Self::Birthplace => 6.into_dart(),
+            Self::LastWorked => 7.into_dart(),
+            Self::ReportedDays => 8.into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::roster_export::RosterColumn
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::roster_export::RosterColumn>
+    for crate::api::roster_export::RosterColumn
+{
+    fn into_into_dart(self) -> crate::api::roster_export::RosterColumn {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::roster_export::RosterFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Csv => 0.into_dart(),
+            Self::Xlsx => 1.into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::roster_export::RosterFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::roster_export::RosterFormat>
+    for crate::api::roster_export::RosterFormat
+{
+    fn into_into_dart(self) -> crate::api::roster_export::RosterFormat {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::draft::RowOverride {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.taj.into_into_dart().into_dart(),
+            self.taxnumber.into_into_dart().into_dart(),
+            self.category.into_into_dart().into_dart(),
+            self.days.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::draft::RowOverride
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::draft::RowOverride>
+    for crate::api::draft::RowOverride
+{
+    fn into_into_dart(self) -> crate::api::draft::RowOverride {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::session::SelectionSession {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.employer.into_into_dart().into_dart(),
+            self.date.into_into_dart().into_dart(),
+            self.worker_ids.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::session::SelectionSession
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::session::SelectionSession>
+    for crate::api::session::SelectionSession
+{
+    fn into_into_dart(self) -> crate::api::session::SelectionSession {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::settings::Settings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.operator.into_into_dart().into_dart(),
+            self.backup_keep.into_into_dart().into_dart(),
+            self.auto_lock_minutes.into_into_dart().into_dart(),
+            self.retention_years.into_into_dart().into_dart(),
+            self.employers.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::settings::Settings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::settings::Settings>
+    for crate::api::settings::Settings
+{
+    fn into_into_dart(self) -> crate::api::settings::Settings {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::backup::Snapshot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::backup::Snapshot {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::backup::Snapshot>
+    for crate::api::backup::Snapshot
+{
+    fn into_into_dart(self) -> crate::api::backup::Snapshot {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::backup::SnapshotReason {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Daily => 0.into_dart(),
+            Self::Export => 1.into_dart(),
+            Self::Manual => 2.into_dart(),
+            Self::Restore => 3.into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::backup::SnapshotReason
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::backup::SnapshotReason>
+    for crate::api::backup::SnapshotReason
+{
+    fn into_into_dart(self) -> crate::api::backup::SnapshotReason {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::spreadsheet_import::SpreadsheetImport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rows.into_into_dart().into_dart(),
+            self.valid.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::spreadsheet_import::SpreadsheetImport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::spreadsheet_import::SpreadsheetImport>
+    for crate::api::spreadsheet_import::SpreadsheetImport
+{
+    fn into_into_dart(self) -> crate::api::spreadsheet_import::SpreadsheetImport {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::privacy::SubjectReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.generated_at.into_into_dart().into_dart(),
+            self.worker.into_into_dart().into_dart(),
+            self.reported_days.into_into_dart().into_dart(),
+            self.exports.into_into_dart().into_dart(),
+            self.audit_entries.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::privacy::SubjectReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::privacy::SubjectReport>
+    for crate::api::privacy::SubjectReport
+{
+    fn into_into_dart(self) -> crate::api::privacy::SubjectReport {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::worker::UpdateError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::worker::UpdateError::Conflict { local, remote } => [
+                0.into_dart(),
+                local.into_into_dart().into_dart(),
+                remote.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::worker::UpdateError::Failed(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::worker::UpdateError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::worker::UpdateError>
+    for crate::api::worker::UpdateError
+{
+    fn into_into_dart(self) -> crate::api::worker::UpdateError {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::worker::Worker {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.taj.into_into_dart().into_dart(),
+            self.taxnumber.into_into_dart().into_dart(),
+            self.mothersname.into_into_dart().into_dart(),
+            self.birthdate.into_into_dart().into_dart(),
+            self.birthplace.into_into_dart().into_dart(),
+            self.zip.into_into_dart().into_dart(),
+            self.city.into_into_dart().into_dart(),
+            self.street.into_into_dart().into_dart(),
+            self.is_selected.into_into_dart().into_dart(),
+            self.anonymized_at.into_into_dart().into_dart(),
+            self.revision.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::worker::Worker {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::worker::Worker> for crate::api::worker::Worker {
+    fn into_into_dart(self) -> crate::api::worker::Worker {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::spreadsheet_import::WorkerField {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Name => 0.into_dart(),
+            Self::Taj => 1.into_dart(),
+            Self::Taxnumber => 2.into_dart(),
+            Self::Mothersname => 3.into_dart(),
+            Self::Birthdate => 4.into_dart(),
+            Self::Birthplace => 5.into_dart(),
+            Self::Zip => 6.into_dart(),
+            Self::City => 7.into_dart(),
+            Self::Street => 8.into_dart(),
+            Self::Tags => 9.into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::spreadsheet_import::WorkerField
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::spreadsheet_import::WorkerField>
+    for crate::api::spreadsheet_import::WorkerField
+{
+    fn into_into_dart(self) -> crate::api::spreadsheet_import::WorkerField {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::query::WorkerPage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.workers.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+            self.offset.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::query::WorkerPage {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query::WorkerPage>
+    for crate::api::query::WorkerPage
+{
+    fn into_into_dart(self) -> crate::api::query::WorkerPage {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::query::WorkerQuery {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.text.into_into_dart().into_dart(),
+            self.selected.into_into_dart().into_dart(),
+            self.city.into_into_dart().into_dart(),
+            self.has_validation_errors.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.last_worked_from.into_into_dart().into_dart(),
+            self.last_worked_to.into_into_dart().into_dart(),
+            self.sort.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::query::WorkerQuery
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query::WorkerQuery>
+    for crate::api::query::WorkerQuery
+{
+    fn into_into_dart(self) -> crate::api::query::WorkerQuery {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::query::WorkerSort {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.descending.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::query::WorkerSort {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query::WorkerSort>
+    for crate::api::query::WorkerSort
+{
+    fn into_into_dart(self) -> crate::api::query::WorkerSort {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::query::WorkerSortKey {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Name => 0.into_dart(),
+            Self::City => 1.into_dart(),
+            Self::BirthDate => 2.into_dart(),
+            Self::Selected => 3.into_dart(),
+            Self::LastWorked => 4.into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::query::WorkerSortKey
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query::WorkerSortKey>
+    for crate::api::query::WorkerSortKey
+{
+    fn into_into_dart(self) -> crate::api::query::WorkerSortKey {
+        self
+    }
+}
+impl flutter_rust_bridge::IntoDart for crate::api::xml_import::XmlImportSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.files.into_into_dart().into_dart(),
+            self.skipped.into_into_dart().into_dart(),
+            self.added.into_into_dart().into_dart(),
+            self.matched.into_into_dart().into_dart(),
+            self.reports.into_into_dart().into_dart(),
+            self.errors.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::xml_import::XmlImportSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::xml_import::XmlImportSummary>
+    for crate::api::xml_import::XmlImportSummary
+{
+    fn into_into_dart(self) -> crate::api::xml_import::XmlImportSummary {
+        self
+    }
+}
+
+impl SseEncode for chrono::DateTime<chrono::Local> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.timestamp_micros(), serializer);
+    }
+}
+
+impl SseEncode for chrono::NaiveDateTime {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.timestamp_micros(), serializer);
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for uuid::Uuid {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.as_bytes().to_vec(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::audit::AuditAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::audit::AuditAction::Add => 0,
+                crate::api::audit::AuditAction::Update => 1,
+                crate::api::audit::AuditAction::Remove => 2,
+                crate::api::audit::AuditAction::Select => 3,
+                crate::api::audit::AuditAction::Export => 4,
+                crate::api::audit::AuditAction::Restore => 5,
+                crate::api::audit::AuditAction::Import => 6,
+                crate::api::audit::AuditAction::SubjectAccess => 7,
+                crate::api::audit::AuditAction::Anonymize => 8,
+                crate::api::audit::AuditAction::RosterExport => 9,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::audit::AuditEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <uuid::Uuid>::sse_encode(self.id, serializer);
+        <chrono::DateTime<chrono::Local>>::sse_encode(self.timestamp, serializer);
+        <String>::sse_encode(self.operator, serializer);
+        <crate::api::audit::AuditAction>::sse_encode(self.action, serializer);
+        <Vec<uuid::Uuid>>::sse_encode(self.worker_ids, serializer);
+        <Vec<crate::api::audit::FieldChange>>::sse_encode(self.changes, serializer);
+        <String>::sse_encode(self.note, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for Box<crate::api::worker::Worker> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::worker::Worker>::sse_encode(*self, serializer);
+    }
+}
+
+impl SseEncode for crate::api::spreadsheet_import::ColumnMapping {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.header, serializer);
+        <crate::api::spreadsheet_import::WorkerField>::sse_encode(self.field, serializer);
+    }
+}
+
+impl SseEncode for crate::api::crew::Crew {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <uuid::Uuid>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Vec<uuid::Uuid>>::sse_encode(self.worker_ids, serializer);
+        <chrono::DateTime<chrono::Local>>::sse_encode(self.updated_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::events::DbEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::events::DbEvent::WorkerAdded { worker } => {
+                <i32>::sse_encode(0, serializer);
+                <crate::api::worker::Worker>::sse_encode(worker, serializer);
+            }
+            crate::api::events::DbEvent::WorkerUpdated { worker } => {
+                <i32>::sse_encode(1, serializer);
+                <crate::api::worker::Worker>::sse_encode(worker, serializer);
+            }
+            crate::api::events::DbEvent::WorkerRemoved { id } => {
+                <i32>::sse_encode(2, serializer);
+                <uuid::Uuid>::sse_encode(id, serializer);
+            }
+            crate::api::events::DbEvent::SelectionChanged { id, selected } => {
+                <i32>::sse_encode(3, serializer);
+                <uuid::Uuid>::sse_encode(id, serializer);
+                <bool>::sse_encode(selected, serializer);
+            }
+            crate::api::events::DbEvent::SelectionsChanged {
+                selected,
+                unselected,
+            } => {
+                <i32>::sse_encode(4, serializer);
+                <Vec<uuid::Uuid>>::sse_encode(selected, serializer);
+                <Vec<uuid::Uuid>>::sse_encode(unselected, serializer);
+            }
+            crate::api::events::DbEvent::ExportDone { path, worker_ids } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(path, serializer);
+                <Vec<uuid::Uuid>>::sse_encode(worker_ids, serializer);
+            }
+            crate::api::events::DbEvent::Conflict { local, deleted } => {
+                <i32>::sse_encode(6, serializer);
+                <crate::api::worker::Worker>::sse_encode(local, serializer);
+                <bool>::sse_encode(deleted, serializer);
+            }
+            crate::api::events::DbEvent::Reloaded => {
+                <i32>::sse_encode(7, serializer);
+            }
+            crate::api::events::DbEvent::Changed { ids } => {
+                <i32>::sse_encode(8, serializer);
+                <Vec<uuid::Uuid>>::sse_encode(ids, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::draft::DraftIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<uuid::Uuid>>::sse_encode(self.worker_id, serializer);
+        <String>::sse_encode(self.message, serializer);
+    }
+}
+
+impl SseEncode for crate::api::draft::DraftRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <uuid::Uuid>::sse_encode(self.worker_id, serializer);
+        <crate::api::draft::RowOverride>::sse_encode(self.overrides, serializer);
+    }
+}
+
+impl SseEncode for crate::api::settings::Employer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.tax_number, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.phone, serializer);
+    }
+}
+
+impl SseEncode for crate::api::privacy::ExportAppearance {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<uuid::Uuid>>::sse_encode(self.export_id, serializer);
+        <chrono::DateTime<chrono::Local>>::sse_encode(self.timestamp, serializer);
+        <String>::sse_encode(self.report_date, serializer);
+        <String>::sse_encode(self.operator, serializer);
+        <String>::sse_encode(self.file, serializer);
+    }
+}
+
+impl SseEncode for crate::api::export_history::ExportRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <uuid::Uuid>::sse_encode(self.id, serializer);
+        <chrono::DateTime<chrono::Local>>::sse_encode(self.timestamp, serializer);
+        <String>::sse_encode(self.operator, serializer);
+        <crate::api::settings::Employer>::sse_encode(self.employer, serializer);
+        <String>::sse_encode(self.report_date, serializer);
+        <Vec<uuid::Uuid>>::sse_encode(self.worker_ids, serializer);
+        <Vec<crate::api::export_history::ReportedWorker>>::sse_encode(self.workers, serializer);
+        <String>::sse_encode(self.file_name, serializer);
+        <String>::sse_encode(self.path, serializer);
+        <String>::sse_encode(self.sha256, serializer);
+    }
+}
+
+impl SseEncode for crate::api::audit::FieldChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.field, serializer);
+        <String>::sse_encode(self.before, serializer);
+        <String>::sse_encode(self.after, serializer);
+    }
+}
+
+impl SseEncode for crate::api::history::HistoryAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::history::HistoryAction::Add => 0,
+                crate::api::history::HistoryAction::Update => 1,
+                crate::api::history::HistoryAction::Remove => 2,
+                crate::api::history::HistoryAction::Select => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::history::HistoryItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <uuid::Uuid>::sse_encode(self.id, serializer);
+        <crate::api::history::HistoryAction>::sse_encode(self.action, serializer);
+        <String>::sse_encode(self.description, serializer);
+        <Vec<uuid::Uuid>>::sse_encode(self.worker_ids, serializer);
+        <chrono::DateTime<chrono::Local>>::sse_encode(self.created_at, serializer);
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::backup::ImportMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::backup::ImportMode::Merge => 0,
+                crate::api::backup::ImportMode::Replace => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::spreadsheet_import::ImportProfile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <Vec<crate::api::spreadsheet_import::ColumnMapping>>::sse_encode(self.columns, serializer);
+    }
+}
+
+impl SseEncode for crate::api::spreadsheet_import::ImportRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.row, serializer);
+        <crate::api::worker::Worker>::sse_encode(self.worker, serializer);
+        <Vec<String>>::sse_encode(self.errors, serializer);
+    }
+}
+
+impl SseEncode for crate::api::backup::ImportSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.added, serializer);
+        <Vec<String>>::sse_encode(self.updated, serializer);
+        <Vec<String>>::sse_encode(self.removed, serializer);
+        <u32>::sse_encode(self.unchanged, serializer);
+        <bool>::sse_encode(self.replaces_settings, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<uuid::Uuid> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <uuid::Uuid>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::audit::AuditEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::audit::AuditEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::spreadsheet_import::ColumnMapping> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::spreadsheet_import::ColumnMapping>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::crew::Crew> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::crew::Crew>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::draft::DraftIssue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::draft::DraftIssue>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::draft::DraftRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::draft::DraftRow>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::settings::Employer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::settings::Employer>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::privacy::ExportAppearance> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::privacy::ExportAppearance>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::export_history::ExportRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::export_history::ExportRecord>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::audit::FieldChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::audit::FieldChange>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::history::HistoryItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::history::HistoryItem>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::spreadsheet_import::ImportProfile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::spreadsheet_import::ImportProfile>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::spreadsheet_import::ImportRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::spreadsheet_import::ImportRow>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Vec<crate::api::draft::ReportDraft> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::draft::ReportDraft>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::worker::Worker> {
+impl SseEncode for Vec<crate::api::export_history::ReportedWorker> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::worker::Worker>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::export_history::ReportedWorker>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Vec<crate::api::privacy::RetentionCandidate> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::privacy::RetentionCandidate>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Vec<crate::api::roster_export::RosterColumn> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::roster_export::RosterColumn>::sse_encode(item, serializer);
+        }
+    }
 }
 
-impl SseDecode for crate::api::worker::Worker {
+impl SseEncode for Vec<crate::api::backup::Snapshot> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <uuid::Uuid>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_taj = <String>::sse_decode(deserializer);
-        let mut var_taxnumber = <String>::sse_decode(deserializer);
-        let mut var_mothersname = <String>::sse_decode(deserializer);
-        let mut var_birthdate = <String>::sse_decode(deserializer);
-        let mut var_birthplace = <String>::sse_decode(deserializer);
-        let mut var_zip = <String>::sse_decode(deserializer);
-        let mut var_city = <String>::sse_decode(deserializer);
-        let mut var_street = <String>::sse_decode(deserializer);
-        let mut var_isSelected = <bool>::sse_decode(deserializer);
-        return crate::api::worker::Worker {
-            id: var_id,
-            name: var_name,
-            taj: var_taj,
-            taxnumber: var_taxnumber,
-            mothersname: var_mothersname,
-            birthdate: var_birthdate,
-            birthplace: var_birthplace,
-            zip: var_zip,
-            city: var_city,
-            street: var_street,
-            is_selected: var_isSelected,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::backup::Snapshot>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Vec<crate::api::worker::Worker> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::worker::Worker>::sse_encode(item, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        7 => wire_init_app_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Vec<crate::api::query::WorkerSort> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::query::WorkerSort>::sse_encode(item, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        3 => wire_add_worker_impl(ptr, rust_vec_len, data_len),
-        1 => wire_export_xml_api_impl(ptr, rust_vec_len, data_len),
-        4 => wire_get_empty_worker_impl(ptr, rust_vec_len, data_len),
-        2 => wire_get_workers_impl(ptr, rust_vec_len, data_len),
-        6 => wire_remove_worker_api_impl(ptr, rust_vec_len, data_len),
-        5 => wire_update_worker_impl(ptr, rust_vec_len, data_len),
-        8 => wire_Worker_cloned_impl(ptr, rust_vec_len, data_len),
-        10 => wire_Worker_has_valid_birthdate_impl(ptr, rust_vec_len, data_len),
-        9 => wire_Worker_set_selected_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
 
-// Section: rust2dart
+impl SseEncode for Option<uuid::Uuid> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <uuid::Uuid>::sse_encode(value, serializer);
+        }
+    }
+}
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::worker::Worker {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.taj.into_into_dart().into_dart(),
-            self.taxnumber.into_into_dart().into_dart(),
-            self.mothersname.into_into_dart().into_dart(),
-            self.birthdate.into_into_dart().into_dart(),
-            self.birthplace.into_into_dart().into_dart(),
-            self.zip.into_into_dart().into_dart(),
-            self.city.into_into_dart().into_dart(),
-            self.street.into_into_dart().into_dart(),
-            self.is_selected.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for Option<chrono::DateTime<chrono::Local>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <chrono::DateTime<chrono::Local>>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::worker::Worker {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::worker::Worker> for crate::api::worker::Worker {
-    fn into_into_dart(self) -> crate::api::worker::Worker {
-        self
+
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Option<crate::api::history::HistoryItem> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::history::HistoryItem>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for uuid::Uuid {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.as_bytes().to_vec(), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::draft::ReportDraft {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <uuid::Uuid>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.date, serializer);
+        <String>::sse_encode(self.employer, serializer);
+        <String>::sse_encode(self.category, serializer);
+        <Vec<crate::api::draft::DraftRow>>::sse_encode(self.rows, serializer);
+        <chrono::DateTime<chrono::Local>>::sse_encode(self.updated_at, serializer);
+        <Option<chrono::DateTime<chrono::Local>>>::sse_encode(self.exported_at, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::export_history::ReportedWorker {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <uuid::Uuid>::sse_encode(self.worker_id, serializer);
+        <String>::sse_encode(self.category, serializer);
+        <u32>::sse_encode(self.days, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::worker::Worker> {
+impl SseEncode for crate::api::privacy::RetentionCandidate {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::worker::Worker>::sse_encode(item, serializer);
-        }
+        <uuid::Uuid>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<chrono::DateTime<chrono::Local>>>::sse_encode(self.last_activity, serializer);
+        <u32>::sse_encode(self.reported_days, serializer);
+        <u32>::sse_encode(self.kept_exports, serializer);
+    }
+}
+
+impl SseEncode for crate::api::roster_export::RosterColumn {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::roster_export::RosterColumn::Name => 0,
+                crate::api::roster_export::RosterColumn::Address => 1,
+                crate::api::roster_export::RosterColumn::Taj => 2,
+                crate::api::roster_export::RosterColumn::Taxnumber => 3,
+                crate::api::roster_export::RosterColumn::Mothersname => 4,
+                crate::api::roster_export::RosterColumn::Birthdate => 5,
+                crate::api::roster_export::RosterColumn::Birthplace => 6,
+                crate::api::roster_export::RosterColumn::LastWorked => 7,
+                crate::api::roster_export::RosterColumn::ReportedDays => 8,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::roster_export::RosterFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::roster_export::RosterFormat::Csv => 0,
+                crate::api::roster_export::RosterFormat::Xlsx => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::draft::RowOverride {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.taj, serializer);
+        <Option<String>>::sse_encode(self.taxnumber, serializer);
+        <Option<String>>::sse_encode(self.category, serializer);
+        <Option<u32>>::sse_encode(self.days, serializer);
+    }
+}
+
+impl SseEncode for crate::api::session::SelectionSession {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.employer, serializer);
+        <String>::sse_encode(self.date, serializer);
+        <Vec<uuid::Uuid>>::sse_encode(self.worker_ids, serializer);
+    }
+}
+
+impl SseEncode for crate::api::settings::Settings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.operator, serializer);
+        <u32>::sse_encode(self.backup_keep, serializer);
+        <u32>::sse_encode(self.auto_lock_minutes, serializer);
+        <u32>::sse_encode(self.retention_years, serializer);
+        <Vec<crate::api::settings::Employer>>::sse_encode(self.employers, serializer);
+    }
+}
+
+impl SseEncode for crate::api::backup::Snapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <chrono::NaiveDateTime>::sse_encode(self.created_at, serializer);
+        <crate::api::backup::SnapshotReason>::sse_encode(self.reason, serializer);
+        <u64>::sse_encode(self.size, serializer);
+    }
+}
+
+impl SseEncode for crate::api::backup::SnapshotReason {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::backup::SnapshotReason::Daily => 0,
+                crate::api::backup::SnapshotReason::Export => 1,
+                crate::api::backup::SnapshotReason::Manual => 2,
+                crate::api::backup::SnapshotReason::Restore => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::spreadsheet_import::SpreadsheetImport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::spreadsheet_import::ImportRow>>::sse_encode(self.rows, serializer);
+        <u32>::sse_encode(self.valid, serializer);
+    }
+}
+
+impl SseEncode for crate::api::privacy::SubjectReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <chrono::DateTime<chrono::Local>>::sse_encode(self.generated_at, serializer);
+        <crate::api::worker::Worker>::sse_encode(self.worker, serializer);
+        <Vec<String>>::sse_encode(self.reported_days, serializer);
+        <Vec<crate::api::privacy::ExportAppearance>>::sse_encode(self.exports, serializer);
+        <Vec<crate::api::audit::AuditEntry>>::sse_encode(self.audit_entries, serializer);
+    }
+}
+
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
     }
 }
 
@@ -542,6 +5223,36 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for crate::api::worker::UpdateError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::worker::UpdateError::Conflict { local, remote } => {
+                <i32>::sse_encode(0, serializer);
+                <Box<crate::api::worker::Worker>>::sse_encode(local, serializer);
+                <Box<crate::api::worker::Worker>>::sse_encode(remote, serializer);
+            }
+            crate::api::worker::UpdateError::Failed(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer
+            .cursor
+            .write_u64::<NativeEndian>(self as _)
+            .unwrap();
+    }
+}
+
 impl SseEncode for crate::api::worker::Worker {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -556,6 +5267,95 @@ impl SseEncode for crate::api::worker::Worker {
         <String>::sse_encode(self.city, serializer);
         <String>::sse_encode(self.street, serializer);
         <bool>::sse_encode(self.is_selected, serializer);
+        <Option<chrono::DateTime<chrono::Local>>>::sse_encode(self.anonymized_at, serializer);
+        <u64>::sse_encode(self.revision, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
+    }
+}
+
+impl SseEncode for crate::api::spreadsheet_import::WorkerField {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::spreadsheet_import::WorkerField::Name => 0,
+                crate::api::spreadsheet_import::WorkerField::Taj => 1,
+                crate::api::spreadsheet_import::WorkerField::Taxnumber => 2,
+                crate::api::spreadsheet_import::WorkerField::Mothersname => 3,
+                crate::api::spreadsheet_import::WorkerField::Birthdate => 4,
+                crate::api::spreadsheet_import::WorkerField::Birthplace => 5,
+                crate::api::spreadsheet_import::WorkerField::Zip => 6,
+                crate::api::spreadsheet_import::WorkerField::City => 7,
+                crate::api::spreadsheet_import::WorkerField::Street => 8,
+                crate::api::spreadsheet_import::WorkerField::Tags => 9,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::query::WorkerPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::worker::Worker>>::sse_encode(self.workers, serializer);
+        <u32>::sse_encode(self.total, serializer);
+        <u32>::sse_encode(self.offset, serializer);
+    }
+}
+
+impl SseEncode for crate::api::query::WorkerQuery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.text, serializer);
+        <Option<bool>>::sse_encode(self.selected, serializer);
+        <Option<String>>::sse_encode(self.city, serializer);
+        <Option<bool>>::sse_encode(self.has_validation_errors, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
+        <Option<String>>::sse_encode(self.last_worked_from, serializer);
+        <Option<String>>::sse_encode(self.last_worked_to, serializer);
+        <Vec<crate::api::query::WorkerSort>>::sse_encode(self.sort, serializer);
+    }
+}
+
+impl SseEncode for crate::api::query::WorkerSort {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::query::WorkerSortKey>::sse_encode(self.key, serializer);
+        <bool>::sse_encode(self.descending, serializer);
+    }
+}
+
+impl SseEncode for crate::api::query::WorkerSortKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::query::WorkerSortKey::Name => 0,
+                crate::api::query::WorkerSortKey::City => 1,
+                crate::api::query::WorkerSortKey::BirthDate => 2,
+                crate::api::query::WorkerSortKey::Selected => 3,
+                crate::api::query::WorkerSortKey::LastWorked => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::xml_import::XmlImportSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.files, serializer);
+        <Vec<String>>::sse_encode(self.skipped, serializer);
+        <Vec<String>>::sse_encode(self.added, serializer);
+        <u32>::sse_encode(self.matched, serializer);
+        <u32>::sse_encode(self.reports, serializer);
+        <Vec<String>>::sse_encode(self.errors, serializer);
     }
 }
 
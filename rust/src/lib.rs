@@ -1,3 +1,9 @@
 pub mod api;
+pub mod crypto;
+// The generated chrono conversions call functions newer chrono versions deprecate
+#[allow(deprecated)]
 mod frb_generated;
+#[cfg(test)]
+mod test_util;
+pub mod text;
 pub mod xml; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be accurate, and you can change it according to your needs. */
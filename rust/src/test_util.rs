@@ -0,0 +1,65 @@
+// Fixtures shared by the tests of several modules
+use chrono::{DateTime, Local};
+use uuid::Uuid;
+
+use crate::api::{
+    export_history::{one_day_each, ExportRecord},
+    settings::Employer,
+    worker::{parse_date, Db, Worker},
+};
+
+pub(crate) fn employer() -> Employer {
+    Employer {
+        tax_number: "23127182215".to_string(),
+        name: "Mezei Istvánné".to_string(),
+        phone: "06305236153".to_string(),
+    }
+}
+
+// Has every field a report needs
+pub(crate) fn worker(name: &str, taj: &str, taxnumber: &str) -> Worker {
+    Worker {
+        name: name.to_string(),
+        birthplace: "Pécs".to_string(),
+        mothersname: "Kiss Mária".to_string(),
+        city: "Pécs".to_string(),
+        street: "Fő utca 1.".to_string(),
+        zip: "7621".to_string(),
+        birthdate: "1990-01-01".to_string(),
+        taj: taj.to_string(),
+        taxnumber: taxnumber.to_string(),
+        ..Worker::default()
+    }
+}
+
+// Kiss Anna and Nagy Béla, the latter with a grouped TAJ
+pub(crate) fn setup() -> (Db, [Uuid; 2]) {
+    let mut db = Db::open().unwrap();
+    let workers = [
+        worker("Kiss Anna", "123456788", "12345678-1-02"),
+        worker("Nagy Béla", "987 654 321", "87654321-2-13"),
+    ];
+    for w in &workers {
+        db.add_new_worker(w.clone()).unwrap();
+    }
+    (db, workers.map(|w| w.id))
+}
+
+// A stored report with one day for each worker
+pub(crate) fn export(
+    ids: Vec<Uuid>,
+    date: &str,
+    path: &str,
+    timestamp: DateTime<Local>,
+) -> ExportRecord {
+    ExportRecord::create(
+        "op",
+        &employer(),
+        parse_date(date).unwrap(),
+        one_day_each(ids),
+        path,
+        "<nyomtatvanyok/>",
+        timestamp,
+    )
+    .unwrap()
+}
@@ -0,0 +1,221 @@
+use std::cmp::Ordering;
+
+// Lowercase without accents, for searching
+pub fn fold(s: &str) -> String {
+    s.chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' | 'ă' | 'ą' => 'a',
+            'é' | 'è' | 'ê' | 'ë' | 'ě' | 'ę' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'ö' | 'ő' | 'õ' | 'ø' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' | 'ű' | 'ů' => 'u',
+            'ý' | 'ÿ' => 'y',
+            'č' | 'ć' | 'ç' => 'c',
+            'š' | 'ś' | 'ş' => 's',
+            'ž' | 'ź' | 'ż' => 'z',
+            'ř' => 'r',
+            'ň' | 'ń' => 'n',
+            'ľ' | 'ĺ' | 'ł' => 'l',
+            'ť' | 'ţ' => 't',
+            'ď' => 'd',
+            c => c,
+        })
+        .collect()
+}
+
+// Whether every word of the search text is found in one of the fields
+pub fn matches_all(search: &str, fields: &[&str]) -> bool {
+    let fields = fields.iter().map(|f| fold(f)).collect::<Vec<String>>();
+    fold(search)
+        .split_whitespace()
+        .all(|term| fields.iter().any(|f| f.contains(term)))
+}
+
+// Hungarian alphabet, digraphs and trigraphs are letters on their own
+const ALPHABET: [&str; 37] = [
+    "a", "b", "c", "cs", "d", "dz", "dzs", "e", "f", "g", "gy", "h", "i", "j", "k", "l", "ly", "m",
+    "n", "ny", "o", "ö", "p", "q", "r", "s", "sz", "t", "ty", "u", "ü", "v", "w", "x", "y", "z",
+    "zs",
+];
+
+// Long vowels sort with the short ones, and only decide between equal words
+fn split_accent(c: char) -> (char, u8) {
+    match c {
+        'á' => ('a', 1),
+        'é' => ('e', 1),
+        'í' => ('i', 1),
+        'ó' => ('o', 1),
+        'ő' => ('ö', 1),
+        'ú' => ('u', 1),
+        'ű' => ('ü', 1),
+        'ö' | 'ü' => (c, 0),
+        c => match fold(&c.to_string()).chars().next() {
+            Some(folded) if folded != c => (folded, 2),
+            _ => (c, 0),
+        },
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CollationKey {
+    letters: Vec<u32>,
+    accents: Vec<u8>,
+    // Lowercase first
+    cases: Vec<bool>,
+    original: String,
+}
+
+// Position of a letter in the alphabet, spaces and digits come first
+fn rank(letter: &str) -> u32 {
+    let offset = 11;
+    match ALPHABET.iter().position(|l| *l == letter) {
+        Some(i) => offset + i as u32,
+        None => match letter.chars().next() {
+            Some(c) if c.is_ascii_digit() => 1 + c as u32 - '0' as u32,
+            Some(c) if c.is_alphanumeric() => offset + ALPHABET.len() as u32 + c as u32,
+            _ => 0,
+        },
+    }
+}
+
+pub fn collation_key(s: &str) -> CollationKey {
+    let (chars, accents): (Vec<char>, Vec<u8>) = s
+        .chars()
+        .flat_map(char::to_lowercase)
+        .map(split_accent)
+        .unzip();
+    let mut letters = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let rest = chars[i..].iter().take(4).collect::<String>();
+        // ssz is sz + sz, ddzs is dzs + dzs
+        let doubled = [
+            "ddzs", "ddz", "ccs", "ggy", "lly", "nny", "ssz", "tty", "zzs",
+        ]
+        .into_iter()
+        .find(|d| rest.starts_with(d));
+        if let Some(d) = doubled {
+            letters.push(rank(&d[1..]));
+            letters.push(rank(&d[1..]));
+            i += d.len();
+            continue;
+        }
+        let letter = ["dzs", "cs", "dz", "gy", "ly", "ny", "sz", "ty", "zs"]
+            .into_iter()
+            .find(|d| rest.starts_with(d))
+            .map(|d| d.to_string())
+            .unwrap_or_else(|| chars[i].to_string());
+        letters.push(rank(&letter));
+        i += letter.chars().count();
+    }
+    CollationKey {
+        letters,
+        accents,
+        cases: s.chars().map(char::is_uppercase).collect(),
+        original: s.to_string(),
+    }
+}
+
+pub fn collate(a: &str, b: &str) -> Ordering {
+    collation_key(a).cmp(&collation_key(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(names: &[&str]) -> Vec<String> {
+        let mut res = names.iter().map(|n| n.to_string()).collect::<Vec<String>>();
+        res.sort_by(|a, b| collate(a, b));
+        res
+    }
+
+    #[test]
+    fn digraphs_are_letters() {
+        // c < cs, d < dz < dzs, g < gy, s < sz
+        for (a, b) in [
+            ("Cukor", "Czakó"),
+            ("Czakó", "Csaba"),
+            ("Cukor", "Csaba"),
+            ("Nádasdy", "Nagy"),
+            ("Gulyás", "Győri"),
+            ("Soós", "Szabó"),
+            ("Dudás", "Dzsida"),
+        ] {
+            assert_eq!(collate(a, b), Ordering::Less, "{} < {}", a, b);
+        }
+    }
+
+    #[test]
+    fn doubled_digraphs() {
+        // ssz is sz + sz
+        assert_eq!(collate("Kasza", "Kassza"), Ordering::Less);
+        assert_eq!(collate("Kassza", "Kaszt"), Ordering::Less);
+        assert_eq!(collation_key("ssz").letters, collation_key("szsz").letters);
+        assert_eq!(collation_key("ggy").letters, collation_key("gygy").letters);
+    }
+
+    #[test]
+    fn accents_only_decide_between_equal_words() {
+        assert_eq!(collate("Eszter", "Éva"), Ordering::Less);
+        assert_eq!(collate("Éva", "Evelin"), Ordering::Less);
+        assert_eq!(collate("Eva", "Éva"), Ordering::Less);
+        // ö and ü are letters on their own
+        assert_eq!(collate("Ozsváth", "Ödön"), Ordering::Less);
+        assert_eq!(collate("Ödön", "Pál"), Ordering::Less);
+        assert_eq!(collate("Ürge", "Vilma"), Ordering::Less);
+        assert_eq!(collate("Ödön", "Őze"), Ordering::Less);
+    }
+
+    #[test]
+    fn case_decides_last() {
+        assert_eq!(collate("ádám", "Ádám"), Ordering::Less);
+        assert_eq!(collate("Ádám", "adél"), Ordering::Less);
+        assert_eq!(collate("Ádám", "Ádám"), Ordering::Equal);
+    }
+
+    #[test]
+    fn roster_order() {
+        assert_eq!(
+            sorted(&[
+                "Zoltán",
+                "csaba",
+                "Ádám",
+                "Czakó",
+                "Cukor",
+                "Éva",
+                "Eszter",
+                "2. csoport"
+            ]),
+            vec![
+                "2. csoport",
+                "Ádám",
+                "Cukor",
+                "Czakó",
+                "csaba",
+                "Eszter",
+                "Éva",
+                "Zoltán"
+            ]
+        );
+    }
+
+    #[test]
+    fn fold_drops_case_and_accents() {
+        assert_eq!(fold("ÁRVÍZTŰRŐ tükörfúrógép"), "arvizturo tukorfurogep");
+        assert_eq!(fold("Čapek Łukasz"), "capek lukasz");
+    }
+
+    #[test]
+    fn every_word_has_to_match_a_field() {
+        let fields = ["Kiss Ödön", "Pécs", "123456788"];
+        assert!(matches_all("odon pecs", &fields));
+        assert!(matches_all("  KISS   ", &fields));
+        assert!(matches_all("3456", &fields));
+        assert!(matches_all("", &fields));
+        assert!(!matches_all("kiss szeged", &fields));
+        // A word does not span two fields
+        assert!(!matches_all("ödönpécs", &fields));
+    }
+}
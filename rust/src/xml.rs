@@ -1,8 +1,72 @@
-use crate::api::worker::Worker;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::api::{
+    settings::Employer,
+    worker::{is_valid_taj, is_valid_taxnumber, Worker},
+};
 use chrono::prelude::*;
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader, Writer,
+};
 use simple_xml_serialize::XMLElement;
 
-pub fn render_xml(workers: Vec<Worker>) -> String {
+// Default kind of employment
+pub const DEFAULT_CATEGORY: &str = "03";
+
+// One worker of the report
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportRow {
+    pub name: String,
+    pub taxnumber: String,
+    pub taj: String,
+    pub category: String,
+    pub days: u32,
+}
+
+impl From<&Worker> for ReportRow {
+    fn from(worker: &Worker) -> Self {
+        Self {
+            name: worker.name.to_owned(),
+            taxnumber: worker.taxnumber.to_owned(),
+            taj: worker.taj.to_owned(),
+            category: DEFAULT_CATEGORY.to_string(),
+            days: 1,
+        }
+    }
+}
+
+// Everything the output depends on besides the rows, so the same context renders the same file
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderContext {
+    pub employer: Employer,
+    pub report_date: NaiveDate,
+    pub generated_at: DateTime<Local>,
+}
+
+impl RenderContext {
+    pub fn new(employer: Employer, report_date: NaiveDate) -> Self {
+        Self {
+            employer,
+            report_date,
+            generated_at: Local::now(),
+        }
+    }
+    // Named by the generation time down to the millisecond, a later attempt
+    // gets a counter when the name is taken. No colons, Windows rejects them.
+    pub fn file_name(&self, attempt: u32) -> String {
+        let time = self.generated_at.format("%Y%m%d-%H%M%S-%3f");
+        match attempt {
+            0 => format!("{}.xml", time),
+            n => format!("{}-{}.xml", time, n),
+        }
+    }
+}
+
+pub fn render_xml(context: &RenderContext, rows: &[ReportRow]) -> String {
+    let employer = &context.employer;
+    let date = context.report_date;
+
     let pad = |i: usize, size: usize| -> String {
         let mut istr = i.to_string();
         while istr.len() < size {
@@ -16,53 +80,53 @@ pub fn render_xml(workers: Vec<Worker>) -> String {
     mezok.add_elements(vec![
         XMLElement::new("mezo")
             .attr("eazon", "0A0001C0001AA")
-            .text("23127182215"),
+            .text(&employer.tax_number),
         XMLElement::new("mezo")
             .attr("eazon", "0A0001E001A")
-            .text("Mezei Istvánné"),
+            .text(&employer.name),
         XMLElement::new("mezo")
             .attr("eazon", "0A0001E002A")
-            .text("06305236153"),
+            .text(&employer.phone),
     ]);
 
-    for (i, worker) in workers.iter().enumerate() {
+    for (i, worker) in rows.iter().enumerate() {
         mezok.add_elements(vec![
             // Name
             XMLElement::new("mezo")
-                .attr("eazon", &format!("0B{}C0001AA", pad(i + 1, 4)))
+                .attr("eazon", format!("0B{}C0001AA", pad(i + 1, 4)))
                 .text(&worker.name),
             // Taxnumber
             XMLElement::new("mezo")
-                .attr("eazon", &format!("0B{}C0002AA", pad(i + 1, 4)))
+                .attr("eazon", format!("0B{}C0002AA", pad(i + 1, 4)))
                 .text(&worker.taxnumber),
             // TAJ
             XMLElement::new("mezo")
-                .attr("eazon", &format!("0B{}C0003AA", pad(i + 1, 4)))
+                .attr("eazon", format!("0B{}C0003AA", pad(i + 1, 4)))
                 .text(&worker.taj), // TODO: TAJ szám valamiért a korábbi algoritmusban 9-re van paddolva. Miért?
             // TYPE MODE U => Új bejelentés
             XMLElement::new("mezo")
-                .attr("eazon", &format!("0B{}D0005AA", pad(i + 1, 4)))
+                .attr("eazon", format!("0B{}D0005AA", pad(i + 1, 4)))
                 .text("U"),
             // TYPE 03
             XMLElement::new("mezo")
-                .attr("eazon", &format!("0B{}D0007AA", pad(i + 1, 4)))
-                .text("03"),
+                .attr("eazon", format!("0B{}D0007AA", pad(i + 1, 4)))
+                .text(&worker.category),
             // Record ID
             XMLElement::new("mezo")
-                .attr("eazon", &format!("0B{}A001A", pad(i + 1, 4)))
-                .text(&(i + 1).to_string()),
+                .attr("eazon", format!("0B{}A001A", pad(i + 1, 4)))
+                .text(i + 1),
             // Time period till valid
             XMLElement::new("mezo")
-                .attr("eazon", &format!("0B{}D0009AA", pad(i + 1, 4)))
-                .text(1),
+                .attr("eazon", format!("0B{}D0009AA", pad(i + 1, 4)))
+                .text(worker.days),
             // Date report
             XMLElement::new("mezo")
-                .attr("eazon", &format!("0B{}D0008AA", pad(i + 1, 4)))
-                .text(&format!(
+                .attr("eazon", format!("0B{}D0008AA", pad(i + 1, 4)))
+                .text(format!(
                     "{}{}{}",
-                    Utc::now().year(),
-                    &pad(Utc::now().month() as usize, 2),
-                    &pad(Utc::now().day() as usize, 2)
+                    date.year(),
+                    pad(date.month() as usize, 2),
+                    pad(date.day() as usize, 2)
                 )),
         ]);
     }
@@ -74,12 +138,11 @@ pub fn render_xml(workers: Vec<Worker>) -> String {
 
     let mut adozo = XMLElement::new("adozo");
 
-    adozo.add_element(XMLElement::new("adoszam").text("23127182215"));
+    adozo.add_element(XMLElement::new("adoszam").text(&employer.tax_number));
 
     let mut nyomtatvanyinformacio = XMLElement::new("nyomtatvanyinformacio");
     nyomtatvanyinformacio.add_elements(vec![
-        XMLElement::new("nyomtatvanyazonosito")
-            .text(format!("{}T1042E", Utc::now().naive_local().format("%y"))),
+        XMLElement::new("nyomtatvanyazonosito").text(format!("{}T1042E", date.format("%y"))),
         XMLElement::new("nyomtatvanyverzio").text("1.0"),
         adozo,
         XMLElement::new("megjegyzes").text("Bejelentés"),
@@ -89,11 +152,734 @@ pub fn render_xml(workers: Vec<Worker>) -> String {
     nyomtatvany.add_element(mezok);
     root.add_element(nyomtatvany);
 
-    format!(
-        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>{}",
-        root.to_string()
-    )
-    // root.to_string_pretty_prolog("\n", "   ")
+    format!("<?xml version=\"1.0\" encoding=\"UTF-8\"?>{}", root)
+}
+
+fn eazon(reader: &Reader<&[u8]>, element: &BytesStart) -> Result<String, String> {
+    Ok(element
+        .try_get_attribute("eazon")
+        .map_err(|e| e.to_string())?
+        .ok_or("Field without eazon")?
+        .decode_and_unescape_value(reader)
+        .map_err(|e| e.to_string())?
+        .to_string())
+}
+
+// Content of a report file
+pub(crate) struct XmlDocument {
+    pub(crate) root: String,
+    // Text of the other elements by their name, like nyomtatvanyazonosito
+    pub(crate) info: HashMap<String, String>,
+    // Values of the mezo elements by their eazon, in document order
+    pub(crate) fields: Vec<(String, String)>,
+}
+
+pub(crate) fn read_document(xml: &str) -> Result<XmlDocument, String> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+    let mut res = XmlDocument {
+        root: String::new(),
+        info: HashMap::new(),
+        fields: vec![],
+    };
+    // Names of the open elements
+    let mut open: Vec<String> = vec![];
+    loop {
+        match reader.read_event().map_err(|e| e.to_string())? {
+            Event::Start(e) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                if open.is_empty() {
+                    res.root = name.to_owned();
+                }
+                if name == "mezo" {
+                    res.fields.push((eazon(&reader, &e)?, String::new()));
+                }
+                open.push(name);
+            }
+            Event::Empty(e) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                if open.is_empty() {
+                    res.root = name.to_owned();
+                }
+                if name == "mezo" {
+                    res.fields.push((eazon(&reader, &e)?, String::new()));
+                }
+            }
+            Event::Text(t) => {
+                let text = t.unescape().map_err(|e| e.to_string())?.to_string();
+                match open.last().map(|n| n.as_str()) {
+                    Some("mezo") => {
+                        if let Some(last) = res.fields.last_mut() {
+                            last.1 = text;
+                        }
+                    }
+                    Some(name) => {
+                        res.info.insert(name.to_string(), text);
+                    }
+                    None => (),
+                }
+            }
+            Event::End(_) => {
+                open.pop();
+            }
+            Event::Eof => break,
+            _ => (),
+        }
+    }
+    Ok(res)
+}
+
+// Values of the mezo elements by their eazon, in document order
+pub fn read_fields(xml: &str) -> Result<Vec<(String, String)>, String> {
+    Ok(read_document(xml)?.fields)
+}
+
+// Row number and field code of a worker field, like 0B0001C0001AA
+pub(crate) fn row_field(eazon: &str) -> Option<(usize, &str)> {
+    let rest = eazon.strip_prefix("0B")?;
+    let number = rest.get(..4)?;
+    if !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some((number.parse().ok()?, &rest[4..]))
+}
+
+// Name, tax ID and TAJ, the fields holding personal data
+const PERSONAL_FIELDS: [&str; 3] = ["C0001AA", "C0002AA", "C0003AA"];
+
+// The same report with the personal fields of the rows cleared, where
+// matches is given the TAJ and tax ID of the row
+pub(crate) fn redact_rows(
+    xml: &str,
+    matches: impl Fn(&str, &str) -> bool,
+) -> Result<String, String> {
+    let mut ids: BTreeMap<usize, (String, String)> = BTreeMap::new();
+    for (eazon, value) in read_fields(xml)? {
+        match row_field(&eazon) {
+            Some((i, "C0002AA")) => ids.entry(i).or_default().1 = value,
+            Some((i, "C0003AA")) => ids.entry(i).or_default().0 = value,
+            _ => (),
+        }
+    }
+    let rows = ids
+        .into_iter()
+        .filter(|(_, (taj, taxnumber))| matches(taj, taxnumber))
+        .map(|(i, _)| i)
+        .collect::<HashSet<usize>>();
+    if rows.is_empty() {
+        return Ok(xml.to_string());
+    }
+    let mut reader = Reader::from_str(xml);
+    let mut writer = Writer::new(vec![]);
+    // Inside a field to clear
+    let mut clear = false;
+    loop {
+        let event = reader.read_event().map_err(|e| e.to_string())?;
+        match &event {
+            Event::Start(e) if e.local_name().as_ref() == b"mezo" => {
+                clear = row_field(&eazon(&reader, e)?)
+                    .is_some_and(|(i, code)| rows.contains(&i) && PERSONAL_FIELDS.contains(&code));
+            }
+            Event::Text(_) | Event::CData(_) if clear => continue,
+            Event::End(_) => clear = false,
+            Event::Eof => break,
+            _ => (),
+        }
+        writer.write_event(event).map_err(|e| e.to_string())?;
+    }
+    String::from_utf8(writer.into_inner()).map_err(|e| e.to_string())
+}
+
+// One worker of a report read back
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedRow {
+    pub row: ReportRow,
+    pub date: NaiveDate,
+}
+
+// A report read back from its XML
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedReport {
+    pub employer: Employer,
+    pub rows: Vec<ParsedRow>,
+}
+
+pub fn parse_xml(xml: &str) -> Result<ParsedReport, String> {
+    let mut employer = Employer {
+        tax_number: String::new(),
+        name: String::new(),
+        phone: String::new(),
+    };
+    let mut rows: BTreeMap<usize, (ReportRow, Option<NaiveDate>)> = BTreeMap::new();
+    for (eazon, value) in read_fields(xml)? {
+        match eazon.as_str() {
+            "0A0001C0001AA" => employer.tax_number = value,
+            "0A0001E001A" => employer.name = value,
+            "0A0001E002A" => employer.phone = value,
+            _ => {
+                let (i, code) = match row_field(&eazon) {
+                    Some(field) => field,
+                    None => continue,
+                };
+                let (row, date) = rows.entry(i).or_insert_with(|| {
+                    (
+                        ReportRow {
+                            name: String::new(),
+                            taxnumber: String::new(),
+                            taj: String::new(),
+                            category: DEFAULT_CATEGORY.to_string(),
+                            days: 1,
+                        },
+                        None,
+                    )
+                });
+                match code {
+                    "C0001AA" => row.name = value,
+                    "C0002AA" => row.taxnumber = value,
+                    "C0003AA" => row.taj = value,
+                    "D0007AA" => row.category = value,
+                    "D0009AA" => row.days = value.trim().parse().unwrap_or(1),
+                    "D0008AA" => *date = NaiveDate::parse_from_str(value.trim(), "%Y%m%d").ok(),
+                    _ => (),
+                }
+            }
+        }
+    }
+    if employer.tax_number.is_empty() {
+        return Err("Not a T1042E report: the employer is missing".into());
+    }
+    Ok(ParsedReport {
+        employer,
+        rows: rows
+            .into_iter()
+            .map(|(i, (row, date))| match date {
+                Some(date) => Ok(ParsedRow { row, date }),
+                None => Err(format!("Row {} has no valid report date", i)),
+            })
+            .collect::<Result<Vec<ParsedRow>, String>>()?,
+    })
+}
+
+// Longest report the four digit row numbers allow
+const MAX_ROWS: usize = 9999;
+
+enum Rule {
+    Text(usize),
+    Digits(usize),
+    Phone,
+    Taj,
+    Taxnumber,
+    Values(&'static [&'static str]),
+    Number(u32, u32),
+    Date,
+    RowNumber,
+}
+
+// Fields of the T1042E form, every one of them is required
+const EMPLOYER_FIELDS: [(&str, Rule); 3] = [
+    ("0A0001C0001AA", Rule::Digits(11)),
+    ("0A0001E001A", Rule::Text(100)),
+    ("0A0001E002A", Rule::Phone),
+];
 
-    // root.to_string()
+const ROW_FIELDS: [(&str, Rule); 8] = [
+    ("C0001AA", Rule::Text(100)),
+    ("C0002AA", Rule::Taxnumber),
+    ("C0003AA", Rule::Taj),
+    ("D0005AA", Rule::Values(&["U"])),
+    ("D0007AA", Rule::Digits(2)),
+    ("A001A", Rule::RowNumber),
+    ("D0008AA", Rule::Date),
+    ("D0009AA", Rule::Number(1, 31)),
+];
+
+// Problem found in a report
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmlIssue {
+    // Starting from 1, None if it is not about a worker row
+    pub row: Option<usize>,
+    // The eazon if it is about a single field
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl XmlIssue {
+    fn new(row: Option<usize>, field: Option<&str>, message: impl Into<String>) -> Self {
+        Self {
+            row,
+            field: field.map(|f| f.to_string()),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for XmlIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "{}: {}", field, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+// Problem with the value, None if it is fine
+fn check(rule: &Rule, value: &str, row: usize) -> Option<String> {
+    let digits = |len: usize| value.len() == len && value.chars().all(|c| c.is_ascii_digit());
+    let ok = match rule {
+        Rule::Text(max) => !value.trim().is_empty() && value.chars().count() <= *max,
+        Rule::Digits(len) => digits(*len),
+        Rule::Phone => {
+            (1..=20).contains(&value.len())
+                && value.chars().any(|c| c.is_ascii_digit())
+                && value
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || " +/-".contains(c))
+        }
+        Rule::Taj => is_valid_taj(value),
+        Rule::Taxnumber => is_valid_taxnumber(value),
+        Rule::Values(values) => values.contains(&value),
+        Rule::Number(min, max) => value
+            .parse::<u32>()
+            .is_ok_and(|n| (*min..=*max).contains(&n)),
+        Rule::Date => NaiveDate::parse_from_str(value, "%Y%m%d").is_ok() && digits(8),
+        Rule::RowNumber => value == row.to_string(),
+    };
+    if ok {
+        return None;
+    }
+    Some(match rule {
+        Rule::Text(max) => format!("Must be given, at most {} characters", max),
+        Rule::Digits(len) => format!("Must be {} digits", len),
+        Rule::Phone => "Not a phone number".to_string(),
+        Rule::Taj => "Not a TAJ number".to_string(),
+        Rule::Taxnumber => "Not a tax number".to_string(),
+        Rule::Values(values) => format!("Must be one of {}", values.join(", ")),
+        Rule::Number(min, max) => format!("Must be a number from {} to {}", min, max),
+        Rule::Date => "Must be a date as YYYYMMDD".to_string(),
+        Rule::RowNumber => format!("Must be the row number {}", row),
+    })
+}
+
+// Checks a report against the T1042E field definitions, empty if it can be submitted
+pub fn validate_xml(xml: &str) -> Vec<XmlIssue> {
+    let document = match read_document(xml) {
+        Ok(document) => document,
+        Err(e) => {
+            return vec![XmlIssue::new(
+                None,
+                None,
+                format!("Not well-formed XML: {}", e),
+            )]
+        }
+    };
+    let mut res = vec![];
+    if document.root != "nyomtatvanyok" {
+        res.push(XmlIssue::new(
+            None,
+            None,
+            "The root element must be nyomtatvanyok",
+        ));
+    }
+    let form_id = document
+        .info
+        .get("nyomtatvanyazonosito")
+        .cloned()
+        .unwrap_or_default();
+    let form_year = form_id
+        .strip_suffix("T1042E")
+        .filter(|y| y.len() == 2 && y.chars().all(|c| c.is_ascii_digit()));
+    if form_year.is_none() {
+        res.push(XmlIssue::new(None, None, "The form ID must be YYT1042E"));
+    }
+
+    let mut values: HashMap<&str, &str> = HashMap::new();
+    let mut rows: BTreeSet<usize> = BTreeSet::new();
+    for (eazon, value) in &document.fields {
+        if values.insert(eazon, value).is_some() {
+            res.push(XmlIssue::new(
+                None,
+                Some(eazon),
+                "Field is given more than once",
+            ));
+            continue;
+        }
+        if let Some((row, code)) = row_field(eazon) {
+            if ROW_FIELDS.iter().any(|(c, _)| *c == code) {
+                rows.insert(row);
+                continue;
+            }
+        }
+        if !EMPLOYER_FIELDS.iter().any(|(c, _)| c == eazon) {
+            res.push(XmlIssue::new(None, Some(eazon), "Unknown field"));
+        }
+    }
+
+    for (eazon, rule) in &EMPLOYER_FIELDS {
+        match values.get(eazon) {
+            Some(value) => {
+                if let Some(message) = check(rule, value, 0) {
+                    res.push(XmlIssue::new(None, Some(eazon), message));
+                }
+            }
+            None => res.push(XmlIssue::new(
+                None,
+                Some(eazon),
+                "Required field is missing",
+            )),
+        }
+    }
+    let tax_number = values.get("0A0001C0001AA").copied().unwrap_or_default();
+    if document.info.get("adoszam").map(|s| s.as_str()) != Some(tax_number) {
+        res.push(XmlIssue::new(
+            None,
+            None,
+            "The tax number of adozo must match the employer",
+        ));
+    }
+
+    if rows.is_empty() {
+        res.push(XmlIssue::new(None, None, "The report has no workers"));
+    }
+    if rows.len() > MAX_ROWS {
+        res.push(XmlIssue::new(
+            None,
+            None,
+            format!("The report has more than {} workers", MAX_ROWS),
+        ));
+    }
+    for (expected, row) in rows.iter().enumerate() {
+        if *row != expected + 1 {
+            res.push(XmlIssue::new(
+                Some(*row),
+                None,
+                format!(
+                    "Row numbers must be continuous, {} is missing",
+                    expected + 1
+                ),
+            ));
+            break;
+        }
+    }
+    for row in &rows {
+        for (code, rule) in &ROW_FIELDS {
+            let eazon = format!("0B{:04}{}", row, code);
+            match values.get(eazon.as_str()) {
+                Some(value) => {
+                    if let Some(message) = check(rule, value, *row) {
+                        res.push(XmlIssue::new(Some(*row), Some(&eazon), message));
+                    }
+                    // The form is for a single year
+                    if matches!(rule, Rule::Date)
+                        && form_year.is_some_and(|y| value.get(2..4) != Some(y))
+                    {
+                        res.push(XmlIssue::new(
+                            Some(*row),
+                            Some(&eazon),
+                            "Not in the year of the form",
+                        ));
+                    }
+                }
+                None => res.push(XmlIssue::new(
+                    Some(*row),
+                    Some(&eazon),
+                    "Required field is missing",
+                )),
+            }
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::test_util::employer;
+
+    fn context() -> RenderContext {
+        RenderContext {
+            employer: employer(),
+            report_date: NaiveDate::from_ymd_opt(2024, 3, 15).unwrap(),
+            generated_at: Local.with_ymd_and_hms(2024, 3, 14, 18, 30, 5).unwrap(),
+        }
+    }
+
+    fn row(name: &str, taxnumber: &str, taj: &str) -> ReportRow {
+        ReportRow {
+            name: name.to_string(),
+            taxnumber: taxnumber.to_string(),
+            taj: taj.to_string(),
+            category: DEFAULT_CATEGORY.to_string(),
+            days: 1,
+        }
+    }
+
+    fn rows() -> Vec<ReportRow> {
+        vec![
+            row("Kiss Anna", "12345678-1-02", "123456789"),
+            ReportRow {
+                category: "05".to_string(),
+                days: 3,
+                ..row("Nagy Béla", "87654321-2-13", "987 654 321")
+            },
+        ]
+    }
+
+    // Run with UPDATE_GOLDEN=1 to rewrite the files after an intended change
+    fn assert_golden(name: &str, actual: &str) {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/golden")
+            .join(name);
+        if std::env::var("UPDATE_GOLDEN").is_ok() {
+            std::fs::write(&path, actual).unwrap();
+        }
+        let expected = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(actual, expected, "{} differs", name);
+    }
+
+    #[test]
+    fn renders_single_row() {
+        let rows = [row("Kiss Anna", "12345678-1-02", "123456789")];
+        assert_golden("single_row.xml", &render_xml(&context(), &rows));
+    }
+
+    #[test]
+    fn renders_categories_and_days() {
+        assert_golden("two_rows.xml", &render_xml(&context(), &rows()));
+    }
+
+    #[test]
+    fn renders_the_same_for_the_same_context() {
+        let context = context();
+        assert_eq!(
+            render_xml(&context, &rows()),
+            render_xml(&context.clone(), &rows())
+        );
+    }
+
+    #[test]
+    fn form_follows_the_report_date() {
+        let context = RenderContext {
+            report_date: NaiveDate::from_ymd_opt(2025, 1, 2).unwrap(),
+            ..context()
+        };
+        let xml = render_xml(&context, &rows());
+        assert!(xml.contains("<nyomtatvanyazonosito>25T1042E</nyomtatvanyazonosito>"));
+        assert!(xml.contains(">20250102</mezo>"));
+    }
+
+    #[test]
+    fn file_name_uses_the_generation_time() {
+        assert_eq!(context().file_name(0), "20240314-183005-000.xml");
+        assert_eq!(context().file_name(2), "20240314-183005-000-2.xml");
+    }
+
+    #[test]
+    fn golden_output_is_valid() {
+        assert_eq!(validate_xml(&render_xml(&context(), &rows())), vec![]);
+    }
+
+    #[test]
+    fn parses_back_what_was_rendered() {
+        let report = parse_xml(&render_xml(&context(), &rows())).unwrap();
+        assert_eq!(report.employer, context().employer);
+        assert_eq!(
+            report.rows.into_iter().map(|r| r.row).collect::<Vec<_>>(),
+            rows()
+        );
+    }
+
+    fn fixture(name: &str) -> String {
+        std::fs::read_to_string(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests/fixtures")
+                .join(name),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_rows_of_several_days() {
+        let report = parse_xml(&fixture("multi_day.xml")).unwrap();
+        assert_eq!(report.employer, context().employer);
+        let rows = report
+            .rows
+            .iter()
+            .map(|r| {
+                (
+                    r.row.name.as_str(),
+                    r.row.category.as_str(),
+                    r.row.days,
+                    r.date.format("%Y-%m-%d").to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                ("Kiss Anna", "03", 1, "2024-03-04".to_string()),
+                ("Nagy Béla", "05", 2, "2024-03-04".to_string()),
+                ("Kiss Anna", "03", 1, "2024-03-05".to_string()),
+                ("Tóth Géza", "03", 3, "2024-03-05".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_needs_the_employer_and_the_dates() {
+        let xml = render_xml(&context(), &rows());
+        let without_employer = xml.replace("<mezo eazon=\"0A0001C0001AA\">23127182215</mezo>", "");
+        assert!(parse_xml(&without_employer).is_err());
+        let without_date = xml.replace(">20240315</mezo>", ">2024.03.15</mezo>");
+        assert_eq!(
+            parse_xml(&without_date).err(),
+            Some("Row 1 has no valid report date".to_string())
+        );
+        assert!(parse_xml("<nyomtatvanyok><mezok>").is_err());
+    }
+
+    fn issues_of(replace: &[(&str, &str)]) -> Vec<XmlIssue> {
+        let mut xml = render_xml(&context(), &rows());
+        for (from, to) in replace {
+            assert!(xml.contains(from), "{} is not in the XML", from);
+            xml = xml.replace(from, to);
+        }
+        validate_xml(&xml)
+    }
+
+    #[test]
+    fn required_fields_are_checked() {
+        assert_eq!(
+            issues_of(&[
+                ("<mezo eazon=\"0B0002D0009AA\">3</mezo>", ""),
+                ("<mezo eazon=\"0A0001E002A\">06305236153</mezo>", ""),
+            ]),
+            vec![
+                XmlIssue::new(None, Some("0A0001E002A"), "Required field is missing"),
+                XmlIssue::new(Some(2), Some("0B0002D0009AA"), "Required field is missing"),
+            ]
+        );
+    }
+
+    #[test]
+    fn fields_are_given_once() {
+        let name = "<mezo eazon=\"0B0001C0001AA\">Kiss Anna</mezo>";
+        assert_eq!(
+            issues_of(&[(name, &format!("{}{}", name, name))]),
+            vec![XmlIssue::new(
+                None,
+                Some("0B0001C0001AA"),
+                "Field is given more than once"
+            )]
+        );
+    }
+
+    #[test]
+    fn row_numbers_are_continuous() {
+        assert_eq!(
+            issues_of(&[
+                ("\"0B0002", "\"0B0003"),
+                (
+                    "<mezo eazon=\"0B0003A001A\">2</mezo>",
+                    "<mezo eazon=\"0B0003A001A\">3</mezo>"
+                ),
+            ]),
+            vec![XmlIssue::new(
+                Some(3),
+                None,
+                "Row numbers must be continuous, 2 is missing"
+            )]
+        );
+        // The row number field has to match the eazon
+        assert_eq!(
+            issues_of(&[(
+                "<mezo eazon=\"0B0002A001A\">2</mezo>",
+                "<mezo eazon=\"0B0002A001A\">1</mezo>"
+            )]),
+            vec![XmlIssue::new(
+                Some(2),
+                Some("0B0002A001A"),
+                "Must be the row number 2"
+            )]
+        );
+    }
+
+    #[test]
+    fn row_values_are_checked() {
+        assert_eq!(
+            issues_of(&[
+                (
+                    "<mezo eazon=\"0B0001D0005AA\">U</mezo>",
+                    "<mezo eazon=\"0B0001D0005AA\">M</mezo>"
+                ),
+                (
+                    "<mezo eazon=\"0B0002D0007AA\">05</mezo>",
+                    "<mezo eazon=\"0B0002D0007AA\">5</mezo>"
+                ),
+                (
+                    "<mezo eazon=\"0B0002D0009AA\">3</mezo>",
+                    "<mezo eazon=\"0B0002D0009AA\">32</mezo>"
+                ),
+                ("987 654 321", "98765432"),
+            ]),
+            vec![
+                XmlIssue::new(Some(1), Some("0B0001D0005AA"), "Must be one of U"),
+                XmlIssue::new(Some(2), Some("0B0002C0003AA"), "Not a TAJ number"),
+                XmlIssue::new(Some(2), Some("0B0002D0007AA"), "Must be 2 digits"),
+                XmlIssue::new(
+                    Some(2),
+                    Some("0B0002D0009AA"),
+                    "Must be a number from 1 to 31"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn dates_are_in_the_year_of_the_form() {
+        assert_eq!(
+            issues_of(&[(
+                "<mezo eazon=\"0B0002D0008AA\">20240315</mezo>",
+                "<mezo eazon=\"0B0002D0008AA\">20231231</mezo>"
+            )]),
+            vec![XmlIssue::new(
+                Some(2),
+                Some("0B0002D0008AA"),
+                "Not in the year of the form"
+            )]
+        );
+        assert_eq!(
+            issues_of(&[(">20240315</mezo>", ">2024-03-15</mezo>")])[0],
+            XmlIssue::new(Some(1), Some("0B0001D0008AA"), "Must be a date as YYYYMMDD")
+        );
+    }
+
+    #[test]
+    fn adoszam_is_the_employer() {
+        assert_eq!(
+            issues_of(&[(
+                "<adoszam>23127182215</adoszam>",
+                "<adoszam>12345678215</adoszam>"
+            )]),
+            vec![XmlIssue::new(
+                None,
+                None,
+                "The tax number of adozo must match the employer"
+            )]
+        );
+    }
+
+    #[test]
+    fn the_form_is_checked() {
+        let issues = issues_of(&[
+            ("24T1042E", "T1042E"),
+            ("<mezok>", "<mezok><mezo eazon=\"0C0001X\">1</mezo>"),
+        ]);
+        assert!(issues.contains(&XmlIssue::new(None, None, "The form ID must be YYT1042E")));
+        assert!(issues.contains(&XmlIssue::new(None, Some("0C0001X"), "Unknown field")));
+        let issues = validate_xml("<nyomtatvanyok></mezok>");
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.starts_with("Not well-formed XML"));
+    }
 }